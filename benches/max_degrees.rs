@@ -0,0 +1,61 @@
+//! Benchmark for the degree lookup table computed by the verifier
+use ark_bls12_381::Fr;
+use ark_ff::{Field, UniformRand};
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::DenseMVPolynomial;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::Rng;
+
+use sumcheck::naive_sumcheck::protocol::verifier::max_degrees;
+use sumcheck::MultiPoly;
+
+/// The previous implementation, iterating separately over terms and over each term's variables.
+fn max_degrees_nested<F: Field>(g: &MultiPoly<F>) -> Vec<usize> {
+    let mut degrees: Vec<usize> = vec![0; g.num_vars()];
+
+    g.terms().iter().for_each(|(_, term)| {
+        term.iter().for_each(|(var, power)| {
+            if *power > degrees[*var] {
+                degrees[*var] = *power
+            }
+        });
+    });
+
+    degrees
+}
+
+/// A random sparse polynomial with `num_terms` terms of up to 5 variables each.
+fn random_sparse_poly(num_vars: usize, num_terms: usize) -> MultiPoly<Fr> {
+    let mut rng = rand::thread_rng();
+    let terms = (0..num_terms)
+        .map(|_| {
+            let vars = (0..rng.gen_range(1..=5))
+                .map(|_| (rng.gen_range(0..num_vars), rng.gen_range(1..=4)))
+                .collect();
+            (Fr::rand(&mut rng), SparseTerm::new(vars))
+        })
+        .collect();
+
+    MultiPoly::from_coefficients_vec(num_vars, terms)
+}
+
+fn bench_max_degrees(c: &mut Criterion) {
+    let mut group = c.benchmark_group("max_degrees");
+
+    for num_terms in [1_000, 10_000] {
+        let g = random_sparse_poly(20, num_terms);
+        assert_eq!(max_degrees(&g), max_degrees_nested(&g));
+
+        group.bench_with_input(BenchmarkId::new("nested", num_terms), &g, |b, g| {
+            b.iter(|| max_degrees_nested(black_box(g)))
+        });
+        group.bench_with_input(BenchmarkId::new("flat", num_terms), &g, |b, g| {
+            b.iter(|| max_degrees(black_box(g)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_max_degrees);
+criterion_main!(benches);
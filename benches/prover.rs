@@ -0,0 +1,40 @@
+//! Benchmark for the prover's first round, the most expensive one, as `num_vars` and the
+//! number of terms of `g` grow
+use ark_bls12_381::Fr;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use sumcheck::naive_sumcheck::protocol::IPForSumcheck;
+use sumcheck::testing::random_sparse_polynomial;
+
+/// Numbers of variables swept by the benchmark
+const NUM_VARS: [usize; 5] = [1, 5, 10, 15, 20];
+/// Numbers of terms swept by the benchmark
+const NUM_TERMS: [usize; 4] = [1, 10, 100, 500];
+/// Largest `2^num_vars · num_terms` benchmarked, since proving time grows with this product
+const MAX_WORK: usize = 1 << 20;
+
+fn bench_prove_round(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let mut group = c.benchmark_group("prove_round");
+    group.sample_size(10);
+
+    for num_vars in NUM_VARS {
+        for num_terms in NUM_TERMS.into_iter().filter(|t| (1 << num_vars) * t <= MAX_WORK) {
+            let g = random_sparse_polynomial::<Fr, _>(num_vars, num_terms, 3, &mut rng);
+            let id = BenchmarkId::new(format!("{num_vars}_vars"), num_terms);
+
+            group.bench_with_input(id, &g, |b, g| {
+                b.iter_batched(
+                    || IPForSumcheck::prover_init(g.clone()).unwrap(),
+                    |mut state| IPForSumcheck::prove_round(black_box(&mut state), &None),
+                    BatchSize::LargeInput,
+                )
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_prove_round);
+criterion_main!(benches);
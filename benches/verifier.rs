@@ -0,0 +1,47 @@
+//! Benchmark for the verifier's checks of a finished transcript, as `num_vars` and the
+//! number of terms of `g` grow
+use ark_bls12_381::Fr;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use sumcheck::naive_sumcheck::protocol::IPForSumcheck;
+use sumcheck::testing::random_sparse_polynomial;
+use sumcheck::transcript::Blake2Transcript;
+
+/// Numbers of variables swept by the benchmark
+const NUM_VARS: [usize; 5] = [1, 5, 10, 15, 20];
+/// Numbers of terms swept by the benchmark
+const NUM_TERMS: [usize; 4] = [1, 10, 100, 500];
+/// Largest `2^num_vars · num_terms` benchmarked, since proving time grows with this product
+const MAX_WORK: usize = 1 << 20;
+
+fn bench_verify(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let mut group = c.benchmark_group("verify");
+
+    for num_vars in NUM_VARS {
+        for num_terms in NUM_TERMS.into_iter().filter(|t| (1 << num_vars) * t <= MAX_WORK) {
+            let g = random_sparse_polynomial::<Fr, _>(num_vars, num_terms, 3, &mut rng);
+            let sum = IPForSumcheck::compute_sum(&g).unwrap();
+            let proof =
+                IPForSumcheck::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+            let id = BenchmarkId::new(format!("{num_vars}_vars"), num_terms);
+
+            group.bench_with_input(id, &g, |b, g| {
+                b.iter(|| {
+                    IPForSumcheck::verify_with_randomness(
+                        black_box(g),
+                        &proof.prover_messages,
+                        proof.verifier_randomness.clone(),
+                        sum,
+                    )
+                    .unwrap()
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_verify);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+//! Benchmark for the first round of the sumcheck of a product of three multilinear factors in
+//! 16 variables, computed from evaluations of the factor tables or from the expanded product
+use ark_bls12_381::Fr;
+use ark_ff::Field;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use sumcheck::mle::DenseMLE;
+use sumcheck::naive_sumcheck::protocol::IPForSumcheck;
+use sumcheck::naive_sumcheck::virtual_poly::VirtualPolynomial;
+use sumcheck::poly_utils::{multiply, to_dense_evaluations};
+use sumcheck::testing::random_multilinear_polynomial;
+
+/// Number of variables of every factor
+const NUM_VARS: usize = 16;
+/// Number of terms of every factor, so that the expanded product has up to `TERMS^3` terms
+const TERMS: usize = 4;
+
+/// The previous implementation, with one pass over the tables per evaluation point.
+fn round_evaluations_per_point<F: Field>(poly: &VirtualPolynomial<F>, degree: usize) -> Vec<F> {
+    let half = 1 << (poly.num_vars - 1);
+
+    (0..=degree)
+        .map(|t| {
+            let t = F::from(t as u64);
+            (0..half)
+                .map(|b| {
+                    poly.products
+                        .iter()
+                        .map(|(coeff, factors)| {
+                            factors.iter().fold(*coeff, |acc, f| {
+                                let (low, high) = (f.evaluations[b], f.evaluations[b + half]);
+                                acc * (low + t * (high - low))
+                            })
+                        })
+                        .sum::<F>()
+                })
+                .sum()
+        })
+        .collect()
+}
+
+fn bench_product_round(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let mut group = c.benchmark_group("product_round");
+    group.sample_size(10);
+
+    let factors: Vec<_> = (0..3).map(|_| random_multilinear_polynomial::<Fr, _>(NUM_VARS, TERMS, &mut rng)).collect();
+    let expanded = multiply(&multiply(&factors[0], &factors[1]), &factors[2]);
+    let mut virtual_poly = VirtualPolynomial::new(NUM_VARS);
+    virtual_poly
+        .add_product(
+            1.into(),
+            factors.iter().map(|f| DenseMLE::from_evaluations(NUM_VARS, to_dense_evaluations(f)).unwrap()).collect(),
+        )
+        .unwrap();
+
+    group.bench_function("evaluations", |b| b.iter(|| black_box(&virtual_poly).round_evaluations(3)));
+    group.bench_function("evaluations_per_point", |b| {
+        b.iter(|| round_evaluations_per_point(black_box(&virtual_poly), 3))
+    });
+    group.bench_function("coefficients", |b| {
+        b.iter_batched(
+            || IPForSumcheck::prover_init(expanded.clone()).unwrap(),
+            |mut state| IPForSumcheck::prove_round(black_box(&mut state), &None),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_product_round);
+criterion_main!(benches);
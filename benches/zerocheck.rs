@@ -0,0 +1,59 @@
+//! Benchmark for all the rounds of a zerocheck prover in 10 variables: eq-weighted with `eq`
+//! expanded, with `eq` kept implicit, and with the `eq` factor split out of the messages
+use ark_bls12_381::Fr;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use sumcheck::naive_sumcheck::protocol::{prover::ProverMsg, verifier::VerifierMsg, IPForSumcheck};
+use sumcheck::naive_sumcheck::zerocheck::ZeroCheck;
+use sumcheck::poly_utils::build_eq_x_r;
+use sumcheck::testing::random_sparse_polynomial;
+
+/// Number of variables of `f`
+const NUM_VARS: usize = 10;
+/// Number of terms of `f`
+const NUM_TERMS: usize = 20;
+
+/// Runs every round of `prove_round` against the fixed challenge `2`.
+fn run_rounds<S>(state: &mut S, prove_round: impl Fn(&mut S, &Option<VerifierMsg<Fr>>) -> ProverMsg<Fr>) {
+    let mut v_msg = None;
+    for _ in 0..NUM_VARS {
+        black_box(prove_round(state, &v_msg));
+        v_msg = Some(VerifierMsg::new(2.into()));
+    }
+}
+
+fn bench_zerocheck_rounds(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let mut group = c.benchmark_group("zerocheck_rounds");
+    group.sample_size(10);
+
+    let f = random_sparse_polynomial::<Fr, _>(NUM_VARS, NUM_TERMS, 3, &mut rng);
+    let tau = ZeroCheck::<Fr>::sample_tau(NUM_VARS, &mut rng);
+
+    group.bench_function("weighted", |b| {
+        b.iter_batched(
+            || IPForSumcheck::prover_init_weighted(f.clone(), build_eq_x_r(&tau)).unwrap(),
+            |mut state| run_rounds(&mut state, IPForSumcheck::prove_weighted_round),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("implicit_eq", |b| {
+        b.iter_batched(
+            || ZeroCheck::prove(&f, &tau).unwrap(),
+            |mut state| run_rounds(&mut state, ZeroCheck::prove_round),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("split_eq", |b| {
+        b.iter_batched(
+            || ZeroCheck::prove(&f, &tau).unwrap(),
+            |mut state| run_rounds(&mut state, ZeroCheck::prove_round_split),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_zerocheck_rounds);
+criterion_main!(benches);
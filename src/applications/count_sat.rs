@@ -0,0 +1,188 @@
+//! Counting satisfying assignments of a boolean formula (#SAT, Thaler §4.2)
+//!
+//! A formula is arithmetized into a polynomial that agrees with it on `{0,1}^n`
+//! (`AND` becomes a product, `NOT x` becomes `1 − x`, and `OR` follows by De Morgan),
+//! so its hypercube sum is the number of satisfying assignments.
+use ark_ff::Field;
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::DenseMVPolynomial;
+use ark_std::{boxed::Box, vec::Vec};
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverState,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::poly_utils::multiply;
+use crate::transcript::SumcheckTranscript;
+use crate::MultiPoly;
+
+/// Boolean formula over variables `x_0, x_1, ...`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Formula {
+    /// The variable with the given index
+    Var(usize),
+    /// Negation
+    Not(Box<Formula>),
+    /// Conjunction
+    And(Box<Formula>, Box<Formula>),
+    /// Disjunction
+    Or(Box<Formula>, Box<Formula>),
+}
+
+impl Formula {
+    /// `x_i`
+    pub fn var(i: usize) -> Self {
+        Self::Var(i)
+    }
+
+    /// `¬self`
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    /// `self ∧ other`
+    pub fn and(self, other: Self) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    /// `self ∨ other`
+    pub fn or(self, other: Self) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Evaluates the formula on a boolean assignment.
+    pub fn evaluate(&self, assignment: &[bool]) -> bool {
+        match self {
+            Self::Var(i) => assignment[*i],
+            Self::Not(f) => !f.evaluate(assignment),
+            Self::And(f, g) => f.evaluate(assignment) && g.evaluate(assignment),
+            Self::Or(f, g) => f.evaluate(assignment) || g.evaluate(assignment),
+        }
+    }
+
+    /// Largest variable index used in the formula.
+    fn max_var(&self) -> usize {
+        match self {
+            Self::Var(i) => *i,
+            Self::Not(f) => f.max_var(),
+            Self::And(f, g) | Self::Or(f, g) => f.max_var().max(g.max_var()),
+        }
+    }
+}
+
+/// `1 − p`
+fn one_minus<F: Field>(p: &MultiPoly<F>) -> MultiPoly<F> {
+    let one = MultiPoly::from_coefficients_vec(
+        p.num_vars(),
+        vec![(F::one(), SparseTerm::new(vec![]))],
+    );
+    &one - p
+}
+
+/// Replaces every `x_i^k` by `x_i`, which leaves the polynomial unchanged on the hypercube.
+fn multilinearize<F: Field>(p: MultiPoly<F>) -> MultiPoly<F> {
+    let terms = p
+        .terms()
+        .iter()
+        .map(|(coeff, term)| {
+            (*coeff, SparseTerm::new(term.vars().into_iter().map(|v| (v, 1)).collect()))
+        })
+        .collect();
+
+    MultiPoly::from_coefficients_vec(p.num_vars(), terms)
+}
+
+fn arithmetize_inner<F: Field>(formula: &Formula, num_vars: usize) -> MultiPoly<F> {
+    match formula {
+        Formula::Var(i) => MultiPoly::from_coefficients_vec(
+            num_vars,
+            vec![(F::one(), SparseTerm::new(vec![(*i, 1)]))],
+        ),
+        Formula::Not(f) => one_minus(&arithmetize_inner(f, num_vars)),
+        Formula::And(f, g) => multilinearize(multiply(
+            &arithmetize_inner(f, num_vars),
+            &arithmetize_inner(g, num_vars),
+        )),
+        Formula::Or(f, g) => one_minus(&multilinearize(multiply(
+            &one_minus(&arithmetize_inner(f, num_vars)),
+            &one_minus(&arithmetize_inner(g, num_vars)),
+        ))),
+    }
+}
+
+/// Arithmetizes `formula` into a multilinear polynomial in `num_vars` variables that agrees with
+/// it on every boolean assignment.
+///
+/// Products are kept multilinear, so every variable has degree at most one, which is
+/// also the per-round degree bound `max_degrees` derives for the verifier.
+pub fn arithmetize<F: Field>(
+    formula: &Formula,
+    num_vars: usize,
+) -> Result<MultiPoly<F>, crate::Error> {
+    if formula.max_var() >= num_vars {
+        return Err(crate::Error::PolynomialError(format!(
+            "Formula refers to x_{}, but only {} variables are available.",
+            formula.max_var(),
+            num_vars,
+        )));
+    }
+
+    Ok(arithmetize_inner(formula, num_vars))
+}
+
+/// Prover arithmetizes the formula, counts its satisfying assignments, and gets ready to prove the count.
+pub fn prove_count<F: Field + From<i32>>(
+    formula: &Formula,
+    num_vars: usize,
+) -> Result<(F, ProverState<F>), crate::Error> {
+    let g = arithmetize(formula, num_vars)?;
+
+    Ok((IPForSumcheck::compute_sum(&g)?, IPForSumcheck::prover_init(g)?))
+}
+
+/// Full verification of a claimed count, given the verifier state of the finished sumcheck.
+///
+/// The formula is public, so the verifier arithmetizes it itself for the final query.
+pub fn verify_count<F: Field>(
+    formula: &Formula,
+    num_vars: usize,
+    claimed_count: F,
+    verifier_state: VerifierState<F>,
+) -> Result<(), crate::Error> {
+    let g = arithmetize(formula, num_vars)?;
+
+    IPForSumcheck::verify(&g, verifier_state, claimed_count)
+}
+
+/// Runs the whole counting protocol between an honest prover and the verifier for a claimed count.
+pub fn run_count_sat<F: Field + From<i32>, T: SumcheckTranscript<F>>(
+    formula: &Formula,
+    num_vars: usize,
+    claimed_count: F,
+    transcript: &mut T,
+) -> Result<(), crate::Error> {
+    let (_, mut prover_state) = prove_count::<F>(formula, num_vars)?;
+    let mut verifier_state = IPForSumcheck::verifier_init(num_vars);
+    let mut verifier_msg: Option<VerifierMsg<F>> = None;
+
+    for _ in 0..num_vars {
+        let prover_message = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_message, &mut verifier_state, transcript)?);
+    }
+
+    verify_count(formula, num_vars, claimed_count, verifier_state)
+}
+
+/// Counts satisfying assignments by enumerating `{0,1}^num_vars`.
+pub fn brute_force_count(formula: &Formula, num_vars: usize) -> u64 {
+    (0..(1_usize << num_vars))
+        .filter(|i| {
+            let assignment: Vec<bool> =
+                (0..num_vars).map(|j| i >> (num_vars - 1 - j) & 1 == 1).collect();
+            formula.evaluate(&assignment)
+        })
+        .count() as u64
+}
@@ -0,0 +1,160 @@
+//! Matrix-multiplication verification (Thaler §4.4)
+//!
+//! To check a claimed product `C = A·B`, the verifier picks random `r1`, `r2` and evaluates
+//! `C̃(r1, r2)` itself. The prover then runs the sumcheck protocol on
+//! `f(x) = Ã(r1, x)·B̃(x, r2)`, whose hypercube sum is exactly `C̃(r1, r2)`. The final check
+//! needs `Ã` and `B̃` at a single point each, which the verifier computes from the inputs
+//! in `O(n²)` time.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::applications::{dimensions, fix_variables, log2_ceil, matrix_mle};
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::naive_sumcheck::virtual_poly::{VirtualPolynomial, VirtualProverState};
+use crate::transcript::SumcheckTranscript;
+
+/// Number of variables for rows of `A`, the shared inner dimension, and columns of `B`.
+fn shape<F: Field>(a: &[Vec<F>], b: &[Vec<F>]) -> Result<(usize, usize, usize), crate::Error> {
+    let (a_rows, a_cols) = dimensions(a)?;
+    let (b_rows, b_cols) = dimensions(b)?;
+    if a_cols != b_rows {
+        return Err(crate::Error::PolynomialError(format!(
+            "Cannot multiply a {a_rows}×{a_cols} matrix by a {b_rows}×{b_cols} matrix."
+        )));
+    }
+
+    // The sumcheck runs over the inner dimension, which needs at least one variable.
+    Ok((log2_ceil(a_rows), log2_ceil(a_cols).max(1), log2_ceil(b_cols)))
+}
+
+fn transpose<F: Field>(m: &[Vec<F>]) -> Vec<Vec<F>> {
+    let cols = m.first().map_or(0, Vec::len);
+    (0..cols).map(|j| m.iter().map(|row| row[j]).collect()).collect()
+}
+
+/// Prover for the MatMult protocol
+pub struct MatMulProver<F: Field> {
+    /// left input matrix
+    a: Vec<Vec<F>>,
+    /// right input matrix
+    b: Vec<Vec<F>>,
+}
+
+/// Verifier State for the MatMult protocol, after the challenges `r1`, `r2` have been sent
+pub struct MatMulVerifier<F: Field> {
+    /// random point for the rows of `C`
+    pub r1: Vec<F>,
+    /// random point for the columns of `C`
+    pub r2: Vec<F>,
+    /// `C̃(r1, r2)`, the sum the prover has to establish
+    pub asserted_sum: F,
+}
+
+/// Prover computes the claimed product `C = A·B` and gets ready for the verifier's challenges.
+pub fn prove_matmul<F: Field>(
+    a: &[Vec<F>],
+    b: &[Vec<F>],
+) -> Result<(Vec<Vec<F>>, MatMulProver<F>), crate::Error> {
+    shape(a, b)?;
+
+    let b_t = transpose(b);
+    let c = a
+        .iter()
+        .map(|row| b_t.iter().map(|col| row.iter().zip(col).map(|(x, y)| *x * y).sum()).collect())
+        .collect();
+
+    Ok((c, MatMulProver { a: a.to_vec(), b: b.to_vec() }))
+}
+
+impl<F: Field> MatMulProver<F> {
+    /// Receive `r1` and `r2` from the verifier and set up the sumcheck over `Ã(r1, x)·B̃(x, r2)`.
+    pub fn sumcheck_prover(
+        &self,
+        verifier: &MatMulVerifier<F>,
+    ) -> Result<VirtualProverState<F>, crate::Error> {
+        let (_, inner_vars, _) = shape(&self.a, &self.b)?;
+
+        Ok(IPForSumcheck::prover_init_virtual(restricted_product(
+            &self.a,
+            &self.b,
+            &verifier.r1,
+            &verifier.r2,
+            inner_vars,
+        )))
+    }
+}
+
+/// `Ã(r1, x)·B̃(x, r2)` as a virtual polynomial over the inner dimension.
+fn restricted_product<F: Field>(
+    a: &[Vec<F>],
+    b: &[Vec<F>],
+    r1: &[F],
+    r2: &[F],
+    inner_vars: usize,
+) -> VirtualPolynomial<F> {
+    let a_r1 = fix_variables(&matrix_mle(a, r1.len(), inner_vars), r1);
+    // B̃(x, r2) is the MLE of Bᵀ with its row variables fixed to r2.
+    let b_r2 = fix_variables(&matrix_mle(&transpose(b), r2.len(), inner_vars), r2);
+
+    VirtualPolynomial { num_vars: inner_vars, products: vec![(F::one(), vec![a_r1, b_r2])] }
+}
+
+/// Verifier draws `r1`, `r2` from `transcript` and evaluates the MLE of the claimed product at `(r1, r2)`.
+pub fn verifier_challenge<F: Field, T: SumcheckTranscript<F>>(
+    a: &[Vec<F>],
+    b: &[Vec<F>],
+    c: &[Vec<F>],
+    transcript: &mut T,
+) -> Result<MatMulVerifier<F>, crate::Error> {
+    let (row_vars, _, col_vars) = shape(a, b)?;
+    if dimensions(c)? != (a.len(), b.first().map_or(0, Vec::len)) {
+        return Err(crate::Error::PolynomialError("Claimed product has the wrong dimensions.".into()));
+    }
+
+    let r1: Vec<F> = (0..row_vars).map(|_| transcript.challenge()).collect();
+    let r2: Vec<F> = (0..col_vars).map(|_| transcript.challenge()).collect();
+    let point: Vec<F> = r1.iter().chain(&r2).cloned().collect();
+    let asserted_sum = matrix_mle(c, row_vars, col_vars).evaluate(&point)?;
+
+    Ok(MatMulVerifier { r1, r2, asserted_sum })
+}
+
+/// Full verification of the MatMult protocol, given the verifier state of the finished sumcheck.
+pub fn verify_matmul<F: Field>(
+    a: &[Vec<F>],
+    b: &[Vec<F>],
+    verifier: &MatMulVerifier<F>,
+    verifier_state: VerifierState<F>,
+) -> Result<(), crate::Error> {
+    let (_, inner_vars, _) = shape(a, b)?;
+    let f = restricted_product(a, b, &verifier.r1, &verifier.r2, inner_vars);
+
+    IPForSumcheck::verify_virtual(&f, verifier_state, verifier.asserted_sum)
+}
+
+/// Runs the whole MatMult protocol between an honest prover and the verifier for claimed product `c`.
+pub fn run_matmul<F: Field, T: SumcheckTranscript<F>>(
+    a: &[Vec<F>],
+    b: &[Vec<F>],
+    c: &[Vec<F>],
+    transcript: &mut T,
+) -> Result<(), crate::Error> {
+    let (_, prover) = prove_matmul(a, b)?;
+    let verifier = verifier_challenge(a, b, c, transcript)?;
+
+    let mut prover_state = prover.sumcheck_prover(&verifier)?;
+    let num_vars = prover_state.poly.num_vars;
+    let mut verifier_state = IPForSumcheck::verifier_init(num_vars);
+    let mut verifier_msg: Option<VerifierMsg<F>> = None;
+
+    for _ in 0..num_vars {
+        let prover_message = IPForSumcheck::prove_round_virtual(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_message, &mut verifier_state, transcript)?);
+    }
+
+    verify_matmul(a, b, &verifier, verifier_state)
+}
@@ -0,0 +1,59 @@
+//! Applications of the Sumcheck protocol, following Chapter 4 of Justin Thaler's book:
+//! Proofs, Arguments, and Zero-Knowledge.
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::vec::Vec;
+
+use crate::mle::DenseMLE;
+
+pub mod count_sat;
+pub mod matmul;
+pub mod triangles;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Number of variables needed to index `n` entries, i.e. `⌈log2 n⌉`.
+pub(crate) fn log2_ceil(n: usize) -> usize {
+    n.next_power_of_two().trailing_zeros() as usize
+}
+
+/// Multilinear extension of a matrix zero-padded to `2^row_vars × 2^col_vars`.
+///
+/// The row index occupies the first `row_vars` variables and the column index the rest.
+pub(crate) fn matrix_mle<F: Field>(m: &[Vec<F>], row_vars: usize, col_vars: usize) -> DenseMLE<F> {
+    let mut evaluations = vec![F::zero(); 1 << (row_vars + col_vars)];
+    for (i, row) in m.iter().enumerate() {
+        for (j, entry) in row.iter().enumerate() {
+            evaluations[(i << col_vars) | j] = *entry;
+        }
+    }
+
+    DenseMLE { num_vars: row_vars + col_vars, evaluations }
+}
+
+/// Fixes the leading variables of `mle` to `point`.
+pub(crate) fn fix_variables<F: Field>(mle: &DenseMLE<F>, point: &[F]) -> DenseMLE<F> {
+    point.iter().fold(mle.clone(), |mle, r| mle.fix_first_variable(*r))
+}
+
+/// Checks that all rows of `m` have the same length, returning `(rows, columns)`.
+pub(crate) fn dimensions<T>(m: &[Vec<T>]) -> Result<(usize, usize), crate::Error> {
+    let cols = m.first().map_or(0, Vec::len);
+    if m.iter().any(|row| row.len() != cols) {
+        return Err(crate::Error::PolynomialError("Matrix rows must all have the same length.".into()));
+    }
+
+    Ok((m.len(), cols))
+}
+
+/// Converts a count back into a `u64`, if it fits.
+pub fn count_to_u64<F: PrimeField>(count: F) -> Option<u64> {
+    let bigint = count.into_bigint();
+    let limbs = bigint.as_ref();
+
+    if bigint.num_bits() <= 64 {
+        Some(limbs[0])
+    } else {
+        None
+    }
+}
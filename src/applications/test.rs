@@ -0,0 +1,129 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::{One, UniformRand};
+use ark_std::vec::Vec;
+
+use crate::applications::count_sat::{
+    arithmetize, brute_force_count, prove_count, run_count_sat, Formula,
+};
+use crate::applications::count_to_u64;
+use crate::applications::matmul::{prove_matmul, run_matmul};
+use crate::applications::triangles::{prove_triangles, run_triangles};
+use crate::naive_sumcheck::protocol::verifier::max_degrees;
+use crate::transcript::RngTranscript;
+
+fn random_matrix(rows: usize, cols: usize) -> Vec<Vec<Fr>> {
+    let mut rng = rand::thread_rng();
+    (0..rows).map(|_| (0..cols).map(|_| Fr::rand(&mut rng)).collect()).collect()
+}
+
+#[test]
+fn test_matmul() {
+    let mut rng = rand::thread_rng();
+
+    for (p, q, s) in [(4, 4, 4), (8, 8, 8), (3, 5, 6)] {
+        let (a, b) = (random_matrix(p, q), random_matrix(q, s));
+        let (c, _) = prove_matmul(&a, &b).unwrap();
+
+        run_matmul(&a, &b, &c, &mut RngTranscript::new(&mut rng)).expect("Failed to verify...");
+    }
+}
+
+#[test]
+fn test_matmul_rejects_corrupted_product() {
+    let mut rng = rand::thread_rng();
+
+    for n in [4, 8] {
+        let (a, b) = (random_matrix(n, n), random_matrix(n, n));
+        let (mut c, _) = prove_matmul(&a, &b).unwrap();
+        c[1][2] += Fr::one();
+
+        assert!(run_matmul(&a, &b, &c, &mut RngTranscript::new(&mut rng)).is_err());
+    }
+}
+
+#[test]
+fn test_matmul_dimension_mismatch() {
+    let (a, b) = (random_matrix(2, 3), random_matrix(2, 3));
+    assert!(prove_matmul(&a, &b).is_err());
+}
+
+fn complete_graph(n: usize) -> Vec<Vec<bool>> {
+    (0..n).map(|i| (0..n).map(|j| i != j).collect()).collect()
+}
+
+#[test]
+fn test_triangles() {
+    let mut rng = rand::thread_rng();
+
+    // K4 has four triangles.
+    let k4 = complete_graph(4);
+    let (count, _) = prove_triangles::<Fr>(&k4).unwrap();
+    assert_eq!(count_to_u64(count), Some(4));
+    run_triangles(&k4, count, &mut RngTranscript::new(&mut rng)).expect("Failed to verify...");
+
+    // K5 (padded to 8 vertices) has ten.
+    let k5 = complete_graph(5);
+    let (count, _) = prove_triangles::<Fr>(&k5).unwrap();
+    assert_eq!(count_to_u64(count), Some(10));
+    run_triangles(&k5, count, &mut RngTranscript::new(&mut rng)).expect("Failed to verify...");
+
+    // The 4-cycle is triangle-free.
+    let c4: Vec<Vec<bool>> = (0..4)
+        .map(|i: usize| (0..4).map(|j: usize| (i + 1) % 4 == j || (j + 1) % 4 == i).collect())
+        .collect();
+    let (count, _) = prove_triangles::<Fr>(&c4).unwrap();
+    assert_eq!(count_to_u64(count), Some(0));
+    run_triangles(&c4, count, &mut RngTranscript::new(&mut rng)).expect("Failed to verify...");
+}
+
+#[test]
+fn test_triangles_rejects_wrong_count() {
+    let mut rng = rand::thread_rng();
+    let k4 = complete_graph(4);
+
+    assert!(run_triangles(&k4, Fr::from(5), &mut RngTranscript::new(&mut rng)).is_err());
+    assert!(run_triangles(&k4, Fr::from(0), &mut RngTranscript::new(&mut rng)).is_err());
+}
+
+#[test]
+fn test_triangles_rejects_invalid_graph() {
+    let mut directed = complete_graph(4);
+    directed[0][1] = false;
+    assert!(prove_triangles::<Fr>(&directed).is_err());
+
+    assert_eq!(count_to_u64(-Fr::one()), None);
+}
+
+#[test]
+fn test_count_sat() {
+    let mut rng = rand::thread_rng();
+    let x = Formula::var;
+
+    let formulas = vec![
+        // (x0 ∨ x1) ∧ ¬x2
+        (x(0).or(x(1)).and(x(2).not()), 3),
+        // x0 ∧ (x0 ∨ x3), reusing a variable across both sides
+        (x(0).and(x(0).or(x(3))), 4),
+        // (x0 ∨ ¬x1 ∨ x2) ∧ (¬x0 ∨ x3 ∨ x4)
+        (x(0).or(x(1).not()).or(x(2)).and(x(0).not().or(x(3)).or(x(4))), 5),
+        // majority of x0, x2, x4 ∨ (x1 ∧ x3 ∧ x5)
+        (
+            x(0).and(x(2)).or(x(0).and(x(4))).or(x(2).and(x(4))).or(x(1).and(x(3)).and(x(5))),
+            6,
+        ),
+    ];
+
+    for (formula, num_vars) in formulas {
+        let g = arithmetize::<Fr>(&formula, num_vars).unwrap();
+        assert!(max_degrees(&g).iter().all(|d| *d <= 1));
+
+        let (count, _) = prove_count::<Fr>(&formula, num_vars).unwrap();
+        assert_eq!(count_to_u64(count), Some(brute_force_count(&formula, num_vars)));
+
+        run_count_sat(&formula, num_vars, count, &mut RngTranscript::new(&mut rng)).expect("Failed to verify...");
+        assert!(run_count_sat(&formula, num_vars, count + Fr::one(), &mut RngTranscript::new(&mut rng)).is_err());
+    }
+
+    // Variable out of range.
+    assert!(arithmetize::<Fr>(&x(3), 3).is_err());
+}
@@ -0,0 +1,116 @@
+//! Triangle counting (Thaler §4.3)
+//!
+//! For the adjacency matrix `A` of an undirected graph without self-loops,
+//! `Σ_{x,y,z} Ã(x,y)·Ã(y,z)·Ã(x,z)` counts every triangle six times. The prover runs the
+//! sumcheck protocol over the `3·log n` variables of `(x, y, z)`, and the verifier finishes
+//! by evaluating `Ã` at the three points derived from the random challenges.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::applications::{dimensions, log2_ceil, matrix_mle};
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::naive_sumcheck::virtual_poly::{VirtualPolynomial, VirtualProverState};
+use crate::transcript::SumcheckTranscript;
+
+/// Checks that `adjacency` describes an undirected graph without self-loops, returning the
+/// number of variables needed to index one vertex and the adjacency MLE.
+fn adjacency_mle<F: Field>(adjacency: &[Vec<bool>]) -> Result<(usize, DenseMLE<F>), crate::Error> {
+    let (rows, cols) = dimensions(adjacency)?;
+    if rows != cols {
+        return Err(crate::Error::PolynomialError("Adjacency matrix must be square.".into()));
+    }
+    if (0..rows).any(|i| adjacency[i][i] || (0..i).any(|j| adjacency[i][j] != adjacency[j][i])) {
+        return Err(crate::Error::PolynomialError(
+            "Adjacency matrix must be symmetric with an empty diagonal.".into(),
+        ));
+    }
+
+    let k = log2_ceil(rows).max(1);
+    let m: Vec<Vec<F>> = adjacency
+        .iter()
+        .map(|row| row.iter().map(|e| if *e { F::one() } else { F::zero() }).collect())
+        .collect();
+
+    Ok((k, matrix_mle(&m, k, k)))
+}
+
+/// Prover counts the triangles of the graph and sets up the sumcheck over `(x, y, z)`.
+///
+/// Returns the claimed number of triangles (not multiplied by six) along with the prover state.
+pub fn prove_triangles<F: Field>(
+    adjacency: &[Vec<bool>],
+) -> Result<(F, VirtualProverState<F>), crate::Error> {
+    let (k, a) = adjacency_mle::<F>(adjacency)?;
+    let n = 1 << k;
+
+    // Extend Ã(x,y), Ã(y,z) and Ã(x,z) to tables over (x, y, z).
+    let table = |pick: fn(usize, usize, usize) -> (usize, usize)| {
+        let evaluations = (0..n * n * n)
+            .map(|i| {
+                let (row, col) = pick(i >> (2 * k), (i >> k) & (n - 1), i & (n - 1));
+                a.evaluations[(row << k) | col]
+            })
+            .collect();
+        DenseMLE { num_vars: 3 * k, evaluations }
+    };
+    let factors = vec![
+        table(|x, y, _| (x, y)),
+        table(|_, y, z| (y, z)),
+        table(|x, _, z| (x, z)),
+    ];
+
+    let poly = VirtualPolynomial { num_vars: 3 * k, products: vec![(F::one(), factors)] };
+    let count = poly.sum_over_hypercube() / F::from(6_u64);
+
+    Ok((count, IPForSumcheck::prover_init_virtual(poly)))
+}
+
+/// Full verification of the claimed triangle count, given the verifier state of the finished sumcheck.
+pub fn verify_triangles<F: Field>(
+    adjacency: &[Vec<bool>],
+    claimed_count: F,
+    verifier_state: VerifierState<F>,
+) -> Result<(), crate::Error> {
+    let (k, a) = adjacency_mle::<F>(adjacency)?;
+
+    // Each variable appears in exactly two of the three factors.
+    verifier_state.check_degrees(|_| 2)?;
+
+    let v_out = IPForSumcheck::partial_verify(verifier_state, claimed_count * F::from(6_u64))?;
+
+    let (r_x, rest) = v_out.r_vec.split_at(k);
+    let (r_y, r_z) = rest.split_at(k);
+    let at = |u: &[F], v: &[F]| a.evaluate(&[u, v].concat());
+
+    let oracle_eval = at(r_x, r_y)? * at(r_y, r_z)? * at(r_x, r_z)?;
+    if oracle_eval == v_out.expected_evaluation {
+        Ok(())
+    } else {
+        Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, oracle_eval))
+    }
+}
+
+/// Runs the whole triangle counting protocol between an honest prover and the verifier,
+/// for a claimed number of triangles.
+pub fn run_triangles<F: Field, T: SumcheckTranscript<F>>(
+    adjacency: &[Vec<bool>],
+    claimed_count: F,
+    transcript: &mut T,
+) -> Result<(), crate::Error> {
+    let (_, mut prover_state) = prove_triangles::<F>(adjacency)?;
+    let num_vars = prover_state.poly.num_vars;
+    let mut verifier_state = IPForSumcheck::verifier_init(num_vars);
+    let mut verifier_msg: Option<VerifierMsg<F>> = None;
+
+    for _ in 0..num_vars {
+        let prover_message = IPForSumcheck::prove_round_virtual(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_message, &mut verifier_state, transcript)?);
+    }
+
+    verify_triangles(adjacency, claimed_count, verifier_state)
+}
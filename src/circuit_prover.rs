@@ -0,0 +1,227 @@
+//! Sumcheck prover for polynomials given by an arithmetic circuit
+//!
+//! `ArithCircuitProver` only needs a circuit `C` with `C(x) = g(x)`, never the terms of `g`,
+//! whose number can grow exponentially in the size of `C`. In round `i` it evaluates `C` at
+//! `(r_1, ..., r_{i-1}, t, b)` for every boolean suffix `b` and `t = 0, ..., d_i`, where `d_i`
+//! is the degree of `C` in `x_i`, and interpolates the round polynomial from the sums. Round
+//! `i` thus costs `(d_i + 1) · 2^(v - i)` evaluations of `C`.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::poly_utils::uni_poly_from_evaluations;
+use crate::traits::SumcheckProver;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Gate of an `ArithCircuit`, reading inputs or the outputs of earlier gates by index
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gate<F: Field> {
+    /// The variable `x_i`
+    Input(usize),
+    /// A constant
+    Const(F),
+    /// Sum of the outputs of two earlier gates
+    Add(usize, usize),
+    /// Product of the outputs of two earlier gates
+    Mul(usize, usize),
+}
+
+/// Arithmetic circuit over `num_vars` inputs, whose output is the output of its last gate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArithCircuit<F: Field> {
+    /// Number of input variables
+    num_vars: usize,
+    /// Gates in topological order
+    gates: Vec<Gate<F>>,
+    /// Degree of the output in each variable
+    degrees: Vec<usize>,
+}
+
+/// Prover State for a polynomial given by an `ArithCircuit`
+#[derive(Clone, Debug)]
+pub struct ArithCircuitProver<F: Field> {
+    /// Circuit computing `g`
+    pub circuit: ArithCircuit<F>,
+    /// randomness provided by the verifier
+    pub randomness: Vec<F>,
+    /// The current round number
+    pub round: usize,
+}
+
+impl<F: Field> ArithCircuit<F> {
+    /// Builds the circuit computing the output of the last of `gates` on `num_vars` inputs.
+    ///
+    /// Fails if there are no gates, or if a gate reads a variable out of range or a gate that
+    /// does not come before it.
+    pub fn new(num_vars: usize, gates: Vec<Gate<F>>) -> Result<Self, crate::Error> {
+        if gates.is_empty() {
+            return Err(crate::Error::PolynomialError("A circuit needs at least one gate.".into()));
+        }
+
+        // Degree of each gate's output in each variable, bounded from above.
+        let mut gate_degrees: Vec<Vec<usize>> = Vec::with_capacity(gates.len());
+        for (k, gate) in gates.iter().enumerate() {
+            let operand = |j: usize| {
+                gate_degrees.get(j).ok_or_else(|| {
+                    crate::Error::PolynomialError(format!(
+                        "Gate {k} reads gate {j}, which does not come before it."
+                    ))
+                })
+            };
+            let degrees = match *gate {
+                Gate::Input(i) if i >= num_vars => {
+                    return Err(crate::Error::PolynomialError(format!(
+                        "Gate {k} reads x{i}, but the circuit has {num_vars} inputs."
+                    )));
+                }
+                Gate::Input(i) => (0..num_vars).map(|j| (i == j) as usize).collect(),
+                Gate::Const(_) => vec![0; num_vars],
+                Gate::Add(a, b) => {
+                    operand(a)?.iter().zip(operand(b)?).map(|(da, db)| *da.max(db)).collect()
+                }
+                Gate::Mul(a, b) => {
+                    operand(a)?.iter().zip(operand(b)?).map(|(da, db)| da + db).collect()
+                }
+            };
+            gate_degrees.push(degrees);
+        }
+
+        Ok(Self { num_vars, degrees: gate_degrees.pop().unwrap(), gates })
+    }
+
+    /// Number of input variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Bound on the degree of the output in each variable, from the structure of the circuit.
+    pub fn degrees(&self) -> &[usize] {
+        &self.degrees
+    }
+
+    /// Evaluates the circuit at `point`, which must have `num_vars` coordinates.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "Expected a point with one coordinate per input...");
+
+        let mut values: Vec<F> = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let value = match *gate {
+                Gate::Input(i) => point[i],
+                Gate::Const(c) => c,
+                Gate::Add(a, b) => values[a] + values[b],
+                Gate::Mul(a, b) => values[a] * values[b],
+            };
+            values.push(value);
+        }
+
+        values.pop().unwrap()
+    }
+
+    /// Sum of the circuit's output over the boolean hypercube.
+    pub fn hypercube_sum(&self) -> F {
+        let mut point = vec![F::zero(); self.num_vars];
+        (0..(1_usize << self.num_vars))
+            .map(|b| {
+                for (j, x) in point.iter_mut().enumerate() {
+                    *x = F::from(((b >> (self.num_vars - 1 - j)) & 1) as u64);
+                }
+                self.evaluate(&point)
+            })
+            .sum()
+    }
+}
+
+impl<F: Field> ArithCircuitProver<F> {
+    /// Initialize prover to argue for the sum of the polynomial computed by `circuit` over the
+    /// boolean hypercube.
+    ///
+    /// A circuit without variables is valid and has no rounds, as in `prover_init`.
+    pub fn new(circuit: ArithCircuit<F>) -> Self {
+        Self {
+            randomness: Vec::with_capacity(circuit.num_vars),
+            circuit,
+            round: 0,
+        }
+    }
+}
+
+impl<F: Field> SumcheckProver<F> for ArithCircuitProver<F> {
+    /// Fixes the verifier's challenge, if any, and sums the circuit over the remaining
+    /// hypercube at `degrees()[i] + 1` values of the current variable `x_i`.
+    fn next_message(
+        &mut self,
+        verifier_msg: Option<VerifierMsg<F>>,
+    ) -> Result<ProverMsg<F>, crate::Error> {
+        let num_vars = self.circuit.num_vars;
+        if self.is_done() {
+            return Err(crate::Error::RoundMismatch {
+                expected: num_vars,
+                got: self.round + 1,
+            });
+        }
+        if verifier_msg.is_some() != (self.round > 0) {
+            return Err(crate::Error::ProtocolError(
+                "A verifier message is expected in every round but the first.".into(),
+            ));
+        }
+        if let Some(msg) = verifier_msg {
+            self.randomness.push(msg.randomness);
+        }
+
+        // The point is laid out as `(r_1, ..., r_{i-1}, t, b)`; `t` and `b` are overwritten in place.
+        let i = self.randomness.len();
+        let mut point = self.randomness.clone();
+        point.resize(num_vars, F::zero());
+        let suffix_len = num_vars - i - 1;
+
+        let mut evals = vec![F::zero(); self.circuit.degrees[i] + 1];
+        for b in 0..(1_usize << suffix_len) {
+            for (j, x) in point[i + 1..].iter_mut().enumerate() {
+                *x = F::from(((b >> (suffix_len - 1 - j)) & 1) as u64);
+            }
+            for (t, eval) in evals.iter_mut().enumerate() {
+                point[i] = F::from(t as u64);
+                *eval += self.circuit.evaluate(&point);
+            }
+        }
+        let gi = uni_poly_from_evaluations(&evals);
+
+        // Increment round
+        self.round += 1;
+
+        Ok(ProverMsg::new(gi))
+    }
+
+    fn is_done(&self) -> bool {
+        self.round >= self.circuit.num_vars
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Full verification for the polynomial computed by `circuit`.
+    ///
+    /// Every round polynomial is bounded by the degree of `circuit` in its variable, and the
+    /// final check evaluates `circuit` at `r_vec`.
+    pub fn verify_circuit(
+        circuit: &ArithCircuit<F>,
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        if verifier_state.num_vars != circuit.num_vars {
+            return Err(crate::Error::RoundMismatch {
+                expected: circuit.num_vars,
+                got: verifier_state.num_vars,
+            });
+        }
+        verifier_state.check_degrees(|i| circuit.degrees[i])?;
+
+        Self::partial_verify(verifier_state, asserted_sum)?
+            .verify_oracle_call(|r_vec| circuit.evaluate(r_vec))
+    }
+}
@@ -0,0 +1,101 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::UniformRand;
+use ark_poly::Polynomial;
+
+use crate::circuit_prover::{ArithCircuit, ArithCircuitProver, Gate};
+use crate::naive_sumcheck::protocol::{IPForSumcheck, verifier::VerifierMsg};
+use crate::testing::thaler_example;
+use crate::traits::SumcheckProver;
+use crate::transcript::RngTranscript;
+
+// 2 x0^3 + x0 x2 + x1 x2, computed as 2 x0^3 + (x0 + x1) x2.
+fn thaler_circuit() -> ArithCircuit<Fr> {
+    ArithCircuit::new(
+        3,
+        vec![
+            Gate::Input(0),
+            Gate::Input(1),
+            Gate::Input(2),
+            Gate::Mul(0, 0),
+            Gate::Mul(3, 0),
+            Gate::Const(2.into()),
+            Gate::Mul(5, 4),
+            Gate::Add(0, 1),
+            Gate::Mul(7, 2),
+            Gate::Add(6, 8),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_arith_circuit() {
+    let mut rng = rand::thread_rng();
+    let (g, circuit) = (thaler_example(), thaler_circuit());
+    assert_eq!(circuit.num_vars(), 3);
+    assert_eq!(circuit.degrees(), [3, 1, 1]);
+    assert_eq!(circuit.hypercube_sum(), 12.into());
+
+    let point: Vec<Fr> = (0..3).map(|_| Fr::rand(&mut rng)).collect();
+    assert_eq!(circuit.evaluate(&point), g.evaluate(&point));
+
+    // Gates may only read earlier gates and existing inputs.
+    assert!(ArithCircuit::<Fr>::new(3, vec![]).is_err());
+    assert!(ArithCircuit::<Fr>::new(3, vec![Gate::Input(3)]).is_err());
+    assert!(ArithCircuit::<Fr>::new(3, vec![Gate::Input(0), Gate::Add(0, 1)]).is_err());
+}
+
+#[test]
+fn test_circuit_prover() {
+    let mut rng = rand::thread_rng();
+    let (g, circuit) = (thaler_example(), thaler_circuit());
+    let mut prover = ArithCircuitProver::new(circuit.clone());
+    let mut reference = IPForSumcheck::prover_init(g).unwrap();
+    let mut verifier_state = IPForSumcheck::verifier_init(3);
+    let mut transcript = RngTranscript::new(&mut rng);
+
+    // Same messages as the prover over the terms of `g`.
+    let mut verifier_msg: Option<VerifierMsg<Fr>> = None;
+    while !prover.is_done() {
+        let prover_msg = prover.next_message(verifier_msg.clone()).unwrap();
+        assert_eq!(prover_msg, reference.next_message(verifier_msg).unwrap());
+        verifier_msg = Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+    assert!(matches!(prover.next_message(verifier_msg), Err(crate::Error::RoundMismatch { .. })));
+    IPForSumcheck::verify_circuit(&circuit, verifier_state.clone(), 12.into()).expect("Failed to verify...");
+    assert!(IPForSumcheck::verify_circuit(&circuit, verifier_state, 13.into()).is_err());
+
+    // A circuit of far more terms than gates: Π_j (1 + x_j) has 2^12 terms.
+    let num_vars = 12;
+    let mut gates = vec![Gate::Const(Fr::from(1))];
+    let mut product = 0;
+    for j in 0..num_vars {
+        let x = gates.len();
+        gates.extend([Gate::Input(j), Gate::Add(0, x), Gate::Mul(product, x + 1)]);
+        product = x + 2;
+    }
+    let circuit = ArithCircuit::new(num_vars, gates).unwrap();
+    assert_eq!(circuit.hypercube_sum(), Fr::from(3_u64.pow(num_vars as u32)));
+
+    let mut prover = ArithCircuitProver::new(circuit.clone());
+    let mut verifier_state = IPForSumcheck::verifier_init(num_vars);
+    let mut verifier_msg = None;
+    while !prover.is_done() {
+        let prover_msg = prover.next_message(verifier_msg).unwrap();
+        verifier_msg = Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+    IPForSumcheck::verify_circuit(&circuit, verifier_state, circuit.hypercube_sum()).expect("Failed to verify...");
+}
+
+#[test]
+fn test_circuit_prover_without_variables() {
+    let circuit = ArithCircuit::new(0, vec![Gate::Const(Fr::from(3)), Gate::Mul(0, 0)]).unwrap();
+    let mut prover = ArithCircuitProver::new(circuit.clone());
+    assert!(prover.is_done());
+    assert!(matches!(prover.next_message(None), Err(crate::Error::RoundMismatch { .. })));
+
+    assert_eq!(circuit.hypercube_sum(), Fr::from(9));
+    let verifier_state = IPForSumcheck::verifier_init(0);
+    assert!(IPForSumcheck::verify_circuit(&circuit, verifier_state.clone(), 9.into()).is_ok());
+    assert!(IPForSumcheck::verify_circuit(&circuit, verifier_state, 10.into()).is_err());
+}
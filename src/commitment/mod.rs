@@ -0,0 +1,9 @@
+//! Commitments to the polynomial the verifier queries at the end of the protocol
+//!
+//! Instead of evaluating `g` itself, the verifier receives a commitment to `g` before the
+//! rounds and an opening of it at `r_vec` afterwards, checked through the `PolyOracle` the
+//! opening implements.
+pub mod pedersen;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
@@ -0,0 +1,171 @@
+//! Pedersen commitments to multilinear extensions, opened as in Hyrax
+//!
+//! The `2^v` evaluations of a `DenseMLE` are laid out as a matrix `M` with `2^⌊v/2⌋` rows, the
+//! first `⌊v/2⌋` variables selecting the row, and each row is committed to with a Pedersen
+//! vector commitment `C_i = Σ_j M_ij·G_j`. Since `eq(z, b)` factors into `L_row · R_col` for a
+//! point `z = (z_row, z_col)`, the evaluation at `z` is `Lᵀ·M·R`. The opening is the row
+//! `u = Lᵀ·M`: the verifier checks it against `Σ_i L_i·C_i = Σ_j u_j·G_j` and computes the
+//! evaluation as `u·R`. Commitments and openings both take `2^⌈v/2⌉` group or field elements.
+//!
+//! The commitments are binding but not hiding, and the opening reveals `u`.
+use ark_ec::Group;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{marker::PhantomData, rand::RngCore, vec::Vec};
+
+use crate::mle::DenseMLE;
+use crate::poly_utils::build_eq_x_r_evals;
+use crate::traits::PolyOracle;
+
+/// Commitment parameters: the generators `G_j` of the row commitments
+///
+/// Nobody may know a discrete logarithm relation between the generators, so they should be
+/// sampled from public randomness, e.g. by `setup` from a seeded RNG.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PedersenOracle<F: PrimeField, G: Group<ScalarField = F>> {
+    /// Largest number of variables of a committed MLE
+    max_num_vars: usize,
+    /// One generator per column of the largest matrix
+    generators: Vec<G>,
+}
+
+/// Commitment to a `DenseMLE`: one Pedersen commitment per row of its evaluation matrix
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: Group> {
+    /// Number of variables of the committed MLE
+    pub num_vars: usize,
+    /// Commitments to the rows of the evaluation matrix
+    pub rows: Vec<G>,
+}
+
+/// Opening of a `Commitment` at a point, i.e. the row `u = Lᵀ·M`
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct OpeningProof<F: PrimeField, G: Group<ScalarField = F>> {
+    /// The rows of the evaluation matrix combined with `eq(z_row, ·)`
+    pub combined_row: Vec<F>,
+    _group: PhantomData<G>,
+}
+
+/// Number of variables selecting the row, and selecting the column, of an MLE in `num_vars`
+/// variables.
+fn split(num_vars: usize) -> (usize, usize) {
+    (num_vars / 2, num_vars - num_vars / 2)
+}
+
+/// `Σ_j scalars_j·bases_j`.
+fn linear_combination<F: PrimeField, G: Group<ScalarField = F>>(bases: &[G], scalars: &[F]) -> G {
+    bases.iter().zip(scalars).map(|(base, scalar)| *base * scalar).sum()
+}
+
+impl<F: PrimeField, G: Group<ScalarField = F>> PedersenOracle<F, G> {
+    /// Samples parameters for MLEs of up to `max_num_vars` variables.
+    pub fn setup<R: RngCore>(max_num_vars: usize, rng: &mut R) -> Self {
+        let num_generators = 1 << split(max_num_vars).1;
+        Self {
+            max_num_vars,
+            generators: (0..num_generators).map(|_| G::rand(rng)).collect(),
+        }
+    }
+
+    /// Largest number of variables of an MLE these parameters can commit to.
+    pub fn max_num_vars(&self) -> usize {
+        self.max_num_vars
+    }
+
+    /// Commits to `g` row by row.
+    pub fn commit(&self, g: &DenseMLE<F>) -> Commitment<G> {
+        if g.num_vars > self.max_num_vars {
+            panic!("Polynomial has more variables than the parameters support...");
+        }
+
+        let num_cols = 1 << split(g.num_vars).1;
+        Commitment {
+            num_vars: g.num_vars,
+            rows: g
+                .evaluations
+                .chunks(num_cols)
+                .map(|row| linear_combination(&self.generators, row))
+                .collect(),
+        }
+    }
+
+    /// Evaluates `g` at `point` and proves that the evaluation matches `commit(g)`.
+    ///
+    /// Fails if `point` does not have one coordinate per variable of `g`.
+    pub fn prove_opening(
+        &self,
+        g: &DenseMLE<F>,
+        point: &[F],
+    ) -> Result<(F, OpeningProof<F, G>), crate::Error> {
+        let eval = g.evaluate(point)?;
+
+        let (row_vars, col_vars) = split(g.num_vars);
+        let left = build_eq_x_r_evals(&point[..row_vars]);
+        let mut combined_row = vec![F::zero(); 1 << col_vars];
+        for (row, weight) in g.evaluations.chunks(combined_row.len()).zip(&left) {
+            for (u, m) in combined_row.iter_mut().zip(row) {
+                *u += *weight * m;
+            }
+        }
+
+        Ok((eval, OpeningProof { combined_row, _group: PhantomData }))
+    }
+
+    /// Checks that `proof` opens `commitment` to `eval` at `point`.
+    pub fn open(
+        &self,
+        proof: OpeningProof<F, G>,
+        point: &[F],
+        eval: F,
+        commitment: &Commitment<G>,
+    ) -> bool {
+        let (row_vars, col_vars) = split(commitment.num_vars);
+        if commitment.num_vars > self.max_num_vars
+            || point.len() != commitment.num_vars
+            || commitment.rows.len() != 1 << row_vars
+            || proof.combined_row.len() != 1 << col_vars
+        {
+            return false;
+        }
+
+        let left = build_eq_x_r_evals(&point[..row_vars]);
+        let right = build_eq_x_r_evals(&point[row_vars..]);
+        linear_combination(&commitment.rows, &left) == linear_combination(&self.generators, &proof.combined_row)
+            && proof.combined_row.iter().zip(&right).map(|(u, r)| *u * r).sum::<F>() == eval
+    }
+}
+
+/// A claimed evaluation with its opening, answering the verifier's final query through
+/// `IPForSumcheck::verify_with_oracle`
+pub struct PedersenOpening<'a, F: PrimeField, G: Group<ScalarField = F>> {
+    /// Commitment parameters
+    pub oracle: &'a PedersenOracle<F, G>,
+    /// Commitment to `g`, received before the rounds
+    pub commitment: &'a Commitment<G>,
+    /// Claimed evaluation of `g` at the verifier's `r_vec`
+    pub eval: F,
+    /// Opening of `commitment` to `eval` at `r_vec`
+    pub proof: OpeningProof<F, G>,
+}
+
+impl<'a, F: PrimeField, G: Group<ScalarField = F>> PolyOracle<F> for PedersenOpening<'a, F, G> {
+    fn num_vars(&self) -> usize {
+        self.commitment.num_vars
+    }
+
+    /// A committed MLE is multilinear.
+    fn degree_bound(&self, _var: usize) -> usize {
+        1
+    }
+
+    /// The claimed evaluation, if the opening holds at `point`.
+    fn query(&self, point: &[F]) -> Result<F, crate::Error> {
+        if self.oracle.open(self.proof.clone(), point, self.eval, self.commitment) {
+            Ok(self.eval)
+        } else {
+            Err(crate::Error::ProtocolError(
+                "Opening does not match the commitment at the queried point.".into(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use ark_bls12_381::{Fr, G1Projective as G1};
+use ark_ff::UniformRand;
+use ark_std::vec::Vec;
+
+use crate::commitment::pedersen::{PedersenOpening, PedersenOracle};
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::linear_time::LinearTimeMleProver;
+use crate::naive_sumcheck::protocol::IPForSumcheck;
+use crate::transcript::RngTranscript;
+
+fn random_mle<R: ark_std::rand::RngCore>(num_vars: usize, rng: &mut R) -> DenseMLE<Fr> {
+    DenseMLE::from_evaluations(num_vars, (0..1 << num_vars).map(|_| Fr::rand(rng)).collect()).unwrap()
+}
+
+#[test]
+fn test_pedersen_opening() {
+    let mut rng = rand::thread_rng();
+    let oracle = PedersenOracle::<Fr, G1>::setup(5, &mut rng);
+
+    // Both an even and an odd number of variables, i.e. square and rectangular matrices.
+    for num_vars in [0, 4, 5] {
+        let g = random_mle(num_vars, &mut rng);
+        let commitment = oracle.commit(&g);
+        let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(&mut rng)).collect();
+        let (eval, proof) = oracle.prove_opening(&g, &point).unwrap();
+        assert_eq!(eval, g.evaluate(&point).unwrap());
+        assert!(oracle.open(proof.clone(), &point, eval, &commitment));
+
+        // A wrong evaluation, a tampered opening or another commitment is rejected.
+        assert!(!oracle.open(proof.clone(), &point, eval + Fr::from(1), &commitment));
+        let mut tampered = proof.clone();
+        tampered.combined_row[0] += Fr::from(1);
+        assert!(!oracle.open(tampered, &point, eval, &commitment));
+        assert!(!oracle.open(proof, &point, eval, &oracle.commit(&random_mle(num_vars, &mut rng))));
+    }
+    assert!(oracle.prove_opening(&random_mle(3, &mut rng), &[Fr::from(1)]).is_err());
+}
+
+#[test]
+fn test_verify_with_pedersen_oracle() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 5;
+    let oracle = PedersenOracle::<Fr, G1>::setup(num_vars, &mut rng);
+    let g = random_mle(num_vars, &mut rng);
+    let commitment = oracle.commit(&g);
+
+    let mut prover = LinearTimeMleProver::new(g.clone());
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+    for _ in 0..num_vars {
+        let prover_msg = prover.prove_round(&verifier_msg);
+        verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+
+    // The prover opens its commitment at the verifier's challenges.
+    let (eval, proof) = oracle.prove_opening(&g, verifier_state.challenges()).unwrap();
+    let opening = PedersenOpening { oracle: &oracle, commitment: &commitment, eval, proof: proof.clone() };
+    IPForSumcheck::verify_with_oracle(&verifier_state, g.sum(), &opening).expect("Failed to verify...");
+    IPForSumcheck::verify_with_oracle(&verifier_state, g.sum(), &g).expect("Failed to verify...");
+    assert!(matches!(
+        IPForSumcheck::verify_with_oracle(&verifier_state, g.sum() + Fr::from(1), &opening),
+        Err(crate::Error::SumMismatch { round: 1, .. })
+    ));
+
+    // A bogus evaluation fails its opening.
+    let bogus = PedersenOpening { oracle: &oracle, commitment: &commitment, eval: eval + Fr::from(1), proof };
+    assert!(matches!(
+        IPForSumcheck::verify_with_oracle(&verifier_state, g.sum(), &bogus),
+        Err(crate::Error::ProtocolError(_))
+    ));
+}
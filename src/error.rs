@@ -1,32 +1,270 @@
-use ark_std::{fmt, string::String};
+use ark_serialize::SerializationError;
+use ark_std::{boxed::Box, fmt, string::{String, ToString}};
 
 use core::fmt::Formatter;
 
 /// Error type for this crate
+///
+/// Rounds are numbered from 1, as in `VerifierState`. Variants may be added in later versions,
+/// so matches need a catch-all arm.
 #[derive(fmt::Debug)]
+#[non_exhaustive]
 pub enum Error {
-    /// protocol rejects proof
-    Reject(Option<String>),
+    /// protocol rejects proof, optionally wrapping the error that caused the rejection
+    ///
+    /// Kept for backwards compatibility: the verifiers in this crate report the structured
+    /// variants below instead.
+    Reject(Option<String>, Option<Box<dyn ark_std::error::Error + Send + Sync>>),
+    /// The sum of `gi` over the round's domain does not match the claim carried over from the
+    /// previous round
+    ///
+    /// Field elements here and in `FinalEvaluationMismatch` are rendered with `Display`.
+    SumMismatch {
+        /// Round of the offending message
+        round: usize,
+        /// The claim carried over from the previous round (the asserted sum in round 1)
+        expected: String,
+        /// The sum of `gi` over the round's domain, as sent by the prover
+        got: String,
+    },
+    /// A round polynomial exceeds its degree bound
+    DegreeTooHigh {
+        /// Round of the offending message
+        round: usize,
+        /// Degree of the received polynomial
+        got: usize,
+        /// Degree bound for that round
+        bound: usize,
+    },
+    /// The number of rounds does not match the number of variables
+    RoundMismatch {
+        /// Number of rounds the verifier expects
+        expected: usize,
+        /// Number of rounds actually received
+        got: usize,
+    },
+    /// The final oracle query does not match the claim the rounds reduced to
+    FinalEvaluationMismatch {
+        /// The claim the rounds reduced to, i.e. `expected_evaluation`
+        expected: String,
+        /// The oracle's answer, e.g. `g(r_vec)`
+        got: String,
+    },
+    /// A recorded challenge differs from the one re-derived by the verifier
+    ChallengeMismatch {
+        /// Round of the offending challenge
+        round: usize,
+    },
+    /// A claim made outside of the rounds, e.g. one of several asserted sums, is wrong
+    ClaimMismatch {
+        /// Position of the offending claim
+        index: usize,
+    },
+    /// Encoded proof does not start with `PROOF_MAGIC`
+    InvalidMagic,
+    /// Encoded proof was written in an unsupported format version
+    UnsupportedVersion {
+        /// Version this crate writes and reads
+        expected: u8,
+        /// Version found in the encoding
+        got: u8,
+    },
+    /// Encoded proof ends before all of its contents are read
+    TruncatedProof,
     /// IO Error
-    IOError,
+    IOError(ark_std::io::Error),
+    /// Data could not be deserialized, e.g. because it was written in an unknown format version
+    DeserializeError(String),
+    /// A polynomial or other input is malformed, e.g. a term refers to a missing variable or
+    /// the input has the wrong shape
+    PolynomialError(String),
+    /// Decoded data describes an inconsistent state, e.g. a round counter that disagrees with
+    /// the challenges received
+    InvalidState(String),
+    /// The protocol is driven or configured wrongly, e.g. a message is missing or a setting is
+    /// out of range
+    ProtocolError(String),
     /// Catch-all error for various other situations
+    #[deprecated(note = "use `PolynomialError`, `InvalidState` or `ProtocolError` instead")]
     OtherError(String),
 }
 
+#[allow(deprecated)]
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        if let Self::OtherError(s) = self {
-            f.write_str(s)
-        } else {
-            f.write_fmt(format_args!("{self:?}"))
+        match self {
+            Self::OtherError(s)
+            | Self::DeserializeError(s)
+            | Self::PolynomialError(s)
+            | Self::InvalidState(s)
+            | Self::ProtocolError(s) => f.write_str(s),
+            Self::SumMismatch { round, expected, got } => write!(
+                f,
+                "Prover message of round {round} is inconsistent with the claim: \
+                 expected the sum of gi over round {round}'s domain to be {expected}, got {got}."
+            ),
+            Self::DegreeTooHigh { round, got, bound } => write!(
+                f,
+                "Prover message of round {round} has degree {got}, exceeding the bound {bound}."
+            ),
+            Self::RoundMismatch { expected, got } => {
+                write!(f, "Expected {expected} rounds, got {got}.")
+            }
+            Self::FinalEvaluationMismatch { expected, got } => write!(
+                f,
+                "Verification failed: expected an evaluation of {expected}, the oracle returned {got}."
+            ),
+            Self::ChallengeMismatch { round } => {
+                write!(f, "Challenge of round {round} does not match the transcript.")
+            }
+            Self::ClaimMismatch { index } => write!(f, "Claim {index} is wrong."),
+            Self::InvalidMagic => f.write_str("Data is not an encoded Sumcheck proof."),
+            Self::UnsupportedVersion { expected, got } => {
+                write!(f, "Unsupported proof format version {got}, expected {expected}.")
+            }
+            Self::TruncatedProof => f.write_str("Encoded proof is truncated."),
+            _ => f.write_fmt(format_args!("{self:?}")),
         }
     }
 }
 
-impl ark_std::error::Error for Error {}
+/// Errors compare by variant and contents; wrapped errors, which cannot be compared directly,
+/// compare by their kind (I/O errors) or message (causes of a `Reject`).
+#[allow(deprecated)]
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Reject(a, source_a), Self::Reject(b, source_b)) => {
+                a == b
+                    && source_a.as_ref().map(|e| e.to_string())
+                        == source_b.as_ref().map(|e| e.to_string())
+            }
+            (
+                Self::SumMismatch { round, expected, got },
+                Self::SumMismatch { round: round_b, expected: expected_b, got: got_b },
+            ) => (round, expected, got) == (round_b, expected_b, got_b),
+            (
+                Self::DegreeTooHigh { round, got, bound },
+                Self::DegreeTooHigh { round: round_b, got: got_b, bound: bound_b },
+            ) => (round, got, bound) == (round_b, got_b, bound_b),
+            (
+                Self::RoundMismatch { expected, got },
+                Self::RoundMismatch { expected: expected_b, got: got_b },
+            ) => (expected, got) == (expected_b, got_b),
+            (
+                Self::FinalEvaluationMismatch { expected, got },
+                Self::FinalEvaluationMismatch { expected: expected_b, got: got_b },
+            ) => (expected, got) == (expected_b, got_b),
+            (Self::ChallengeMismatch { round: a }, Self::ChallengeMismatch { round: b }) => a == b,
+            (Self::ClaimMismatch { index: a }, Self::ClaimMismatch { index: b }) => a == b,
+            (Self::InvalidMagic, Self::InvalidMagic)
+            | (Self::TruncatedProof, Self::TruncatedProof) => true,
+            (
+                Self::UnsupportedVersion { expected, got },
+                Self::UnsupportedVersion { expected: expected_b, got: got_b },
+            ) => (expected, got) == (expected_b, got_b),
+            (Self::IOError(a), Self::IOError(b)) => a.kind() == b.kind(),
+            (Self::DeserializeError(a), Self::DeserializeError(b))
+            | (Self::PolynomialError(a), Self::PolynomialError(b))
+            | (Self::InvalidState(a), Self::InvalidState(b))
+            | (Self::ProtocolError(a), Self::ProtocolError(b))
+            | (Self::OtherError(a), Self::OtherError(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
+/// Wrapped errors cannot be cloned, so clones keep their kind (I/O errors) or message
+/// (causes of a `Reject`) only. Every other variant is cloned exactly.
+#[allow(deprecated)]
+impl Clone for Error {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Reject(msg, source) => Self::Reject(
+                msg.clone(),
+                source.as_ref().map(|e| {
+                    Box::new(Self::ProtocolError(e.to_string()))
+                        as Box<dyn ark_std::error::Error + Send + Sync>
+                }),
+            ),
+            Self::SumMismatch { round, expected, got } => Self::SumMismatch {
+                round: *round,
+                expected: expected.clone(),
+                got: got.clone(),
+            },
+            Self::DegreeTooHigh { round, got, bound } => {
+                Self::DegreeTooHigh { round: *round, got: *got, bound: *bound }
+            }
+            Self::RoundMismatch { expected, got } => {
+                Self::RoundMismatch { expected: *expected, got: *got }
+            }
+            Self::FinalEvaluationMismatch { expected, got } => Self::FinalEvaluationMismatch {
+                expected: expected.clone(),
+                got: got.clone(),
+            },
+            Self::ChallengeMismatch { round } => Self::ChallengeMismatch { round: *round },
+            Self::ClaimMismatch { index } => Self::ClaimMismatch { index: *index },
+            Self::InvalidMagic => Self::InvalidMagic,
+            Self::UnsupportedVersion { expected, got } => {
+                Self::UnsupportedVersion { expected: *expected, got: *got }
+            }
+            Self::TruncatedProof => Self::TruncatedProof,
+            Self::IOError(e) => Self::IOError(ark_std::io::Error::new(e.kind(), e.to_string())),
+            Self::DeserializeError(s) => Self::DeserializeError(s.clone()),
+            Self::PolynomialError(s) => Self::PolynomialError(s.clone()),
+            Self::InvalidState(s) => Self::InvalidState(s.clone()),
+            Self::ProtocolError(s) => Self::ProtocolError(s.clone()),
+            Self::OtherError(s) => Self::OtherError(s.clone()),
+        }
+    }
+}
+
+impl Error {
+    /// A `SumMismatch` in `round` between the carried-over claim and the sum of `gi` over the
+    /// round's domain.
+    pub(crate) fn sum_mismatch<F: fmt::Display>(round: usize, expected: F, got: F) -> Self {
+        Self::SumMismatch { round, expected: expected.to_string(), got: got.to_string() }
+    }
+
+    /// A `FinalEvaluationMismatch` between `expected_evaluation` and the oracle's answer.
+    pub(crate) fn final_evaluation_mismatch<F: fmt::Display>(expected: F, got: F) -> Self {
+        Self::FinalEvaluationMismatch { expected: expected.to_string(), got: got.to_string() }
+    }
+}
+
+impl ark_std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn ark_std::error::Error + 'static)> {
+        match self {
+            Self::IOError(e) => Some(e),
+            Self::Reject(_, Some(e)) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl From<ark_std::io::Error> for Error {
-    fn from(_: ark_std::io::Error) -> Self {
-        Self::IOError
+    fn from(e: ark_std::io::Error) -> Self {
+        Self::IOError(e)
     }
-}
\ No newline at end of file
+}
+
+impl From<SerializationError> for Error {
+    fn from(e: SerializationError) -> Self {
+        match e {
+            // Errors raised by this crate's own `CanonicalDeserialize` impls travel inside an I/O error.
+            #[cfg(feature = "std")]
+            SerializationError::IoError(e) if e.get_ref().is_some_and(|inner| inner.is::<Error>()) => {
+                *e.into_inner().unwrap().downcast::<Error>().unwrap()
+            }
+            // Without `std` they cannot be downcast, and only their message survives.
+            #[cfg(not(feature = "std"))]
+            SerializationError::IoError(e) if e.kind() == ark_std::io::ErrorKind::InvalidData => {
+                Self::DeserializeError(e.to_string())
+            }
+            SerializationError::IoError(e) => Self::IOError(e),
+            e => Self::DeserializeError(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Sumcheck over an extension field
+//!
+//! Over a small base field `F`, a cheating prover's chance to survive a round is up to
+//! `deg / |F|`. Drawing the challenges from an extension `E` of `F` instead shrinks it to
+//! `deg / |E|`, while `g` and its hypercube sum stay in `F`.
+//!
+//! `MixedProverState` keeps the coefficients of `g` in `F` and only moves to `E` where a term
+//! meets a challenge, so the round messages are `ProverMsg<E>` and the challenges
+//! `VerifierMsg<E>`. The verifier runs the usual rounds over `E` and evaluates `g`, embedded
+//! into `E`, at the `E`-valued point. With `E = F` this is the ordinary protocol.
+use ark_ff::{Field, PrimeField};
+use ark_poly::DenseMVPolynomial;
+use ark_std::{cmp::Ordering, marker::PhantomData, vec, vec::Vec, Zero};
+
+use crate::naive_sumcheck::eval::hypercube_sum;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::{max_degrees, VerifierMsg, VerifierState},
+};
+use crate::poly_utils::validate_poly;
+use crate::transcript::SumcheckTranscript;
+use crate::{MultiPoly, UniPoly};
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Sumcheck for a polynomial over `F` with challenges in the extension `E`, i.e. any field
+/// whose base prime field is `F`
+pub struct ExtendedSumcheck<F: PrimeField, E: Field<BasePrimeField = F>> {
+    _marker: PhantomData<(F, E)>,   // cache fields F and E
+}
+
+/// Prover State for a polynomial over `F`, answering challenges in `E`
+pub struct MixedProverState<F: PrimeField, E: Field<BasePrimeField = F>> {
+    /// Polynomial for which we want to prove a relation, with its coefficients in `F`
+    pub g: MultiPoly<F>,
+    /// Sum of `g` over the boolean hypercube, computed in `F` by `prover_init_mixed`
+    pub asserted_sum: F,
+    /// Challenges in `E` received from the verifier so far
+    pub randomness: Vec<E>,
+    /// Round counter, from 0
+    pub round: usize,
+}
+
+impl<F, E> ExtendedSumcheck<F, E>
+where
+    F: PrimeField,
+    E: Field<BasePrimeField = F> + From<i32>,
+{
+    /// The polynomial `g`, with every coefficient embedded into `E`.
+    pub fn lift(g: &MultiPoly<F>) -> MultiPoly<E> {
+        MultiPoly::from_coefficients_vec(
+            g.num_vars,
+            g.terms
+                .iter()
+                .map(|(coeff, term)| (E::from_base_prime_field(*coeff), term.clone()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Initialize the prover for `g`, lifted into `E`.
+    ///
+    pub fn prover_init(g: &MultiPoly<F>) -> Result<ProverState<E>, crate::Error> {
+        IPForSumcheck::prover_init(Self::lift(g))
+    }
+
+    /// Initialize the prover for `g`, keeping its coefficients in `F`.
+    ///
+    pub fn prover_init_mixed(g: &MultiPoly<F>) -> Result<MixedProverState<F, E>, crate::Error> {
+        validate_poly(g)?;
+
+        Ok(MixedProverState {
+            g: g.clone(),
+            asserted_sum: hypercube_sum(g),
+            randomness: Vec::with_capacity(g.num_vars),
+            round: 0,
+        })
+    }
+
+    /// Receive a challenge in `E` from the verifier, generate the round message over `E`, and
+    /// proceed to next round.
+    ///
+    /// After the challenges `r_0, ..., r_{j-1}`, each term `c · Π x_i^{p_i}` adds
+    /// `c · 2^k · Π_{i < j} r_i^{p_i} · X^{p_j}` to the message, where `2^k` counts the points of
+    /// the remaining hypercube at which its variables after `x_j` are all 1. `c · 2^k` is
+    /// computed in `F`, and only the product with the challenges in `E`.
+    pub fn prove_round_mixed(
+        prover_state: &mut MixedProverState<F, E>,
+        v_msg: &Option<VerifierMsg<E>>,
+    ) -> ProverMsg<E> {
+        if prover_state.round >= prover_state.g.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if prover_state.round == 0 {
+                panic!("Prover should go first...");
+            }
+            prover_state.randomness.push(msg.randomness);
+        } else if prover_state.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        let j = prover_state.randomness.len();
+        let remaining = prover_state.g.num_vars - j - 1;
+        let gi = prover_state.g.terms.iter().fold(UniPoly::<E>::zero(), |sum, (coeff, term)| {
+            let (mut fixed, mut power, mut later) = (E::one(), 0, 0);
+            for (var, p) in term.iter() {
+                match var.cmp(&j) {
+                    Ordering::Less => fixed *= prover_state.randomness[*var].pow([*p as u64]),
+                    Ordering::Equal => power = *p,
+                    Ordering::Greater => later += 1,
+                }
+            }
+            let weight = *coeff * F::from(2_u64).pow([(remaining - later) as u64]);
+
+            sum + UniPoly::from_coefficients_vec(vec![(power, fixed * E::from_base_prime_field(weight))])
+        });
+
+        // Increment round
+        prover_state.round += 1;
+
+        ProverMsg::new(gi)
+    }
+
+    /// Evaluation of `g`, embedded into `E`, at the point `point` of `E^num_vars`.
+    pub fn evaluate(g: &MultiPoly<F>, point: &[E]) -> E {
+        g.terms
+            .iter()
+            .map(|(coeff, term)| {
+                term.iter().fold(E::from_base_prime_field(*coeff), |product, (var, power)| {
+                    product * point[*var].pow([*power as u64])
+                })
+            })
+            .sum()
+    }
+
+    /// Full verification of the claim that `g` sums to `asserted_sum`, given the verifier state
+    /// of a sumcheck run over `E`.
+    ///
+    /// The final query evaluates `g` at the challenges with `evaluate`, without lifting `g`.
+    pub fn verify(
+        g: &MultiPoly<F>,
+        verifier_state: VerifierState<E>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        validate_poly(g)?;
+        if verifier_state.degree_bounds().is_none() {
+            let degrees = max_degrees(g);
+            verifier_state.check_degrees(|i| degrees[i])?;
+        }
+
+        IPForSumcheck::partial_verify(verifier_state, E::from_base_prime_field(asserted_sum))?
+            .verify_oracle_call(|r_vec| Self::evaluate(g, r_vec))
+    }
+
+    /// Runs the whole protocol between an honest prover, which keeps `g` over `F`, and the
+    /// verifier, drawing every challenge in `E` from `transcript`.
+    pub fn run<T: SumcheckTranscript<E>>(
+        g: &MultiPoly<F>,
+        asserted_sum: F,
+        transcript: &mut T,
+    ) -> Result<(), crate::Error> {
+        let mut prover_state = Self::prover_init_mixed(g)?;
+        let mut verifier_state = IPForSumcheck::verifier_init(g.num_vars);
+        let mut verifier_msg = None;
+
+        for _ in 0..g.num_vars {
+            let prover_msg = Self::prove_round_mixed(&mut prover_state, &verifier_msg);
+            verifier_msg =
+                Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, transcript)?);
+        }
+
+        Self::verify(g, verifier_state, asserted_sum)
+    }
+}
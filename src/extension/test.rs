@@ -0,0 +1,167 @@
+use ark_bls12_381::{Fq, Fq2};
+use ark_ff::{Field, Zero};
+use ark_poly::Polynomial;
+
+use crate::extension::ExtendedSumcheck;
+use crate::naive_sumcheck::protocol::{IPForSumcheck, prover::ProverMsg};
+use crate::testing::{random_sparse_polynomial, thaler_example};
+use crate::transcript::RngTranscript;
+use crate::UniPoly;
+
+// Goldilocks, p = 2^64 - 2^32 + 1, and its quadratic extension by the non-residue 7.
+// The ark-ff 0.4 `MontConfig` derive puts its impls inside a function, hence the `allow`.
+#[allow(non_local_definitions)]
+mod goldilocks {
+    use ark_ff::{Fp2, Fp2Config, Fp64, MontBackend, MontConfig, MontFp};
+
+    #[derive(MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    pub struct GoldilocksConfig;
+    pub type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+    pub struct GoldilocksQuadConfig;
+    impl Fp2Config for GoldilocksQuadConfig {
+        type Fp = Goldilocks;
+        const NONRESIDUE: Goldilocks = MontFp!("7");
+        const FROBENIUS_COEFF_FP2_C1: &'static [Goldilocks] = &[MontFp!("1"), MontFp!("-1")];
+    }
+    pub type GoldilocksQuad = Fp2<GoldilocksQuadConfig>;
+}
+use goldilocks::{Goldilocks, GoldilocksQuad};
+
+type Mixed = ExtendedSumcheck<Goldilocks, GoldilocksQuad>;
+
+#[test]
+fn test_extended_sumcheck() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    let asserted_sum = IPForSumcheck::compute_sum(&g).unwrap();
+    assert_eq!(asserted_sum, Fq::from(12));
+    assert_eq!(
+        IPForSumcheck::compute_sum(&ExtendedSumcheck::<Fq, Fq2>::lift(&g)).unwrap(),
+        Fq2::from_base_prime_field(asserted_sum)
+    );
+
+    let mut transcript = RngTranscript::new(&mut rng);
+    assert!(ExtendedSumcheck::<Fq, Fq2>::run(&g, asserted_sum, &mut transcript).is_ok());
+    assert!(ExtendedSumcheck::<Fq, Fq2>::run(&g, Fq::from(13), &mut transcript).is_err());
+}
+
+#[test]
+fn test_extended_challenges() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut prover_state = ExtendedSumcheck::<Fq, Fq2>::prover_init(&g).unwrap();
+    let mut verifier_state = IPForSumcheck::verifier_init(3);
+    let mut transcript = RngTranscript::new(&mut rng);
+    let mut verifier_msg = None;
+
+    for _ in 0..3 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+
+    // The challenges leave the base field, i.e. have a nonzero second coordinate.
+    assert!(prover_state.randomness.iter().any(|r| !r.c1.is_zero()));
+    assert!(ExtendedSumcheck::<Fq, Fq2>::verify(&g, verifier_state, Fq::from(12)).is_ok());
+}
+
+#[test]
+fn test_extended_tampered_round() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut prover_state = ExtendedSumcheck::<Fq, Fq2>::prover_init(&g).unwrap();
+    let mut verifier_state = IPForSumcheck::verifier_init(3);
+    let mut transcript = RngTranscript::new(&mut rng);
+    let mut verifier_msg = None;
+
+    // Shift the second round polynomial by an extension field constant outside of `Fq`.
+    for round in 1..=3 {
+        let mut prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        if round == 2 {
+            let shift = UniPoly::from_coefficients_vec(vec![(0, Fq2::new(Fq::from(0), Fq::from(1)))]);
+            prover_msg = ProverMsg::new(prover_msg.gi + shift);
+        }
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+
+    assert!(matches!(
+        ExtendedSumcheck::<Fq, Fq2>::verify(&g, verifier_state, Fq::from(12)),
+        Err(crate::Error::SumMismatch { round: 2, .. })
+    ));
+}
+
+#[test]
+fn test_mixed_sumcheck() {
+    let mut rng = rand::thread_rng();
+
+    for g in [thaler_example(), random_sparse_polynomial::<Goldilocks, _>(5, 16, 3, &mut rng)] {
+        let asserted_sum = IPForSumcheck::compute_sum(&g).unwrap();
+        assert_eq!(Mixed::prover_init_mixed(&g).unwrap().asserted_sum, asserted_sum);
+
+        let mut transcript = RngTranscript::new(&mut rng);
+        assert!(Mixed::run(&g, asserted_sum, &mut transcript).is_ok());
+        assert!(Mixed::run(&g, asserted_sum + Goldilocks::from(1), &mut transcript).is_err());
+        assert!(ExtendedSumcheck::<Goldilocks, Goldilocks>::run(&g, asserted_sum, &mut transcript).is_ok());
+    }
+}
+
+#[test]
+fn test_mixed_matches_lifted_prover() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Goldilocks, _>(4, 12, 3, &mut rng);
+    let mut mixed_state = Mixed::prover_init_mixed(&g).unwrap();
+    let mut lifted_state = Mixed::prover_init(&g).unwrap();
+    let mut verifier_state = IPForSumcheck::verifier_init(4);
+    let mut transcript = RngTranscript::new(&mut rng);
+    let mut verifier_msg = None;
+
+    for _ in 0..4 {
+        let prover_msg = Mixed::prove_round_mixed(&mut mixed_state, &verifier_msg);
+        let lifted_msg = IPForSumcheck::prove_round(&mut lifted_state, &verifier_msg);
+        for x in [GoldilocksQuad::from(0), GoldilocksQuad::from(1), GoldilocksQuad::new(Goldilocks::from(3), Goldilocks::from(5))] {
+            assert_eq!(prover_msg.evaluate(&x), lifted_msg.evaluate(&x));
+        }
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+
+    // The challenges leave the base field, i.e. have a nonzero second coordinate.
+    assert!(mixed_state.randomness.iter().any(|r| !r.c1.is_zero()));
+    assert_eq!(
+        Mixed::evaluate(&g, &verifier_state.randomness),
+        Mixed::lift(&g).evaluate(&verifier_state.randomness)
+    );
+    assert!(Mixed::verify(&g, verifier_state, mixed_state.asserted_sum).is_ok());
+}
+
+#[test]
+fn test_mixed_tampered_round() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Goldilocks, _>(3, 8, 2, &mut rng);
+    let mut prover_state = Mixed::prover_init_mixed(&g).unwrap();
+    let mut verifier_state = IPForSumcheck::verifier_init(3);
+    let mut transcript = RngTranscript::new(&mut rng);
+    let mut verifier_msg = None;
+
+    // Shift the second round polynomial by an extension field constant outside of `Goldilocks`.
+    for round in 1..=3 {
+        let mut prover_msg = Mixed::prove_round_mixed(&mut prover_state, &verifier_msg);
+        if round == 2 {
+            let shift =
+                UniPoly::from_coefficients_vec(vec![(0, GoldilocksQuad::new(Goldilocks::from(0), Goldilocks::from(1)))]);
+            prover_msg = ProverMsg::new(prover_msg.gi + shift);
+        }
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+
+    assert!(matches!(
+        Mixed::verify(&g, verifier_state, prover_state.asserted_sum),
+        Err(crate::Error::SumMismatch { round: 2, .. })
+    ));
+}
@@ -0,0 +1,162 @@
+//! Folded sumcheck for GKR layer reductions
+//!
+//! Each layer reduction proves a sum `Σ_x add(x) · mult(x) · W(x)` of three multilinear factors:
+//! the add-gate and mult-gate wiring polynomials and the next layer's MLE. The product is never
+//! formed: each round fixes the previous challenge in every factor, evaluates each factor at
+//! `t = 0, 1, 2, 3` on its own and multiplies the four values pointwise, so the round polynomial
+//! has degree at most 3.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{DegreeInfo, VerifierMsg, VerifierOutput, VerifierState},
+};
+use crate::poly_utils::uni_poly_from_evaluations;
+use crate::transcript::SumcheckTranscript;
+
+/// Degree of every round polynomial of a product of three multilinear factors
+pub const FOLDED_DEGREE: usize = 3;
+
+/// Prover for `Σ_x add(x) · mult(x) · W(x)`
+#[derive(Clone, Debug)]
+pub struct FoldedSumcheckProver<F: Field> {
+    /// Add-gate polynomial, with the variables fixed so far already folded in
+    pub add: DenseMLE<F>,
+    /// Mult-gate polynomial, with the variables fixed so far already folded in
+    pub mult: DenseMLE<F>,
+    /// Next layer's MLE, with the variables fixed so far already folded in
+    pub w: DenseMLE<F>,
+    /// randomness provided by the verifier
+    pub randomness: Vec<F>,
+    /// The current round number
+    pub round: usize,
+}
+
+/// Verifier for `Σ_x add(x) · mult(x) · W(x)`, rejecting any round polynomial of degree above 3
+#[derive(Clone, Debug)]
+pub struct FoldedSumcheckVerifier<F: Field> {
+    /// Underlying verifier state, initialized with a bound of 3 in every round
+    pub state: VerifierState<F>,
+}
+
+impl<F: Field> FoldedSumcheckProver<F> {
+    /// Initializes the prover for the product of `add`, `mult` and `w`.
+    ///
+    /// Fails unless the three factors are in the same number of variables. Factors without
+    /// variables are valid and have no rounds, as in `prover_init`.
+    pub fn new(add: DenseMLE<F>, mult: DenseMLE<F>, w: DenseMLE<F>) -> Result<Self, crate::Error> {
+        if let Some(f) = [&mult, &w].into_iter().find(|f| f.num_vars != add.num_vars) {
+            return Err(crate::Error::PolynomialError(format!(
+                "Factor has {} variables, expected {}.",
+                f.num_vars, add.num_vars,
+            )));
+        }
+
+        Ok(Self {
+            randomness: Vec::with_capacity(add.num_vars),
+            add,
+            mult,
+            w,
+            round: 0,
+        })
+    }
+
+    /// Sum of the product over the boolean hypercube, i.e. the claim this prover argues for.
+    pub fn asserted_sum(&self) -> F {
+        let (add, mult, w) = (&self.add.evaluations, &self.mult.evaluations, &self.w.evaluations);
+
+        (0..add.len()).map(|b| add[b] * mult[b] * w[b]).sum()
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    pub fn prove_round(&mut self, v_msg: &Option<VerifierMsg<F>>) -> ProverMsg<F> {
+        if self.round >= self.add.num_vars + self.randomness.len() {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if self.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            self.randomness.push(msg.randomness);
+            for factor in [&mut self.add, &mut self.mult, &mut self.w] {
+                *factor = factor.fix_first_variable(msg.randomness);
+            }
+        } else if self.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        // Each factor at t = 0..=3 from its two halves, then the pointwise product.
+        let half = self.add.evaluations.len() / 2;
+        let mut evals = [F::zero(); FOLDED_DEGREE + 1];
+        for b in 0..half {
+            let mut products = [F::one(); FOLDED_DEGREE + 1];
+            for factor in [&self.add, &self.mult, &self.w] {
+                let (low, high) = (factor.evaluations[b], factor.evaluations[b + half]);
+                let slope = high - low;
+                let mut value = low;
+                for product in products.iter_mut() {
+                    *product *= value;
+                    value += slope;
+                }
+            }
+            evals.iter_mut().zip(products).for_each(|(e, p)| *e += p);
+        }
+        let gi = uni_poly_from_evaluations(&evals);
+
+        // Increment round
+        self.round += 1;
+
+        ProverMsg::new(gi)
+    }
+}
+
+impl<F: Field> FoldedSumcheckVerifier<F> {
+    /// Initializes the verifier for a product of three factors in `num_vars` variables.
+    pub fn new(num_vars: usize) -> Self {
+        let degrees = DegreeInfo::new(vec![FOLDED_DEGREE; num_vars]);
+
+        Self {
+            // One bound per variable by construction.
+            state: IPForSumcheck::verifier_init_with_degrees(num_vars, degrees).unwrap(),
+        }
+    }
+
+    /// Checks that `prover_msg` has degree at most 3, then draws the next challenge from
+    /// `transcript`, see `IPForSumcheck::verify_round`.
+    pub fn verify_round<T: SumcheckTranscript<F>>(
+        &mut self,
+        prover_msg: ProverMsg<F>,
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error> {
+        IPForSumcheck::verify_round(prover_msg, &mut self.state, transcript)
+    }
+
+    /// Runs the consistency checks of every round, leaving the claim `add(r) · mult(r) · W(r)`
+    /// at `r_vec` to the caller, e.g. the next layer of a GKR proof.
+    pub fn partial_verify(&self, asserted_sum: F) -> Result<VerifierOutput<F>, crate::Error> {
+        IPForSumcheck::partial_verify(&self.state, asserted_sum)
+    }
+
+    /// Full verification, evaluating the three factors at `r_vec` for the final check.
+    pub fn verify(
+        &self,
+        add: &DenseMLE<F>,
+        mult: &DenseMLE<F>,
+        w: &DenseMLE<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        let v_out = self.partial_verify(asserted_sum)?;
+        let product_eval =
+            add.evaluate(&v_out.r_vec)? * mult.evaluate(&v_out.r_vec)? * w.evaluate(&v_out.r_vec)?;
+        if product_eval == v_out.expected_evaluation {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, product_eval))
+        }
+    }
+}
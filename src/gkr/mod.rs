@@ -0,0 +1,95 @@
+//! Building blocks for GKR-style protocols
+//!
+//! After each layer's sumcheck the verifier is left holding two claims about the next
+//! layer's MLE `W`, at points `b*` and `c*`. These are reduced to a single claim by
+//! restricting `W` to the line `ℓ(t) = b* + t·(c* − b*)` through both points.
+use ark_ff::Field;
+use ark_poly::polynomial::multivariate::Term;
+use ark_poly::polynomial::{DenseMVPolynomial, Polynomial};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{rand::RngCore, vec::Vec};
+
+use crate::poly_utils::uni_poly_from_evaluations;
+use crate::{MultiPoly, UniPoly};
+
+pub mod folded;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Prover message for the two-to-one claim reduction
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+pub struct ProverLineMsg<F: Field> {
+    /// `W` restricted to the line through `b*` and `c*`, i.e. `t ↦ W(ℓ(t))`
+    pub restriction: UniPoly<F>,
+}
+
+/// Evaluates the line `ℓ(t) = b + t·(c − b)`, so that `ℓ(0) = b` and `ℓ(1) = c`.
+pub fn line_at<F: Field>(b: &[F], c: &[F], t: F) -> Vec<F> {
+    b.iter().zip(c).map(|(bi, ci)| *bi + t * (*ci - bi)).collect()
+}
+
+/// Prover computes `W(ℓ(t))` by evaluating `W` along the line at `deg(W) + 1` points.
+pub fn reduce_two_claims_prover<F: Field>(
+    w: &MultiPoly<F>,
+    b: &[F],
+    c: &[F],
+) -> Result<ProverLineMsg<F>, crate::Error> {
+    if b.len() != w.num_vars() || c.len() != w.num_vars() {
+        return Err(crate::Error::PolynomialError(format!(
+            "Points must have {} coordinates, got {} and {}.",
+            w.num_vars(),
+            b.len(),
+            c.len(),
+        )));
+    }
+
+    // The restriction of W to a line has degree at most the total degree of W.
+    let degree = w.terms().iter().map(|(_, term)| term.degree()).max().unwrap_or(0);
+    let evals: Vec<F> = (0..=degree)
+        .map(|t| w.evaluate(&line_at(b, c, F::from(t as u64))))
+        .collect();
+
+    Ok(ProverLineMsg { restriction: uni_poly_from_evaluations(&evals) })
+}
+
+/// Verifier checks the prover's line restriction against both claims, samples `r*`, and returns
+/// the single new claim `W(ℓ(r*)) = restriction(r*)` as `(ℓ(r*), restriction(r*))`.
+///
+/// `W` is assumed to be multilinear, as in GKR, so its restriction has degree at most `b.len()`.
+pub fn reduce_two_claims_verifier<F: Field, R: RngCore>(
+    msg: &ProverLineMsg<F>,
+    b: &[F],
+    c: &[F],
+    claim_b: F,
+    claim_c: F,
+    rng: &mut R,
+) -> Result<(Vec<F>, F), crate::Error> {
+    if b.len() != c.len() {
+        return Err(crate::Error::PolynomialError(format!(
+            "Points must have the same number of coordinates, got {} and {}.",
+            b.len(),
+            c.len(),
+        )));
+    }
+
+    // The reduction is a single round.
+    if msg.restriction.degree() > b.len() {
+        return Err(crate::Error::DegreeTooHigh {
+            round: 1,
+            got: msg.restriction.degree(),
+            bound: b.len(),
+        });
+    }
+
+    // Claim 0 is about `W(b)`, claim 1 about `W(c)`.
+    if msg.restriction.evaluate(&F::zero()) != claim_b {
+        return Err(crate::Error::ClaimMismatch { index: 0 });
+    }
+    if msg.restriction.evaluate(&F::one()) != claim_c {
+        return Err(crate::Error::ClaimMismatch { index: 1 });
+    }
+
+    let r = F::rand(rng);
+    Ok((line_at(b, c, r), msg.restriction.evaluate(&r)))
+}
@@ -0,0 +1,129 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::{One, UniformRand};
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::{DenseMVPolynomial, Polynomial};
+
+use crate::gkr::{
+    folded::{FoldedSumcheckProver, FoldedSumcheckVerifier},
+    reduce_two_claims_prover, reduce_two_claims_verifier,
+};
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::prover::ProverMsg;
+use crate::naive_sumcheck::protocol::IPForSumcheck;
+use crate::poly_utils::{build_eq_x_r, multiply};
+use crate::transcript::RngTranscript;
+use crate::{MultiPoly, UniPoly};
+
+// Re-indexes the variables of `g` by `offset` inside a polynomial of `num_vars` variables.
+fn shift(g: &MultiPoly<Fr>, offset: usize, num_vars: usize) -> MultiPoly<Fr> {
+    let terms = g
+        .terms
+        .iter()
+        .map(|(c, t)| (*c, SparseTerm::new(t.iter().map(|(v, p)| (v + offset, *p)).collect())))
+        .collect();
+
+    MultiPoly::from_coefficients_vec(num_vars, terms)
+}
+
+#[test]
+fn test_reduce_two_claims_after_sumcheck() {
+    let mut rng = rand::thread_rng();
+    let k = 2;
+
+    // Next-layer MLE W over k variables.
+    let w = MultiPoly::from_coefficients_vec(
+        k,
+        vec![
+            (Fr::rand(&mut rng), SparseTerm::new(vec![])),
+            (Fr::rand(&mut rng), SparseTerm::new(vec![(0, 1)])),
+            (Fr::rand(&mut rng), SparseTerm::new(vec![(1, 1)])),
+            (Fr::rand(&mut rng), SparseTerm::new(vec![(0, 1), (1, 1)])),
+        ],
+    );
+
+    // A single multiplication gate wired to inputs b = 10 and c = 01.
+    let mult = build_eq_x_r(&[Fr::one(), 0.into(), 0.into(), Fr::one()]);
+    let f = multiply(&multiply(&mult, &shift(&w, 0, 2 * k)), &shift(&w, k, 2 * k));
+
+    // Layer sumcheck over (b, c).
+    let asserted_sum = IPForSumcheck::<Fr>::compute_sum(&f).unwrap();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(f.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(2 * k);
+    let mut verifier_msg = None;
+    for _ in 0..2 * k {
+        let prover_message = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    let v_out = IPForSumcheck::<Fr>::partial_verify(verifier_state, asserted_sum).unwrap();
+
+    // The prover makes claims about W at b* and c*, which the verifier checks via the wiring predicate.
+    let (b_star, c_star) = v_out.r_vec.split_at(k);
+    let (claim_b, claim_c) = (w.evaluate(&b_star.to_vec()), w.evaluate(&c_star.to_vec()));
+    assert_eq!(mult.evaluate(&v_out.r_vec) * claim_b * claim_c, v_out.expected_evaluation);
+
+    // Two claims become one.
+    let msg = reduce_two_claims_prover(&w, b_star, c_star).unwrap();
+    let (point, claim) =
+        reduce_two_claims_verifier(&msg, b_star, c_star, claim_b, claim_c, &mut rng).unwrap();
+    assert_eq!(w.evaluate(&point), claim);
+
+    // A false claim about W(b*) is caught.
+    let lie = claim_b + Fr::one();
+    assert_eq!(
+        reduce_two_claims_verifier(&msg, b_star, c_star, lie, claim_c, &mut rng),
+        Err(crate::Error::ClaimMismatch { index: 0 }),
+    );
+}
+
+fn random_mle(num_vars: usize, rng: &mut impl ark_std::rand::RngCore) -> DenseMLE<Fr> {
+    DenseMLE::from_evaluations(num_vars, (0..1 << num_vars).map(|_| Fr::rand(rng)).collect()).unwrap()
+}
+
+#[test]
+fn test_folded_sumcheck() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 4;
+    let (add, mult, w) = (random_mle(num_vars, &mut rng), random_mle(num_vars, &mut rng), random_mle(num_vars, &mut rng));
+
+    let run = |claim_offset: Fr, rng: &mut rand::rngs::ThreadRng| {
+        let mut prover = FoldedSumcheckProver::new(add.clone(), mult.clone(), w.clone()).unwrap();
+        let asserted_sum = prover.asserted_sum();
+        let mut verifier = FoldedSumcheckVerifier::<Fr>::new(num_vars);
+        let mut verifier_msg = None;
+        for _ in 0..num_vars {
+            let prover_msg = prover.prove_round(&verifier_msg);
+            assert!(prover_msg.degree() <= 3);
+            verifier_msg = verifier.verify_round(prover_msg, &mut RngTranscript::new(&mut *rng)).ok();
+        }
+        verifier.verify(&add, &mult, &w, asserted_sum + claim_offset)
+    };
+    assert!(run(Fr::from(0), &mut rng).is_ok());
+    assert!(run(Fr::one(), &mut rng).is_err());
+
+    // The sum agrees with the expanded product of the three factors.
+    let expanded: Fr = (0..1 << num_vars).map(|b| add.evaluations[b] * mult.evaluations[b] * w.evaluations[b]).sum();
+    assert_eq!(FoldedSumcheckProver::new(add.clone(), mult.clone(), w.clone()).unwrap().asserted_sum(), expanded);
+
+    // A round polynomial of degree 4 is rejected on arrival.
+    let mut verifier = FoldedSumcheckVerifier::<Fr>::new(num_vars);
+    let high = ProverMsg::new(UniPoly::from_coefficients_vec(vec![(4, Fr::one())]));
+    assert_eq!(
+        verifier.verify_round(high, &mut RngTranscript::new(&mut rng)),
+        Err(crate::Error::DegreeTooHigh { round: 1, got: 4, bound: 3 })
+    );
+
+    // Factors without variables make an instance with no rounds.
+    let constant = |c: u64| DenseMLE::from_evaluations(0, vec![Fr::from(c)]).unwrap();
+    let prover = FoldedSumcheckProver::new(constant(2), constant(3), constant(5)).unwrap();
+    assert_eq!(prover.asserted_sum(), Fr::from(30));
+    let verifier = FoldedSumcheckVerifier::<Fr>::new(0);
+    assert!(verifier.verify(&constant(2), &constant(3), &constant(5), Fr::from(30)).is_ok());
+    assert!(verifier.verify(&constant(2), &constant(3), &constant(5), Fr::from(31)).is_err());
+
+    // All three factors must share their number of variables.
+    assert!(matches!(
+        FoldedSumcheckProver::new(add.clone(), random_mle(3, &mut rng), w.clone()),
+        Err(crate::Error::PolynomialError(_))
+    ));
+}
@@ -1,14 +1,36 @@
 //! A crate for the Sumcheck protocol
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs `alloc`, so that the
+//! provers and verifiers can run in embedded or enclave environments. Everything that needs an
+//! operating system, such as the channels of `interactive`, is left out.
+#![cfg_attr(not(feature = "std"), no_std)]
 
 pub use error::Error;
 
 #[macro_use]
 extern crate ark_std;
+extern crate alloc;
 
 /// error for this crate
 mod error;
 
+pub mod applications;
+pub mod circuit_prover;
+pub mod commitment;
+pub mod extension;
+pub mod gkr;
+pub mod mle;
 pub mod naive_sumcheck;
+pub mod poly_utils;
+#[cfg(feature = "std")]
+pub mod soundness;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod traits;
+pub mod transcript;
+pub mod verify;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 use ark_poly::polynomial::multivariate::{SparsePolynomial, SparseTerm};
 use ark_poly::polynomial::univariate::SparsePolynomial as UniSparsePolynomial;
@@ -0,0 +1,75 @@
+//! Multilinear polynomials stored as evaluation tables
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::vec::Vec;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Dense multilinear extension: the evaluations of a multilinear polynomial over `{0,1}^num_vars`
+///
+/// Entries are ordered as in `to_binary_vec`, i.e. the first variable is the most significant bit.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq, Eq)]
+pub struct DenseMLE<F: Field> {
+    /// Number of variables
+    pub num_vars: usize,
+    /// Evaluations over the boolean hypercube
+    pub evaluations: Vec<F>,
+}
+
+impl<F: Field> DenseMLE<F> {
+    /// Wraps a table of `2^num_vars` hypercube evaluations.
+    pub fn from_evaluations(num_vars: usize, evaluations: Vec<F>) -> Result<Self, crate::Error> {
+        if evaluations.len() != 1 << num_vars {
+            return Err(crate::Error::PolynomialError(format!(
+                "Expected {} evaluations for {} variables, got {}.",
+                1_usize << num_vars,
+                num_vars,
+                evaluations.len(),
+            )));
+        }
+
+        Ok(Self { num_vars, evaluations })
+    }
+
+    /// Fixes the first variable to `r`, halving the table.
+    ///
+    /// Panics on an MLE without variables, which has none left to fix.
+    pub fn fix_first_variable(&self, r: F) -> Self {
+        if self.num_vars == 0 {
+            panic!("Cannot fix a variable of a 0-variate MLE...");
+        }
+
+        let half = self.evaluations.len() / 2;
+        let evaluations = (0..half)
+            .map(|b| {
+                let (low, high) = (self.evaluations[b], self.evaluations[b + half]);
+                low + r * (high - low)
+            })
+            .collect();
+
+        Self { num_vars: self.num_vars - 1, evaluations }
+    }
+
+    /// Evaluates the multilinear extension at `point`, by fixing one variable at a time.
+    pub fn evaluate(&self, point: &[F]) -> Result<F, crate::Error> {
+        if point.len() != self.num_vars {
+            return Err(crate::Error::PolynomialError(format!(
+                "Cannot evaluate a {}-variate MLE at a point with {} coordinates.",
+                self.num_vars,
+                point.len(),
+            )));
+        }
+
+        let folded = point
+            .iter()
+            .fold(self.clone(), |mle, r| mle.fix_first_variable(*r));
+
+        Ok(folded.evaluations[0])
+    }
+
+    /// Sum of all evaluations over the boolean hypercube.
+    pub fn sum(&self) -> F {
+        self.evaluations.iter().sum()
+    }
+}
@@ -0,0 +1,46 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::UniformRand;
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::{DenseMVPolynomial, Polynomial};
+use ark_std::vec::Vec;
+
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::prover::to_binary_vec;
+use crate::MultiPoly;
+
+#[test]
+fn test_dense_mle_matches_sparse_form() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 4;
+
+    // A random multilinear polynomial with one term per subset of the variables.
+    let terms: Vec<(Fr, SparseTerm)> = (0..(1 << num_vars))
+        .map(|mask: usize| {
+            let vars = (0..num_vars).filter(|i| mask >> i & 1 == 1).map(|i| (i, 1)).collect();
+            (Fr::rand(&mut rng), SparseTerm::new(vars))
+        })
+        .collect();
+    let g = MultiPoly::from_coefficients_vec(num_vars, terms);
+
+    let evaluations = (0..(1 << num_vars))
+        .map(|i| g.evaluate(&to_binary_vec(i, num_vars)))
+        .collect();
+    let mle = DenseMLE::from_evaluations(num_vars, evaluations).unwrap();
+
+    for _ in 0..8 {
+        let point: Vec<Fr> = (0..num_vars).map(|_| Fr::rand(&mut rng)).collect();
+        assert_eq!(mle.evaluate(&point).unwrap(), g.evaluate(&point));
+    }
+
+    assert!(mle.evaluate(&[Fr::from(1)]).is_err());
+    assert!(DenseMLE::from_evaluations(num_vars, vec![Fr::from(1); 3]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Cannot fix a variable of a 0-variate MLE")]
+fn test_fix_variable_of_constant_mle() {
+    let mle = DenseMLE::from_evaluations(0, vec![Fr::from(3)]).unwrap();
+    assert_eq!(mle.evaluate(&[]).unwrap(), Fr::from(3));
+
+    mle.fix_first_variable(Fr::from(1));
+}
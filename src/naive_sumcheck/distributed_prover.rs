@@ -0,0 +1,224 @@
+//! Prover sharded over independent workers
+//!
+//! With `2^k` workers, worker `w` owns the points of the hypercube whose last `k` variables
+//! spell out `w`, i.e. every `2^k`-th point in the order of `to_binary_vec`. The shard
+//! variables are the ones fixed last, so every worker contributes a partial sum of the round
+//! polynomial in each of the first `num_vars - k` rounds; in the last `k` rounds each remaining
+//! point is left to the worker whose already fixed shard bits are zero, and the others send
+//! the zero polynomial. The coordinator only adds the contributions up.
+//!
+//! Workers share nothing but `g`, so each one may live behind an RPC layer: the coordinator
+//! tags every challenge with its round, and a worker refuses a challenge for any other round,
+//! so all workers fix the same challenges in the same order.
+use ark_ff::{Field, Zero};
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::VerifierMsg,
+};
+use crate::{MultiPoly, UniPoly};
+
+/// Challenge broadcast by the coordinator to every worker
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundChallenge<F: Field> {
+    /// Round the workers are asked to contribute to, counted from 1
+    pub round: usize,
+    /// The verifier's challenge for the previous round, none in round 1
+    pub randomness: Option<F>,
+}
+
+/// State of one worker, holding its own copy of `g`
+#[derive(Clone, Debug)]
+pub struct WorkerState<F: Field + From<i32>> {
+    /// Prover state over the whole of `g`, with the challenges fixed so far
+    state: ProverState<F>,
+    /// Index of the worker, i.e. its assignment to the last `log2(num_workers)` variables
+    worker_index: usize,
+    /// Number of shard variables
+    shard_vars: usize,
+}
+
+/// Coordinator that broadcasts challenges and sums the workers' contributions
+#[derive(Clone, Debug)]
+pub struct Coordinator<F: Field> {
+    /// Number of workers, a power of two
+    num_workers: usize,
+    /// Number of variables of `g`
+    num_vars: usize,
+    /// The last round broadcast
+    round: usize,
+    /// randomness provided by the verifier
+    randomness: Vec<F>,
+}
+
+/// A coordinator and its workers, run in-process
+pub struct DistributedProver<F: Field + From<i32>> {
+    /// The coordinator
+    pub coordinator: Coordinator<F>,
+    /// Every worker, in index order
+    pub workers: Vec<WorkerState<F>>,
+}
+
+/// Number of shard variables for `num_workers` workers, failing unless it is a power of two
+/// no larger than the hypercube.
+fn shard_vars(num_vars: usize, num_workers: usize) -> Result<usize, crate::Error> {
+    if !num_workers.is_power_of_two() || num_workers.trailing_zeros() as usize > num_vars {
+        return Err(crate::Error::ProtocolError(format!(
+            "Expected a power of two of at most 2^{num_vars} workers, got {num_workers}."
+        )));
+    }
+
+    Ok(num_workers.trailing_zeros() as usize)
+}
+
+impl<F: Field + From<i32>> WorkerState<F> {
+    /// Initializes worker `worker_index` of `num_workers` for `g`.
+    pub fn new(g: MultiPoly<F>, worker_index: usize, num_workers: usize) -> Result<Self, crate::Error> {
+        let shard_vars = shard_vars(g.num_vars, num_workers)?;
+        if worker_index >= num_workers {
+            return Err(crate::Error::ProtocolError(format!(
+                "Worker index {worker_index} is out of range for {num_workers} workers."
+            )));
+        }
+
+        Ok(Self {
+            state: IPForSumcheck::prover_init(g)?,
+            worker_index,
+            shard_vars,
+        })
+    }
+
+    /// Fixes the broadcast challenge, if any, and returns this worker's share of the round
+    /// polynomial for the next variable.
+    ///
+    /// Fails if `challenge` is not for the round this worker is about to contribute to.
+    pub fn round_contribution(&mut self, challenge: &RoundChallenge<F>) -> Result<UniPoly<F>, crate::Error> {
+        let num_vars = self.state.g.num_vars;
+        if challenge.round != self.state.round + 1
+            || challenge.round > num_vars
+            || challenge.randomness.is_some() != (self.state.round > 0)
+        {
+            return Err(crate::Error::ProtocolError(format!(
+                "Worker {} expected a challenge for round {}, got one for round {}.",
+                self.worker_index,
+                self.state.round + 1,
+                challenge.round
+            )));
+        }
+        if let Some(r) = challenge.randomness {
+            self.state.randomness.push(r);
+        }
+        self.state.round += 1;
+
+        // Bit of this worker's index for shard variable `j`.
+        let first_shard = num_vars - self.shard_vars;
+        let bit = |j: usize| (self.worker_index >> (num_vars - 1 - j)) & 1;
+
+        // The points with a fixed shard bit belong to the worker whose bit is zero.
+        let i = self.state.randomness.len();
+        if (first_shard..=i).any(|j| bit(j) == 1) {
+            return Ok(UniPoly::zero());
+        }
+
+        // Points are laid out as `evaluate_gi` expects, from the current variable on.
+        let free = first_shard.saturating_sub(i + 1);
+        let mut points = vec![F::zero(); num_vars - i];
+        for j in (i + 1 + free)..num_vars {
+            points[j - i] = F::from(bit(j) as u64);
+        }
+
+        let mut gi = UniPoly::zero();
+        for b in 0..(1_usize << free) {
+            for j in 0..free {
+                points[1 + j] = F::from(((b >> (free - 1 - j)) & 1) as u64);
+            }
+            gi = gi + self.state.evaluate_gi(points.clone());
+        }
+
+        Ok(gi)
+    }
+
+    /// Index of this worker.
+    pub fn worker_index(&self) -> usize {
+        self.worker_index
+    }
+}
+
+impl<F: Field> Coordinator<F> {
+    /// Initializes the coordinator of `num_workers` workers for a polynomial in `num_vars`
+    /// variables.
+    pub fn new(num_vars: usize, num_workers: usize) -> Result<Self, crate::Error> {
+        shard_vars(num_vars, num_workers)?;
+
+        Ok(Self {
+            num_workers,
+            num_vars,
+            round: 0,
+            randomness: Vec::with_capacity(num_vars),
+        })
+    }
+
+    /// Records the verifier's message for the previous round, if any, and returns the
+    /// challenge to broadcast to every worker for the next one.
+    pub fn challenge(&mut self, v_msg: &Option<VerifierMsg<F>>) -> RoundChallenge<F> {
+        if self.round >= self.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if self.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            self.randomness.push(msg.randomness);
+        } else if self.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+        self.round += 1;
+
+        RoundChallenge { round: self.round, randomness: v_msg.as_ref().map(|msg| msg.randomness) }
+    }
+
+    /// Sums the contributions of every worker to the current round into the prover message.
+    pub fn combine(&self, contributions: Vec<UniPoly<F>>) -> Result<ProverMsg<F>, crate::Error> {
+        if contributions.len() != self.num_workers {
+            return Err(crate::Error::ProtocolError(format!(
+                "Expected {} contributions, got {}.",
+                self.num_workers,
+                contributions.len()
+            )));
+        }
+
+        let gi = contributions
+            .into_iter()
+            .fold(UniPoly::zero(), |sum, gi| sum + gi);
+        Ok(ProverMsg::new(gi))
+    }
+}
+
+impl<F: Field + From<i32>> DistributedProver<F> {
+    /// Initializes a coordinator and `num_workers` workers for `g`.
+    pub fn new(g: MultiPoly<F>, num_workers: usize) -> Result<Self, crate::Error> {
+        let coordinator = Coordinator::new(g.num_vars, num_workers)?;
+        let workers = (0..num_workers)
+            .map(|w| WorkerState::new(g.clone(), w, num_workers))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { coordinator, workers })
+    }
+
+    /// Receive message from verifier, broadcast it to every worker, and combine their
+    /// contributions into the prover message.
+    pub fn prove_round(&mut self, v_msg: &Option<VerifierMsg<F>>) -> Result<ProverMsg<F>, crate::Error> {
+        let challenge = self.coordinator.challenge(v_msg);
+        let contributions = self
+            .workers
+            .iter_mut()
+            .map(|worker| worker.round_contribution(&challenge))
+            .collect::<Result<_, _>>()?;
+
+        self.coordinator.combine(contributions)
+    }
+}
@@ -0,0 +1,77 @@
+//! Evaluation of `g` over the whole boolean hypercube at once
+//!
+//! On `{0, 1}` every power `x_j^p` with `p >= 1` equals `x_j`, so a term only depends on the set
+//! of variables it contains, and equals its coefficient at the points where all of them are 1
+//! and zero elsewhere. The table of evaluations is then built by bucketing the coefficients by
+//! that set and folding one variable at a time, as in Horner's scheme: each step adds the half
+//! of the table where `x_j = 0` into the half where `x_j = 1`. This takes `v * 2^(v - 1)`
+//! additions and no multiplication, instead of evaluating every term at every point.
+//!
+//! The table takes `2^v` field elements. When only the sum is needed, `hypercube_sum` gets it
+//! from the terms directly.
+use ark_ff::Field;
+use ark_poly::polynomial::DenseMVPolynomial;
+use ark_std::vec::Vec;
+
+use crate::MultiPoly;
+
+/// Evaluations of `g` at every point of `{0, 1}^num_vars`, indexed with `x_0` as the most
+/// significant bit, i.e. in the order of `to_binary_vec`.
+///
+/// Panics if a term refers to a variable outside of `0..num_vars`, see `validate_poly`.
+pub fn hypercube_evaluations<F: Field>(g: &MultiPoly<F>) -> Vec<F> {
+    let v = g.num_vars();
+    check_variables(g);
+    let mut table = vec![F::zero(); 1 << v];
+
+    // Bucket each coefficient at the point whose ones are the variables of its term.
+    for (coeff, term) in g.terms() {
+        let support = term
+            .iter()
+            .filter(|(_, power)| *power > 0)
+            .fold(0_usize, |mask, (var, _)| mask | 1 << (v - 1 - var));
+        table[support] += coeff;
+    }
+
+    // Fold in one variable at a time, going from x_{v-1} (the least significant bit) up.
+    for j in 0..v {
+        let bit = 1 << j;
+        for mask in 0..table.len() {
+            if mask & bit == 0 {
+                let low = table[mask];
+                table[mask | bit] += low;
+            }
+        }
+    }
+
+    table
+}
+
+/// Sum of `g` over `{0, 1}^num_vars`, in one pass over the terms and without building the table
+/// of `hypercube_evaluations`.
+///
+/// A term in the variables `S` equals its coefficient at the `2^(v - |S|)` points where all of
+/// them are 1, and zero elsewhere. `S` only counts the distinct variables with a nonzero power,
+/// so terms that are not in canonical form are summed correctly too. Panics if a term refers to
+/// a variable outside of `0..num_vars`, see `validate_poly`.
+pub fn hypercube_sum<F: Field>(g: &MultiPoly<F>) -> F {
+    let v = g.num_vars();
+    check_variables(g);
+
+    g.terms()
+        .iter()
+        .map(|(coeff, term)| {
+            let mut vars: Vec<usize> =
+                term.iter().filter(|(_, power)| *power > 0).map(|(var, _)| *var).collect();
+            vars.sort_unstable();
+            vars.dedup();
+            *coeff * F::from(2_u64).pow([(v - vars.len()) as u64])
+        })
+        .sum()
+}
+
+fn check_variables<F: Field>(g: &MultiPoly<F>) {
+    if g.terms().iter().any(|(_, term)| term.iter().any(|(var, _)| *var >= g.num_vars())) {
+        panic!("Term refers to a variable outside of 0..num_vars...");
+    }
+}
@@ -0,0 +1,83 @@
+//! Linear-time prover for multilinear `g`
+//!
+//! The bookkeeping table algorithm from Thaler's book: the prover keeps the table `T` of
+//! `g(r_1, ..., r_{j-1}, b)` over every boolean suffix `b`, starting from the `2^v` hypercube
+//! evaluations of `g`. In round `j` it reads `g_j(0) = Σ_b T[b]` and `g_j(1) = Σ_b T[b + 2^(v-j)]`
+//! off the two halves of `T` in one pass, and once `r_j` arrives folds the halves into
+//! `T[b] = T[b] · (1 - r_j) + T[b + 2^(v-j)] · r_j`. Every round thus halves the work, for
+//! `O(2^v)` field operations in total instead of `O(2^v)` per round.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::{prover::ProverMsg, verifier::VerifierMsg};
+use crate::poly_utils::uni_poly_from_evaluations;
+
+/// Prover State over the bookkeeping table of multilinear `g`
+#[derive(Clone, Debug)]
+pub struct LinearTimeMleProver<F: Field> {
+    /// Number of variables of `g`
+    num_vars: usize,
+    /// Values of `g` with the challenges so far fixed, over the remaining hypercube
+    table: Vec<F>,
+    /// randomness provided by the verifier
+    randomness: Vec<F>,
+    /// The current round number
+    round: usize,
+}
+
+impl<F: Field> LinearTimeMleProver<F> {
+    /// Initialize prover to argue for the sum of `mle` over the boolean hypercube.
+    ///
+    /// An `mle` without variables is valid and has no rounds, as in `prover_init`.
+    pub fn new(mle: DenseMLE<F>) -> Self {
+        Self {
+            num_vars: mle.num_vars,
+            table: mle.evaluations,
+            randomness: Vec::with_capacity(mle.num_vars),
+            round: 0,
+        }
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    ///
+    /// Round `j` costs `O(2^(num_vars - j))` field operations.
+    pub fn prove_round(&mut self, v_msg: &Option<VerifierMsg<F>>) -> ProverMsg<F> {
+        if self.round >= self.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if self.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            self.randomness.push(msg.randomness);
+            let half = self.table.len() / 2;
+            for b in 0..half {
+                self.table[b] = self.table[b] + msg.randomness * (self.table[b + half] - self.table[b]);
+            }
+            self.table.truncate(half);
+        } else if self.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        let (low, high) = self.table.split_at(self.table.len() / 2);
+        let gi = uni_poly_from_evaluations(&[low.iter().sum(), high.iter().sum()]);
+
+        // Increment round
+        self.round += 1;
+
+        ProverMsg::new(gi)
+    }
+
+    /// Number of variables of `g`.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Number of messages sent so far.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
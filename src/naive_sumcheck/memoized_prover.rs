@@ -0,0 +1,183 @@
+//! Prover that tabulates `g` once and reuses the table across proof executions
+//!
+//! `g` is evaluated once on the grid `Π_j {0, ..., d_j}`, where `d_j` is the degree of `x_j` in
+//! `g` (at least 1), which for multilinear `g` is the usual table of `2^num_vars` evaluations over
+//! the boolean hypercube. Each round polynomial is read off the slices of the table for the
+//! current variable, and the verifier's challenge is then folded into the table by Lagrange
+//! interpolation along that variable, so no round ever goes back to the terms of `g`. The
+//! initial table is shared, so proving `g` again for other verifier randomness only costs the
+//! folding.
+
+use ark_ff::Field;
+use ark_poly::Polynomial;
+use ark_std::vec::Vec;
+use alloc::sync::Arc;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{max_degrees, VerifierMsg},
+};
+use crate::poly_utils::uni_poly_from_evaluations;
+use crate::MultiPoly;
+
+/// Evaluations of `g` over the grid `Π_j {0, ..., degrees[j]}`, with `x_0` most significant
+#[derive(Debug)]
+pub struct EvaluationTable<F: Field> {
+    /// Number of evaluation points minus one, per variable
+    pub degrees: Vec<usize>,
+    /// Evaluations, ordered lexicographically by point
+    pub evaluations: Vec<F>,
+}
+
+/// Prover State over a shared evaluation table
+#[derive(Clone, Debug)]
+pub struct MemoizedProverState<F: Field> {
+    /// Table of `g` before any variable is fixed, shared by every execution
+    pub table: Arc<EvaluationTable<F>>,
+    /// Table with the variables fixed so far folded in, once there is at least one
+    folded: Option<Vec<F>>,
+    /// randomness provided by the verifier
+    pub randomness: Vec<F>,
+    /// The current round number
+    pub round: usize,
+}
+
+impl<F: Field> EvaluationTable<F> {
+    /// Evaluates `g` on the grid of its degrees, failing if the grid does not fit in memory.
+    pub fn new(g: &MultiPoly<F>) -> Result<Self, crate::Error> {
+        let degrees: Vec<usize> = max_degrees(g).into_iter().map(|d| d.max(1)).collect();
+        let len = degrees
+            .iter()
+            .try_fold(1_usize, |len, d| len.checked_mul(d.checked_add(1)?))
+            .ok_or_else(|| {
+                crate::Error::ProtocolError(format!("Evaluation grid of degrees {degrees:?} is too large."))
+            })?;
+
+        let mut point = vec![F::zero(); degrees.len()];
+        let evaluations = (0..len)
+            .map(|i| {
+                let mut rest = i;
+                for (x, d) in point.iter_mut().zip(&degrees).rev() {
+                    *x = F::from((rest % (d + 1)) as u64);
+                    rest /= d + 1;
+                }
+                g.evaluate(&point)
+            })
+            .collect();
+
+        Ok(Self { degrees, evaluations })
+    }
+}
+
+impl<F: Field> MemoizedProverState<F> {
+    /// A fresh execution over the same table, i.e. a prover that has not sent any message yet.
+    pub fn restart(&self) -> Self {
+        Self {
+            table: Arc::clone(&self.table),
+            folded: None,
+            randomness: Vec::with_capacity(self.num_vars()),
+            round: 0,
+        }
+    }
+
+    /// Number of variables of `g`.
+    pub fn num_vars(&self) -> usize {
+        self.table.degrees.len()
+    }
+
+    /// The table over the variables that are not fixed yet.
+    fn current(&self) -> &[F] {
+        self.folded.as_deref().unwrap_or(&self.table.evaluations)
+    }
+
+    /// Evaluations of the current round polynomial at `X = 0..=d`, where `d` is the degree of
+    /// the current variable, summing each slice of the table over the boolean points only.
+    fn round_evaluations(&self) -> Vec<F> {
+        let degrees = &self.table.degrees[self.randomness.len()..];
+        let table = self.current();
+        let block = table.len() / (degrees[0] + 1);
+
+        // Offsets of the boolean points within a slice, built one variable at a time.
+        let mut offsets = vec![0];
+        let mut stride = block;
+        for d in &degrees[1..] {
+            stride /= d + 1;
+            offsets = offsets.into_iter().flat_map(|o| [o, o + stride]).collect();
+        }
+
+        (0..=degrees[0])
+            .map(|t| offsets.iter().map(|o| table[t * block + o]).sum())
+            .collect()
+    }
+
+    /// Fixes the current variable to `r`, interpolating each slice of the table at `r`.
+    fn fold(&mut self, r: F) {
+        let d = self.table.degrees[self.randomness.len()];
+        let table = self.current();
+        let block = table.len() / (d + 1);
+
+        // Lagrange basis over the nodes 0..=d, evaluated at r.
+        let nodes: Vec<F> = (0..=d).map(|t| F::from(t as u64)).collect();
+        let basis: Vec<F> = nodes
+            .iter()
+            .enumerate()
+            .map(|(t, xt)| {
+                let (num, den) = nodes.iter().enumerate().filter(|(s, _)| *s != t).fold(
+                    (F::one(), F::one()),
+                    |(num, den), (_, xs)| (num * (r - xs), den * (*xt - xs)),
+                );
+                num * den.inverse().expect("Interpolation nodes are distinct...")
+            })
+            .collect();
+
+        let folded = (0..block)
+            .map(|b| basis.iter().enumerate().map(|(t, l)| *l * table[t * block + b]).sum())
+            .collect();
+        self.folded = Some(folded);
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Initialize a prover for `g` that evaluates it once on its degree grid, see
+    /// `MemoizedProverState`.
+    ///
+    pub fn prover_init_memoized(g: &MultiPoly<F>) -> Result<MemoizedProverState<F>, crate::Error> {
+        Ok(MemoizedProverState {
+            table: Arc::new(EvaluationTable::new(g)?),
+            folded: None,
+            randomness: Vec::with_capacity(g.num_vars),
+            round: 0,
+        })
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    ///
+    /// Takes time linear in the size of the table over the remaining variables.
+    pub fn prove_round_memoized(
+        prover_state: &mut MemoizedProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        if prover_state.round >= prover_state.num_vars() {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if prover_state.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            prover_state.fold(msg.randomness);
+            prover_state.randomness.push(msg.randomness);
+        } else if prover_state.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        let gi = uni_poly_from_evaluations(&prover_state.round_evaluations());
+
+        // Increment round
+        prover_state.round += 1;
+
+        ProverMsg::new(gi)
+    }
+}
@@ -1,4 +1,18 @@
+pub mod distributed_prover;
+pub mod eval;
+pub mod linear_time;
+pub mod memoized_prover;
+pub mod product_prover;
 pub mod protocol;
+pub mod recursive;
+pub mod sqrt_space_prover;
+pub mod streaming_prover;
+pub mod univariate_skip;
+pub mod virtual_poly;
+pub mod weighted;
+pub mod zerocheck;
 
 #[cfg(test)]
+mod no_std_test;
+#[cfg(all(test, feature = "std"))]
 mod test;
\ No newline at end of file
@@ -0,0 +1,42 @@
+//! Tests that build without `std`
+//!
+//! `cargo test --no-default-features` runs only these, exercising the protocol through what the
+//! crate offers to `no_std` users, with a deterministic RNG.
+use ark_bls12_381::Fr as Fr;
+use ark_std::{rand::SeedableRng, vec::Vec};
+use rand_chacha::ChaCha20Rng;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{to_binary_vec, ProverMsg},
+};
+use crate::testing::random_sparse_polynomial;
+use crate::transcript::RngTranscript;
+
+#[test]
+fn test_to_binary_vec() {
+    assert_eq!(to_binary_vec::<Fr>(6, 4), [0, 1, 1, 0].map(Fr::from));
+    assert_eq!(to_binary_vec::<Fr>(6, 2), [1, 1, 0].map(Fr::from));
+    assert_eq!(to_binary_vec::<Fr>(0, 0), [Fr::from(0)]);
+}
+
+#[test]
+fn test_seeded_protocol() {
+    let mut rng = ChaCha20Rng::from_seed([7; 32]);
+    let g = random_sparse_polynomial::<Fr, _>(5, 10, 3, &mut rng);
+    let mut prover_state = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let asserted_sum = prover_state.asserted_sum();
+    let mut verifier_state = IPForSumcheck::verifier_init(g.num_vars);
+    let mut transcript = RngTranscript::new(ChaCha20Rng::from_seed([8; 32]));
+
+    // Messages go through the wire format, as they would between two devices.
+    let mut verifier_msg = None;
+    let mut frame = Vec::new();
+    for _ in 0..g.num_vars {
+        frame.clear();
+        IPForSumcheck::prove_round(&mut prover_state, &verifier_msg).write_framed(&mut frame).unwrap();
+        let prover_msg = ProverMsg::read_framed(&frame[..]).unwrap();
+        verifier_msg = Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+    IPForSumcheck::verify(&g, verifier_state, asserted_sum).expect("Failed to verify...");
+}
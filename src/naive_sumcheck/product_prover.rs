@@ -0,0 +1,109 @@
+//! Sumcheck over the product `f · g` of two multilinear polynomials
+//!
+//! The factors are kept in separate states, as when they are maintained by different parts of a
+//! larger protocol such as GKR, and the product is never formed: each round fixes the previous
+//! challenge in both factors independently, then evaluates `Σ_b f(t, b) · g(t, b)` at
+//! `t = 0, 1, 2` and interpolates the degree-2 round polynomial.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::poly_utils::uni_poly_from_evaluations;
+
+/// Prover State for one factor of a product
+#[derive(Clone, Debug)]
+pub struct ProductProverState<F: Field> {
+    /// Factor with the variables fixed so far already folded in
+    pub factor: DenseMLE<F>,
+    /// randomness provided by the verifier
+    pub randomness: Vec<F>,
+    /// The current round number
+    pub round: usize,
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Initialize the prover state of one factor of a product, see `prove_product_round`.
+    ///
+    /// A factor without variables is valid and has no rounds, as in `prover_init`.
+    pub fn prover_init_product(factor: DenseMLE<F>) -> ProductProverState<F> {
+        ProductProverState {
+            randomness: Vec::with_capacity(factor.num_vars),
+            factor,
+            round: 0,
+        }
+    }
+
+    /// Receive message from verifier, generate prover message for `Σ_x f(x) · g(x)`, and
+    /// proceed to next round in both factors.
+    ///
+    /// Both states must be in the same round of factors in the same number of variables.
+    pub fn prove_product_round(
+        f_state: &mut ProductProverState<F>,
+        g_state: &mut ProductProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        if f_state.round != g_state.round || f_state.factor.num_vars != g_state.factor.num_vars {
+            panic!("Factors should be in the same round...");
+        }
+        if f_state.round >= f_state.factor.num_vars + f_state.randomness.len() {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if f_state.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            for state in [&mut *f_state, &mut *g_state] {
+                state.randomness.push(msg.randomness);
+                state.factor = state.factor.fix_first_variable(msg.randomness);
+            }
+        } else if f_state.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        let (f, g) = (&f_state.factor.evaluations, &g_state.factor.evaluations);
+        let half = f.len() / 2;
+        let evals: Vec<F> = (0..3_u64)
+            .map(|t| {
+                let t = F::from(t);
+                (0..half)
+                    .map(|b| (f[b] + t * (f[b + half] - f[b])) * (g[b] + t * (g[b + half] - g[b])))
+                    .sum()
+            })
+            .collect();
+        let gi = uni_poly_from_evaluations(&evals);
+
+        // Increment round
+        f_state.round += 1;
+        g_state.round += 1;
+
+        ProverMsg::new(gi)
+    }
+
+    /// Full verification for the product `f · g`.
+    ///
+    /// Every round polynomial has degree at most 2, and the final check only needs `f` and `g`
+    /// evaluated at `r_vec`.
+    pub fn verify_product(
+        f: &DenseMLE<F>,
+        g: &DenseMLE<F>,
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        verifier_state.check_degrees(|_| 2)?;
+
+        let v_out = Self::partial_verify(verifier_state, asserted_sum)?;
+        let product_eval = f.evaluate(&v_out.r_vec)? * g.evaluate(&v_out.r_vec)?;
+        if product_eval == v_out.expected_evaluation {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, product_eval))
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Shareable verifier for async contexts
+//!
+//! `AsyncSumcheckVerifier` keeps its `VerifierState` and transcript behind an `Arc<Mutex<_>>`,
+//! so it can be cloned into several futures that each feed it prover messages through `&self`.
+//! The lock is never held across an `.await`.
+use ark_ff::Field;
+use ark_std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::transcript::SumcheckTranscript;
+use crate::MultiPoly;
+
+/// Verifier state together with the transcript its challenges are drawn from
+struct Shared<F: Field, T: SumcheckTranscript<F>> {
+    /// State of the (interactive) verifier
+    state: VerifierState<F>,
+    /// Source of the verifier's challenges
+    transcript: T,
+}
+
+/// Verifier with interior mutability, cheap to clone
+pub struct AsyncSumcheckVerifier<F: Field, T: SumcheckTranscript<F>> {
+    /// State shared by every clone
+    inner: Arc<Mutex<Shared<F, T>>>,
+}
+
+impl<F: Field, T: SumcheckTranscript<F>> Clone for AsyncSumcheckVerifier<F, T> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+impl<F: Field, T: SumcheckTranscript<F>> AsyncSumcheckVerifier<F, T> {
+    /// Initializes a verifier for a polynomial in `num_variables` variables.
+    pub fn new(num_variables: usize, transcript: T) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Shared {
+                state: IPForSumcheck::verifier_init(num_variables),
+                transcript,
+            })),
+        }
+    }
+
+    /// Locks the shared state.
+    fn lock(&self) -> Result<MutexGuard<'_, Shared<F, T>>, crate::Error> {
+        self.inner
+            .lock()
+            .map_err(|_| crate::Error::ProtocolError("Verifier lock is poisoned.".into()))
+    }
+
+    /// Runs `verify_round` on `msg` and returns the verifier's challenge.
+    pub async fn receive_message(&self, msg: ProverMsg<F>) -> Result<VerifierMsg<F>, crate::Error> {
+        let mut shared = self.lock()?;
+        let Shared { state, transcript } = &mut *shared;
+
+        IPForSumcheck::verify_round(msg, state, transcript)
+    }
+
+    /// If all rounds have been received
+    pub fn is_finished(&self) -> Result<bool, crate::Error> {
+        Ok(self.lock()?.state.finished)
+    }
+
+    /// Full verification of the rounds received so far.
+    ///
+    /// The recorded rounds are consumed and the verifier starts over with a fresh state.
+    pub fn verify(&self, g: &MultiPoly<F>, asserted_sum: F) -> Result<(), crate::Error> {
+        let mut shared = self.lock()?;
+        let num_vars = shared.state.num_vars;
+        let state = ark_std::mem::replace(&mut shared.state, IPForSumcheck::verifier_init(num_vars));
+        drop(shared);
+
+        IPForSumcheck::verify(g, state, asserted_sum)
+    }
+}
@@ -0,0 +1,216 @@
+//! Complete interactive executions of the Sumcheck protocol
+//!
+//! Either in a single call, or split into `run_prover_async` and `run_verifier_async`, which run
+//! the two parties on their own threads (or processes) and only exchange messages through a
+//! `MsgChannel`. The verifier does not send its last challenge, which the prover never uses.
+//! `run_prover_over_stream` and `run_verifier_over_stream` do the same over a byte stream,
+//! framing the messages as in `wire`.
+#[cfg(feature = "std")]
+use std::{
+    io,
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    time::Duration,
+};
+
+use ark_ff::Field;
+use ark_poly::Polynomial;
+use ark_std::{
+    io::{Read, Write},
+    rand::RngCore,
+};
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    proof::SumcheckProof,
+    prover::ProverMsg,
+    verifier::{VerifierMsg, VerifierOutput},
+    wire::FramedStream,
+};
+use crate::transcript::{RngTranscript, SumcheckTranscript};
+use crate::MultiPoly;
+
+/// One end of a duplex channel, sending `Out` and receiving `In`
+///
+/// A closed channel or a timeout must be reported as an error, typically `Error::IOError`.
+pub trait MsgChannel<Out, In> {
+    /// Sends `msg` to the other end.
+    fn send(&mut self, msg: Out) -> Result<(), crate::Error>;
+    /// Waits for the next message from the other end.
+    fn recv(&mut self) -> Result<In, crate::Error>;
+}
+
+/// One end of a pair of `std::sync::mpsc` channels
+#[cfg(feature = "std")]
+pub struct MpscChannel<Out, In> {
+    /// Sender to the other end
+    sender: Sender<Out>,
+    /// Receiver from the other end
+    receiver: Receiver<In>,
+    /// How long `recv` waits before giving up, forever if none
+    timeout: Option<Duration>,
+}
+
+#[cfg(feature = "std")]
+impl<Out, In> MpscChannel<Out, In> {
+    /// Both ends of a fresh duplex channel, without timeout.
+    pub fn pair() -> (Self, MpscChannel<In, Out>) {
+        let (out_sender, out_receiver) = mpsc::channel();
+        let (in_sender, in_receiver) = mpsc::channel();
+
+        (
+            Self { sender: out_sender, receiver: in_receiver, timeout: None },
+            MpscChannel { sender: in_sender, receiver: out_receiver, timeout: None },
+        )
+    }
+
+    /// Makes `recv` fail with a `TimedOut` I/O error after waiting for `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Out, In> MsgChannel<Out, In> for MpscChannel<Out, In> {
+    fn send(&mut self, msg: Out) -> Result<(), crate::Error> {
+        self.sender.send(msg).map_err(|_| {
+            crate::Error::IOError(io::Error::new(io::ErrorKind::BrokenPipe, "Channel is closed."))
+        })
+    }
+
+    fn recv(&mut self) -> Result<In, crate::Error> {
+        let closed = || io::Error::new(io::ErrorKind::UnexpectedEof, "Channel is closed.");
+        match self.timeout {
+            None => self.receiver.recv().map_err(|_| closed().into()),
+            Some(timeout) => self.receiver.recv_timeout(timeout).map_err(|e| match e {
+                RecvTimeoutError::Timeout => {
+                    io::Error::new(io::ErrorKind::TimedOut, "Timed out waiting for a message.").into()
+                }
+                RecvTimeoutError::Disconnected => closed().into(),
+            }),
+        }
+    }
+}
+
+impl<F: Field + From<i32>> IPForSumcheck<F> {
+    /// Runs the whole protocol for `g` between an honest prover and a verifier drawing its
+    /// challenges from `rng`, including the final oracle query.
+    ///
+    /// Returns the sum claimed by the prover along with the verifier's output.
+    pub fn run_interactive_protocol<R: RngCore>(
+        g: &MultiPoly<F>,
+        rng: &mut R,
+    ) -> Result<(F, VerifierOutput<F>), crate::Error> {
+        let asserted_sum = Self::compute_sum(g)?;
+        let v_out = Self::run_interactive_protocol_with_sum(g, asserted_sum, rng)?;
+
+        Ok((asserted_sum, v_out))
+    }
+
+    /// Same as `run_interactive_protocol`, for a given `asserted_sum`, e.g. a wrong one to
+    /// check that the verifier rejects it.
+    ///
+    pub fn run_interactive_protocol_with_sum<R: RngCore>(
+        g: &MultiPoly<F>,
+        asserted_sum: F,
+        rng: &mut R,
+    ) -> Result<VerifierOutput<F>, crate::Error> {
+        let mut transcript = RngTranscript::new(rng);
+        let mut prover_state = Self::prover_init(g.clone())?;
+        let mut verifier_state = Self::verifier_init(g.num_vars);
+        let mut verifier_msg = None;
+
+        for _ in 0..g.num_vars {
+            let prover_msg = Self::prove_round(&mut prover_state, &verifier_msg);
+            verifier_msg = Some(Self::verify_round(prover_msg, &mut verifier_state, &mut transcript)?);
+        }
+
+        let v_out = Self::partial_verify(verifier_state, asserted_sum)?;
+        let g_eval = g.evaluate(&v_out.r_vec);
+        if g_eval == v_out.expected_evaluation {
+            Ok(v_out)
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, g_eval))
+        }
+    }
+
+    /// Runs the whole protocol for `g` as `run_interactive_protocol`, and returns the messages
+    /// exchanged once the verifier accepts.
+    pub fn run<R: RngCore>(g: &MultiPoly<F>, rng: &mut R) -> Result<SumcheckProof<F>, crate::Error> {
+        let mut transcript = RngTranscript::new(rng);
+        let mut prover_state = Self::prover_init(g.clone())?;
+        let asserted_sum = prover_state.asserted_sum();
+        let mut verifier_state = Self::verifier_init(g.num_vars);
+        let mut verifier_msg = None;
+
+        for _ in 0..g.num_vars {
+            let prover_msg = Self::prove_round(&mut prover_state, &verifier_msg);
+            verifier_msg = Some(Self::verify_round(prover_msg, &mut verifier_state, &mut transcript)?);
+        }
+        Self::verify(g, &verifier_state, asserted_sum)?;
+
+        let (verifier_randomness, partial_sums) = verifier_state.into_parts();
+        Ok(SumcheckProof {
+            prover_messages: partial_sums.into_iter().map(ProverMsg::new).collect(),
+            verifier_randomness,
+        })
+    }
+
+    /// Runs an honest prover for `g`, sending its messages through `channel` and waiting for a
+    /// challenge after each of them but the last.
+    pub fn run_prover_async<C: MsgChannel<ProverMsg<F>, VerifierMsg<F>>>(
+        g: MultiPoly<F>,
+        channel: &mut C,
+    ) -> Result<(), crate::Error> {
+        let num_vars = g.num_vars;
+        let mut prover_state = Self::prover_init(g)?;
+        let mut verifier_msg = None;
+
+        for round in 1..=num_vars {
+            channel.send(Self::prove_round(&mut prover_state, &verifier_msg))?;
+            if round < num_vars {
+                verifier_msg = Some(channel.recv()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs the verifier for the claim that `g` sums to `asserted_sum`, answering every message
+    /// received through `channel` but the last with a challenge from `transcript`, then
+    /// checking the rounds and querying `g`.
+    pub fn run_verifier_async<T: SumcheckTranscript<F>, C: MsgChannel<VerifierMsg<F>, ProverMsg<F>>>(
+        g: &MultiPoly<F>,
+        asserted_sum: F,
+        transcript: &mut T,
+        channel: &mut C,
+    ) -> Result<(), crate::Error> {
+        let mut verifier_state = Self::verifier_init(g.num_vars);
+
+        for round in 1..=g.num_vars {
+            let verifier_msg = Self::verify_round(channel.recv()?, &mut verifier_state, transcript)?;
+            if round < g.num_vars {
+                channel.send(verifier_msg)?;
+            }
+        }
+
+        Self::verify(g, verifier_state, asserted_sum)
+    }
+
+    /// Runs an honest prover for `g` over `stream`, e.g. a `TcpStream` to the verifier, see
+    /// `run_prover_async`.
+    pub fn run_prover_over_stream<S: Read + Write>(g: MultiPoly<F>, stream: S) -> Result<(), crate::Error> {
+        Self::run_prover_async(g, &mut FramedStream(stream))
+    }
+
+    /// Runs the verifier for the claim that `g` sums to `asserted_sum` over `stream`, e.g. a
+    /// `TcpStream` to the prover, see `run_verifier_async`.
+    pub fn run_verifier_over_stream<T: SumcheckTranscript<F>, S: Read + Write>(
+        g: &MultiPoly<F>,
+        asserted_sum: F,
+        transcript: &mut T,
+        stream: S,
+    ) -> Result<(), crate::Error> {
+        Self::run_verifier_async(g, asserted_sum, transcript, &mut FramedStream(stream))
+    }
+}
@@ -1,12 +1,45 @@
 //! Interactive Proof system for the Sumcheck protocol
 
 use ark_ff::Field;
-use ark_std::marker::PhantomData;
+use ark_serialize::SerializationError;
+use ark_std::{io, marker::PhantomData};
 
+#[cfg(feature = "std")]
+pub mod async_verifier;
+pub mod interactive;
+pub mod proof;
 pub mod prover;
+#[cfg(feature = "serde")]
+mod serde_support;
+pub mod typestate;
 pub mod verifier;
+pub mod wire;
+pub mod zk;
 
 /// Interactive Proof system for the Sumcheck protocol
 pub struct IPForSumcheck<F: Field> {
     _marker: PhantomData<F>,   // cache field F
-}
\ No newline at end of file
+}
+
+/// Version of the wire format of `ProverMsg` and `VerifierMsg`, written before every message
+pub const MESSAGE_VERSION: u8 = 1;
+
+/// Rejects messages written in a version of the wire format this crate cannot read.
+///
+/// The error is wrapped in an I/O error so that it survives `CanonicalDeserialize`, and is
+/// recovered as `Error::DeserializeError` when converted into a crate error.
+pub(crate) fn check_message_version(version: u8) -> Result<(), SerializationError> {
+    if version == MESSAGE_VERSION {
+        Ok(())
+    } else {
+        Err(invalid_data(crate::Error::DeserializeError(format!(
+            "Unknown message version {version}, expected {MESSAGE_VERSION}."
+        ))))
+    }
+}
+
+/// Wraps `e` in an I/O error, so that it is recovered unchanged when the `SerializationError`
+/// is converted back into a crate error.
+pub(crate) fn invalid_data(e: crate::Error) -> SerializationError {
+    SerializationError::IoError(io::Error::new(io::ErrorKind::InvalidData, e))
+}
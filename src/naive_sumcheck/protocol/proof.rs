@@ -0,0 +1,463 @@
+//! Recorded executions of the Sumcheck protocol
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError};
+use ark_std::{io, rand::SeedableRng, vec::Vec};
+use rand_chacha::ChaCha20Rng;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{max_degrees, DegreeInfo, VerifierMsg},
+};
+use crate::poly_utils::{is_canonical_uni_poly, validate_poly};
+use crate::transcript::{FsConfig, RngTranscript, SumcheckTranscript};
+use crate::MultiPoly;
+
+/// Sumcheck Proof, i.e. the messages exchanged by the prover and the verifier in every round
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq, Eq)]
+pub struct SumcheckProof<F: Field> {
+    /// the message sent by the prover at each round
+    pub prover_messages: Vec<ProverMsg<F>>,
+    /// the random field element sampled by the verifier at each round
+    pub verifier_randomness: Vec<F>,
+}
+
+/// Non-interactive Sumcheck proof without its challenges, which the verifier re-derives from the
+/// transcript, see `SumcheckProof::compress_non_interactive`
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq, Eq)]
+pub struct CompressedSumcheckProof<F: Field> {
+    /// the message sent by the prover at each round
+    pub prover_messages: Vec<ProverMsg<F>>,
+}
+
+/// Size of a proof, as reported by `SumcheckProof::stats`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofStats {
+    /// Number of complete rounds recorded in the proof
+    pub num_rounds: usize,
+    /// Degree of the prover's polynomial in every round
+    pub degrees: Vec<usize>,
+    /// Size of the compressed `CanonicalSerialize` encoding
+    pub bytes_compressed: usize,
+    /// Size of the uncompressed `CanonicalSerialize` encoding
+    pub bytes_uncompressed: usize,
+}
+
+/// Tag at the start of every proof encoded by `SumcheckProof::to_bytes`
+pub const PROOF_MAGIC: [u8; 4] = *b"SCPF";
+
+/// Version of the format written by `SumcheckProof::to_bytes`
+pub const PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Iterator over the rounds of a recorded proof, yielding one `(ProverMsg, VerifierMsg)` pair per round
+pub struct ProofTranscript<F: Field> {
+    /// The recorded proof
+    proof: SumcheckProof<F>,
+    /// Index of the next round to yield
+    round: usize,
+}
+
+impl<F: Field> SumcheckProof<F> {
+    /// Number of complete rounds recorded in the proof.
+    pub fn num_rounds(&self) -> usize {
+        self.prover_messages.len().min(self.verifier_randomness.len())
+    }
+
+    /// Checks the shape of the proof before any verification: one prover message and one
+    /// challenge for each of the `num_vars` rounds, and a round polynomial of degree at most
+    /// `degree_bounds[i]` in every round `i` (from 0).
+    pub fn validate(&self, num_vars: usize, degree_bounds: &[usize]) -> Result<(), crate::Error> {
+        if degree_bounds.len() != num_vars {
+            return Err(crate::Error::ProtocolError(format!(
+                "Expected {num_vars} degree bounds, got {}.",
+                degree_bounds.len()
+            )));
+        }
+        if self.prover_messages.len() != num_vars || self.verifier_randomness.len() != num_vars {
+            return Err(crate::Error::RoundMismatch {
+                expected: num_vars,
+                got: self.num_rounds(),
+            });
+        }
+
+        match self
+            .prover_messages
+            .iter()
+            .zip(degree_bounds)
+            .enumerate()
+            .find(|(_, (msg, bound))| msg.degree() > **bound)
+        {
+            Some((i, (msg, bound))) => Err(crate::Error::DegreeTooHigh {
+                round: i + 1,
+                got: msg.degree(),
+                bound: *bound,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Size of the `CanonicalSerialize` encoding in `compress` mode.
+    pub fn serialized_size(&self, compress: Compress) -> usize {
+        CanonicalSerialize::serialized_size(self, compress)
+    }
+
+    /// Size of the compressed encoding, the one written by `to_bytes` after its header.
+    pub fn size_in_bytes(&self) -> usize {
+        self.serialized_size(Compress::Yes)
+    }
+
+    /// Length of the compressed encoding, measured by actually serializing the proof.
+    ///
+    /// Agrees with `size_in_bytes`, which computes the same length without encoding.
+    pub fn size_bytes(&self) -> usize {
+        let mut bytes = Vec::new();
+        // Writing into a `Vec` cannot fail.
+        self.serialize_compressed(&mut bytes).unwrap();
+
+        bytes.len()
+    }
+
+    /// Compressed size of every prover message, in round order.
+    pub fn per_round_sizes(&self) -> Vec<usize> {
+        self.prover_messages.iter().map(|msg| msg.compressed_size()).collect()
+    }
+
+    /// Number of rounds, round polynomial degrees and encoded sizes of the proof.
+    pub fn stats(&self) -> ProofStats {
+        ProofStats {
+            num_rounds: self.num_rounds(),
+            degrees: self.prover_messages.iter().map(|msg| msg.degree()).collect(),
+            bytes_compressed: self.serialized_size(Compress::Yes),
+            bytes_uncompressed: self.serialized_size(Compress::No),
+        }
+    }
+
+    /// Drops the challenges of a non-interactive proof, which are determined by `transcript`.
+    ///
+    /// `transcript` must be in the state the prover's was in before the first round, i.e. already
+    /// bound to the instance with `absorb_instance`. Each challenge is re-derived from it and
+    /// compared to the recorded one, so that `decompress` is guaranteed to give back `self`.
+    pub fn compress_non_interactive<T: SumcheckTranscript<F>>(
+        &self,
+        transcript: &mut T,
+    ) -> Result<CompressedSumcheckProof<F>, crate::Error> {
+        if self.prover_messages.len() != self.verifier_randomness.len() {
+            return Err(crate::Error::RoundMismatch {
+                expected: self.prover_messages.len(),
+                got: self.verifier_randomness.len(),
+            });
+        }
+
+        for (i, (prover_msg, randomness)) in
+            self.prover_messages.iter().zip(&self.verifier_randomness).enumerate()
+        {
+            transcript.absorb_prover_msg(prover_msg);
+            if transcript.challenge() != *randomness {
+                return Err(crate::Error::ChallengeMismatch { round: i + 1 });
+            }
+        }
+
+        Ok(CompressedSumcheckProof { prover_messages: self.prover_messages.clone() })
+    }
+
+    /// Encodes the proof behind a header made of `PROOF_MAGIC`, `PROOF_FORMAT_VERSION`, the
+    /// number of rounds and the degree of every round polynomial, followed by the compressed
+    /// `CanonicalSerialize` encoding of the proof. Integers in the header are little-endian `u64`s.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let header_len = PROOF_MAGIC.len() + 1 + 8 * (self.prover_messages.len() + 1);
+        let mut bytes = Vec::with_capacity(header_len + self.compressed_size());
+        bytes.extend_from_slice(&PROOF_MAGIC);
+        bytes.push(PROOF_FORMAT_VERSION);
+        bytes.extend_from_slice(&(self.prover_messages.len() as u64).to_le_bytes());
+        for msg in &self.prover_messages {
+            bytes.extend_from_slice(&(msg.degree() as u64).to_le_bytes());
+        }
+        // Writing into a `Vec` cannot fail.
+        self.serialize_compressed(&mut bytes).unwrap();
+
+        bytes
+    }
+
+    /// Decodes a proof written by `to_bytes` for an instance with the given per-round
+    /// `degree_bounds`, e.g. `max_degrees(g)`.
+    ///
+    /// The header is checked against `degree_bounds` before any field element is decoded, and
+    /// every round polynomial must be in canonical form with exactly the degree announced in the
+    /// header. Lengths read from `bytes` never size an allocation beyond the input itself.
+    pub fn from_bytes(bytes: &[u8], degree_bounds: &[usize]) -> Result<Self, crate::Error> {
+        let mut reader = bytes;
+        if take(&mut reader, PROOF_MAGIC.len())? != PROOF_MAGIC {
+            return Err(crate::Error::InvalidMagic);
+        }
+        let version = take(&mut reader, 1)?[0];
+        if version != PROOF_FORMAT_VERSION {
+            return Err(crate::Error::UnsupportedVersion {
+                expected: PROOF_FORMAT_VERSION,
+                got: version,
+            });
+        }
+
+        let num_rounds = read_len(&mut reader)?;
+        if num_rounds != degree_bounds.len() {
+            return Err(crate::Error::RoundMismatch {
+                expected: degree_bounds.len(),
+                got: num_rounds,
+            });
+        }
+        let mut degrees = Vec::with_capacity(num_rounds);
+        for (i, bound) in degree_bounds.iter().enumerate() {
+            let degree = read_len(&mut reader)?;
+            if degree > *bound {
+                return Err(crate::Error::DegreeTooHigh {
+                    round: i + 1,
+                    got: degree,
+                    bound: *bound,
+                });
+            }
+            degrees.push(degree);
+        }
+
+        // What follows is the `CanonicalSerialize` encoding: two length-prefixed lists.
+        check_list_len(&mut reader, num_rounds, "prover messages")?;
+        let mut prover_messages = Vec::with_capacity(num_rounds);
+        for (i, degree) in degrees.into_iter().enumerate() {
+            let msg = ProverMsg::<F>::deserialize_compressed(&mut reader).map_err(truncated)?;
+            if !is_canonical_uni_poly(&msg.gi) || msg.degree() != degree {
+                return Err(crate::Error::DeserializeError(format!(
+                    "Round polynomial {} is not canonical of degree {degree}, as announced.",
+                    i + 1
+                )));
+            }
+            prover_messages.push(msg);
+        }
+
+        check_list_len(&mut reader, num_rounds, "challenges")?;
+        let verifier_randomness = (0..num_rounds)
+            .map(|_| F::deserialize_compressed(&mut reader).map_err(truncated))
+            .collect::<Result<Vec<F>, _>>()?;
+
+        if !reader.is_empty() {
+            return Err(crate::Error::DeserializeError(format!(
+                "{} trailing bytes after the proof.",
+                reader.len()
+            )));
+        }
+
+        Ok(Self { prover_messages, verifier_randomness })
+    }
+}
+
+impl<F: Field> CompressedSumcheckProof<F> {
+    /// Number of rounds recorded in the proof.
+    pub fn num_rounds(&self) -> usize {
+        self.prover_messages.len()
+    }
+
+    /// Regenerates the challenges dropped by `SumcheckProof::compress_non_interactive`.
+    ///
+    /// `transcript` must be bound to the instance, exactly as for compression. With any other
+    /// transcript the result is a well-formed proof whose challenges `verify_non_interactive`
+    /// rejects.
+    pub fn decompress<T: SumcheckTranscript<F>>(&self, transcript: &mut T) -> SumcheckProof<F> {
+        let verifier_randomness = self
+            .prover_messages
+            .iter()
+            .map(|prover_msg| {
+                transcript.absorb_prover_msg(prover_msg);
+                transcript.challenge()
+            })
+            .collect();
+
+        SumcheckProof {
+            prover_messages: self.prover_messages.clone(),
+            verifier_randomness,
+        }
+    }
+}
+
+/// Splits the first `len` bytes off `reader`.
+fn take<'a>(reader: &mut &'a [u8], len: usize) -> Result<&'a [u8], crate::Error> {
+    if reader.len() < len {
+        return Err(crate::Error::TruncatedProof);
+    }
+    let (head, tail) = reader.split_at(len);
+    *reader = tail;
+
+    Ok(head)
+}
+
+/// Reads a little-endian `u64` length, saturating at `usize::MAX`.
+fn read_len(reader: &mut &[u8]) -> Result<usize, crate::Error> {
+    let len = u64::from_le_bytes(take(reader, 8)?.try_into().unwrap());
+
+    Ok(usize::try_from(len).unwrap_or(usize::MAX))
+}
+
+/// Reads the length prefix of a list of `what`, which must hold `expected` items.
+fn check_list_len(reader: &mut &[u8], expected: usize, what: &str) -> Result<(), crate::Error> {
+    let len = read_len(reader)?;
+    if len == expected {
+        Ok(())
+    } else {
+        Err(crate::Error::DeserializeError(format!(
+            "Header announces {expected} rounds, but the proof holds {len} {what}."
+        )))
+    }
+}
+
+/// Reports running out of input as `TruncatedProof`.
+fn truncated(e: SerializationError) -> crate::Error {
+    match e {
+        SerializationError::IoError(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+            crate::Error::TruncatedProof
+        }
+        e => e.into(),
+    }
+}
+
+impl<F: Field + From<i32>> IPForSumcheck<F> {
+    /// Non-interactive prover: runs every round against `transcript` instead of a verifier.
+    ///
+    /// The transcript is first bound to the instance, i.e. `g`'s degree bounds and its hypercube
+    /// sum, then absorbs each prover message before producing the next challenge, exactly
+    /// as `verify_round` does. A verifier replaying the proof with an identical transcript
+    /// derives the same challenges.
+    pub fn prove_non_interactive<T: SumcheckTranscript<F>>(
+        g: MultiPoly<F>,
+        transcript: &mut T,
+    ) -> Result<SumcheckProof<F>, crate::Error> {
+        let num_vars = g.num_vars;
+        let mut prover_state = Self::prover_init(g)?;
+        transcript.absorb_instance(&FsConfig::new(&prover_state.g, prover_state.asserted_sum()));
+        let mut verifier_msg = None;
+        let mut prover_messages = Vec::with_capacity(num_vars);
+        let mut verifier_randomness = Vec::with_capacity(num_vars);
+
+        for _ in 0..num_vars {
+            let prover_msg = Self::prove_round(&mut prover_state, &verifier_msg);
+            transcript.absorb_prover_msg(&prover_msg);
+            let randomness = transcript.challenge();
+
+            prover_messages.push(prover_msg);
+            verifier_randomness.push(randomness);
+            verifier_msg = Some(VerifierMsg::new(randomness));
+        }
+
+        Ok(SumcheckProof { prover_messages, verifier_randomness })
+    }
+
+    /// Runs the interactive protocol for `g` with every verifier challenge drawn from a ChaCha20
+    /// generator seeded with `seed`, so that the whole execution is reproducible.
+    ///
+    /// Returns the asserted sum, the recorded proof and the challenges, which are exactly the
+    /// `r_vec` of the verifier's output. `verify_seeded` replays the proof with the same seed.
+    pub fn run_protocol_seeded(
+        g: &MultiPoly<F>,
+        seed: [u8; 32],
+    ) -> Result<(F, SumcheckProof<F>, Vec<F>), crate::Error> {
+        let mut transcript = RngTranscript::new(ChaCha20Rng::from_seed(seed));
+        let mut prover_state = Self::prover_init(g.clone())?;
+        let mut verifier_state = Self::verifier_init(g.num_vars);
+        let mut verifier_msg = None;
+        let mut prover_messages = Vec::with_capacity(g.num_vars);
+        let asserted_sum = prover_state.asserted_sum();
+
+        for _ in 0..g.num_vars {
+            let prover_msg = Self::prove_round(&mut prover_state, &verifier_msg);
+            prover_messages.push(prover_msg.clone());
+            verifier_msg = Some(Self::verify_round(prover_msg, &mut verifier_state, &mut transcript)?);
+        }
+
+        let v_out = Self::partial_verify(verifier_state, asserted_sum)?;
+        let proof = SumcheckProof {
+            prover_messages,
+            verifier_randomness: v_out.r_vec.clone(),
+        };
+
+        Ok((asserted_sum, proof, v_out.r_vec))
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Full verification of a non-interactive proof.
+    ///
+    /// The shape of `proof` is checked with `validate` first. The challenges are then re-derived
+    /// from `transcript`, bound to `g` and `asserted_sum`, and must match the ones recorded in
+    /// `proof`. A binding transcript therefore rejects a proof produced for another instance even
+    /// if its round polynomials happen to be consistent.
+    pub fn verify_non_interactive<T: SumcheckTranscript<F>>(
+        g: &MultiPoly<F>,
+        proof: &SumcheckProof<F>,
+        asserted_sum: F,
+        transcript: &mut T,
+    ) -> Result<(), crate::Error> {
+        validate_poly(g)?;
+        proof.validate(g.num_vars, &max_degrees(g))?;
+
+        transcript.absorb_instance(&FsConfig::new(g, asserted_sum));
+        let mut verifier_state = Self::verifier_init_with_degrees(g.num_vars, DegreeInfo::from_poly(g))?;
+        for (i, (prover_msg, randomness)) in
+            proof.prover_messages.iter().zip(&proof.verifier_randomness).enumerate()
+        {
+            let v_msg = Self::verify_round(prover_msg.clone(), &mut verifier_state, transcript)?;
+
+            if v_msg.randomness != *randomness {
+                return Err(crate::Error::ChallengeMismatch { round: i + 1 });
+            }
+        }
+
+        Self::verify(g, verifier_state, asserted_sum)
+    }
+
+    /// Full verification of a proof recorded by `run_protocol_seeded`, re-deriving every
+    /// challenge from `seed` and rejecting unless they are identical to the recorded ones.
+    pub fn verify_seeded(
+        g: &MultiPoly<F>,
+        proof: &SumcheckProof<F>,
+        asserted_sum: F,
+        seed: [u8; 32],
+    ) -> Result<(), crate::Error> {
+        let mut transcript = RngTranscript::new(ChaCha20Rng::from_seed(seed));
+
+        Self::verify_non_interactive(g, proof, asserted_sum, &mut transcript)
+    }
+}
+
+impl<F: Field> ProofTranscript<F> {
+    /// Start replaying `proof` from its first round.
+    pub fn new(proof: SumcheckProof<F>) -> Self {
+        Self { proof, round: 0 }
+    }
+}
+
+impl<F: Field> Iterator for ProofTranscript<F> {
+    type Item = (ProverMsg<F>, VerifierMsg<F>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.round >= self.proof.num_rounds() {
+            return None;
+        }
+
+        let p_msg = self.proof.prover_messages[self.round].clone();
+        let v_msg = VerifierMsg::new(self.proof.verifier_randomness[self.round]);
+        self.round += 1;
+
+        Some((p_msg, v_msg))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.proof.num_rounds() - self.round;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<F: Field> ExactSizeIterator for ProofTranscript<F> {}
+
+impl<F: Field> IntoIterator for SumcheckProof<F> {
+    type Item = (ProverMsg<F>, VerifierMsg<F>);
+    type IntoIter = ProofTranscript<F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ProofTranscript::new(self)
+    }
+}
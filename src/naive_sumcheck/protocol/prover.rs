@@ -1,60 +1,364 @@
 //! Prover
-use ark_ff::Field;
+use ark_ff::{Field, Zero};
 use ark_poly::polynomial::multivariate::{SparseTerm, Term};
 use ark_poly::polynomial::{DenseMVPolynomial, Polynomial};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::{cfg_into_iter, vec::Vec};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    cfg_into_iter,
+    fmt,
+    io::{Read, Write},
+    vec::Vec,
+};
 
-use crate::naive_sumcheck::protocol::{IPForSumcheck, verifier::VerifierMsg};
+use crate::naive_sumcheck::eval::hypercube_sum;
+use crate::naive_sumcheck::protocol::{
+    check_message_version, invalid_data, IPForSumcheck, MESSAGE_VERSION, verifier::VerifierMsg,
+};
+use crate::naive_sumcheck::protocol::verifier::max_degrees;
+use crate::poly_utils::{normalize_polynomial, normalize_uni_poly, polynomial_total_degree, validate_poly};
 use crate::{MultiPoly, UniPoly};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 
-/// Utility functions
+// Utility functions
 
 /// Converts index `i` into its binary representation, potentially padding
 /// some leading zeroes until the bitstring contains `nu` bits in total.
 /// Returns a vector containing these bits as field elements.
-pub fn to_binary_vec<F: Field + std::convert::From<i32>>(i: usize, nu: usize) -> Vec<F> {
-	format!("{:0>width$}", format!("{:b}", i), width = nu)
-		.chars()
-		.map(|x| if x == '0' { 0.into() } else { 1.into() })
-		.collect::<_>()
+pub fn to_binary_vec<F: Field + From<i32>>(i: usize, nu: usize) -> Vec<F> {
+	to_base_vec(i, nu, 2)
+}
+
+/// Converts index `i` into its base-`base` representation, most significant digit first,
+/// padding leading zeroes until it contains `nu` digits in total, as `to_binary_vec` does for
+/// `base = 2`. Returns a vector containing these digits as field elements.
+pub fn to_base_vec<F: Field>(i: usize, nu: usize, base: usize) -> Vec<F> {
+	assert!(base >= 2, "Base should be at least 2...");
+
+	// As many digits as `i` has, at least one, padded to `nu`.
+	let mut digits = Vec::with_capacity(nu.max(1));
+	let mut rest = i;
+	loop {
+		digits.push(F::from((rest % base) as u64));
+		rest /= base;
+		if rest == 0 {
+			break;
+		}
+	}
+	digits.resize(digits.len().max(nu), F::zero());
+	digits.reverse();
+
+	digits
+}
+
+/// Converts index `i` into its mixed-radix representation, where digit `j` ranges over
+/// `0..sizes[j]` and the first digit is the most significant, as `to_base_vec` does when every
+/// size is the same. `i` must be below the product of `sizes`.
+pub fn to_mixed_radix_vec(i: usize, sizes: &[usize]) -> Vec<usize> {
+	let mut digits = vec![0; sizes.len()];
+	let mut rest = i;
+	for (digit, size) in digits.iter_mut().zip(sizes).rev() {
+		*digit = rest % size;
+		rest /= size;
+	}
+	assert_eq!(rest, 0, "Index should be below the number of points...");
+
+	digits
+}
+
+/// Finite set `D_i` each variable `x_i` is summed over, in variable order
+pub type Domain<F> = Vec<Vec<F>>;
+
+/// Checks that `domains` has one nonempty set of distinct points for each of `num_vars` variables.
+pub(crate) fn check_domains<F: Field>(domains: &[Vec<F>], num_vars: usize) -> Result<(), crate::Error> {
+    if domains.len() != num_vars {
+        return Err(crate::Error::ProtocolError(format!(
+            "Expected a domain for each of {num_vars} variables, got {}.",
+            domains.len()
+        )));
+    }
+    for (i, points) in domains.iter().enumerate() {
+        if points.is_empty() {
+            return Err(crate::Error::ProtocolError(format!("Domain of x{i} is empty.")));
+        }
+        if points.iter().enumerate().any(|(k, point)| points[..k].contains(point)) {
+            return Err(crate::Error::ProtocolError(format!(
+                "Domain of x{i} contains a point more than once."
+            )));
+        }
+    }
+
+    Ok(())
 }
 
 /// Prover Message
-#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+///
+/// Serialized as its version byte followed by `gi`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ProverMsg<F: Field> {
+    /// version of the wire format, `MESSAGE_VERSION` for every message built by this crate
+    pub(crate) version: u8,
     /// univariate polynomial representing a partial sum that gets sent to the verifier
     pub gi: UniPoly<F>,
 }
 
+impl<F: Field> ProverMsg<F> {
+    /// A message in the current wire format.
+    pub fn new(gi: UniPoly<F>) -> Self {
+        Self { version: MESSAGE_VERSION, gi }
+    }
+
+    /// A message carrying the round polynomial `gi`, same as `new`.
+    pub fn from_uni_poly(gi: UniPoly<F>) -> Self {
+        Self::new(gi)
+    }
+
+    /// Version of the wire format the message was built or decoded with.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Degree of `gi`, zero for the zero polynomial.
+    pub fn degree(&self) -> usize {
+        self.gi.degree()
+    }
+
+    /// Evaluation of `gi` at `point`, same as [`Self::eval_at`] by reference.
+    pub fn evaluate(&self, point: &F) -> F {
+        self.eval_at(*point)
+    }
+
+    /// Evaluation of `gi` at `r`, by value.
+    pub fn eval_at(&self, r: F) -> F {
+        self.gi.evaluate(&r)
+    }
+
+    /// Evaluation of `gi` at the literal point 0.
+    ///
+    /// This is one endpoint of the boolean domain only; a round over a `DomainPoint`, a base-`d`
+    /// domain or a custom domain sums `gi` over other points.
+    pub fn eval_at_zero(&self) -> F {
+        self.eval_at(F::zero())
+    }
+
+    /// Evaluation of `gi` at the literal point 1, see [`Self::eval_at_zero`] for other domains.
+    pub fn eval_at_one(&self) -> F {
+        self.eval_at(F::one())
+    }
+
+    /// Fails unless `gi` has degree at most `bound`, reporting `round` (from 1) on failure.
+    pub fn check_degree(&self, round: usize, bound: usize) -> Result<(), crate::Error> {
+        if self.degree() > bound {
+            return Err(crate::Error::DegreeTooHigh { round, got: self.degree(), bound });
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: Field> Default for ProverMsg<F> {
+    /// A message carrying the zero polynomial.
+    fn default() -> Self {
+        Self::new(UniPoly::zero())
+    }
+}
+
+impl<F: Field> CanonicalSerialize for ProverMsg<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.version.serialize_with_mode(&mut writer, compress)?;
+        self.gi.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.version.serialized_size(compress) + self.gi.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for ProverMsg<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.gi.check()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for ProverMsg<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        check_message_version(version)?;
+        let gi = UniPoly::deserialize_with_mode(&mut reader, compress, validate)?;
+
+        Ok(Self { version, gi })
+    }
+}
+
 /// Prover State
-pub struct ProverState<F: Field + std::convert::From<i32>> {
+#[derive(Clone)]
+pub struct ProverState<F: Field + From<i32>> {
     /// Polynomial for which we want to prove a relation
     pub g: MultiPoly<F>,
     /// randomness provided by the verifier
     pub randomness: Vec<F>,
     /// The current round number
     pub round: usize,
+    /// Sum of `g` over the hypercube, computed once by `prover_init`
+    pub asserted_sum: F,
+    /// Each variable is summed over `{0, ..., base - 1}`, 2 for the boolean hypercube
+    pub base: usize,
+    /// Per-variable domains, overriding `base` when set
+    pub domains: Option<Domain<F>>,
+    /// Expected degree bound of every round polynomial, i.e. `max_degrees(g)`
+    pub degree_bounds: Vec<usize>,
+    /// Whether `try_prove_round` fails on a round polynomial above its bound, which is otherwise
+    /// only a debug assertion
+    pub strict: bool,
+}
+
+impl<F: Field + From<i32>> CanonicalSerialize for ProverState<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.g.serialize_with_mode(&mut writer, compress)?;
+        self.randomness.serialize_with_mode(&mut writer, compress)?;
+        self.round.serialize_with_mode(&mut writer, compress)?;
+        self.asserted_sum.serialize_with_mode(&mut writer, compress)?;
+        self.base.serialize_with_mode(&mut writer, compress)?;
+        self.domains.serialize_with_mode(&mut writer, compress)?;
+        self.strict.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.g.serialized_size(compress)
+            + self.randomness.serialized_size(compress)
+            + self.round.serialized_size(compress)
+            + self.asserted_sum.serialized_size(compress)
+            + self.base.serialized_size(compress)
+            + self.domains.serialized_size(compress)
+            + self.strict.serialized_size(compress)
+    }
+}
+
+impl<F: Field + From<i32>> Valid for ProverState<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.g.check()?;
+        self.randomness.check()?;
+        self.asserted_sum.check()?;
+        self.domains.check()?;
+        self.check_consistency().map_err(invalid_data)
+    }
+}
+
+impl<F: Field + From<i32>> CanonicalDeserialize for ProverState<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let g = MultiPoly::deserialize_with_mode(&mut reader, compress, validate)?;
+        let state = Self {
+            randomness: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            round: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            asserted_sum: F::deserialize_with_mode(&mut reader, compress, validate)?,
+            base: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            domains: Option::deserialize_with_mode(&mut reader, compress, validate)?,
+            // Derived from `g` rather than stored.
+            degree_bounds: max_degrees(&g),
+            strict: bool::deserialize_with_mode(&mut reader, compress, validate)?,
+            g,
+        };
+        if let Validate::Yes = validate {
+            state.check()?;
+        }
+
+        Ok(state)
+    }
+}
+
+/// Summarizes `g` by its shape instead of listing every term.
+impl<F: Field + From<i32>> fmt::Debug for ProverState<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProverState")
+            .field("num_vars", &self.g.num_vars)
+            .field("num_terms", &self.g.terms.len())
+            .field("total_degree", &polynomial_total_degree(&self.g))
+            .field("round", &self.round)
+            .field("challenges", &self.randomness.len())
+            .finish()
+    }
 }
 
-impl<F: Field + std::convert::From<i32>> ProverState<F> {
+impl<F: Field + From<i32>> ProverState<F> {
+    /// Restores a state written with `serialize_compressed`, e.g. to finish a run that was
+    /// interrupted between two rounds.
+    ///
+    /// Fails unless `g` is valid, `round <= num_vars` and one challenge was received for every
+    /// message after the first.
+    pub fn resume(bytes: &[u8]) -> Result<Self, crate::Error> {
+        Ok(Self::deserialize_compressed(bytes)?)
+    }
+
+    /// Checks that the round counter agrees with `g` and the challenges received.
+    fn check_consistency(&self) -> Result<(), crate::Error> {
+        validate_poly(&self.g)?;
+        check_base(self.base)?;
+        if let Some(domains) = &self.domains {
+            check_domains(domains, self.g.num_vars)?;
+        }
+        if self.round > self.g.num_vars {
+            return Err(crate::Error::InvalidState(format!(
+                "Round {} is past the last round {}.",
+                self.round, self.g.num_vars
+            )));
+        }
+        if self.randomness.len() != self.round.saturating_sub(1) {
+            return Err(crate::Error::InvalidState(format!(
+                "Expected {} challenges after {} messages, got {}.",
+                self.round.saturating_sub(1),
+                self.round,
+                self.randomness.len()
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Given polynomial g, fix X_i, evaluate over x_{i+1}, ...
 	pub fn gen_uni_polynomial(&mut self, r: Option<F>) -> UniPoly<F> {
-		if r.is_some() {
-			self.randomness.push(r.unwrap());
+		if let Some(r) = r {
+			self.randomness.push(r);
 		}
 
         // remaining number of "non-fixed" variables
 		let v = self.g.num_vars() - self.randomness.len();
 
-        // For each possible combination in 0..2^{v - 1}
-		(0..(1 << (v as u32 - 1))).fold(   // Note: -1 because 1 variable will get fixed here
-			UniPoly::<F>::from_coefficients_vec(vec![(0, 0_u32.into())]),
-			|sum, i| sum + self.evaluate_gi(to_binary_vec::<F>(i as usize, v)),
+        if let Some(domains) = &self.domains {
+            // For each point of D_{j+1} × ... × D_{v-1}, behind a placeholder for X
+            let remaining = &domains[self.randomness.len() + 1..];
+            let sizes: Vec<usize> = remaining.iter().map(Vec::len).collect();
+            return (0..sizes.iter().product()).fold(UniPoly::<F>::zero(), |sum, i| {
+                let points = ark_std::iter::once(F::zero())
+                    .chain(to_mixed_radix_vec(i, &sizes).into_iter().zip(remaining).map(|(t, d)| d[t]))
+                    .collect();
+                sum + self.evaluate_gi(points)
+            });
+        }
+
+        // For each possible combination in 0..base^{v - 1}
+		(0..self.base.pow(v as u32 - 1)).fold(   // Note: -1 because 1 variable will get fixed here
+			UniPoly::<F>::zero(),   // also the result for the zero polynomial
+			|sum, i| sum + self.evaluate_gi(to_base_vec::<F>(i, v, self.base)),
 		)
 	}
 
@@ -62,9 +366,9 @@ impl<F: Field + std::convert::From<i32>> ProverState<F> {
     /// into one univariate polynomial.
 	pub fn evaluate_gi(&self, points: Vec<F>) -> UniPoly<F> {
 		let result = cfg_into_iter!(self.g.terms()).fold(
-			UniPoly::<F>::from_coefficients_vec(vec![]),   // empty
+			UniPoly::<F>::zero(),
 			|sum, (coeff, term)| {
-				let (coeff_eval, fixed_term) = self.evaluate_term(&term, &points);
+				let (coeff_eval, fixed_term) = self.evaluate_term(term, &points);
 				let current = match fixed_term {
 					None => UniPoly::<F>::from_coefficients_vec(vec![(0, *coeff * coeff_eval)]),
 					_ => UniPoly::<F>::from_coefficients_vec(vec![(
@@ -83,7 +387,7 @@ impl<F: Field + std::convert::From<i32>> ProverState<F> {
 	pub fn evaluate_term(
 		&self,
 		term: &SparseTerm,
-		points: &Vec<F>,
+		points: &[F],
 	) -> (F, Option<SparseTerm>) {
 		let mut fixed_term: Option<SparseTerm> = None;
 		let coeff: F =
@@ -92,46 +396,352 @@ impl<F: Field + std::convert::From<i32>> ProverState<F> {
 					fixed_term = Some(SparseTerm::new(vec![(j, *power)]));   // fix term
 					product   // retain product
 				}
-				j if j < self.randomness.len() => self.randomness[j].pow(&[*power as u64]) * product,
-				_ => points[*var - self.randomness.len()].pow(&[*power as u64]) * product,   // i.e., j > self.randomness.len()
+				j if j < self.randomness.len() => self.randomness[j].pow([*power as u64]) * product,
+				_ => points[*var - self.randomness.len()].pow([*power as u64]) * product,   // i.e., j > self.randomness.len()
 			});
 
 		(coeff, fixed_term)
 	}
 
-    // Sum all evaluations of polynomial `g` over boolean hypercube.
+    // Sum all evaluations of polynomial `g` over the hypercube, see `compute_sum_with_base`.
 	pub fn slow_sum_g(&self) -> F {
-		let v = self.g.num_vars();
-		let n = 2_u32.pow(v as u32);
-        
-		(0..n)
-			.map(|i| self.g.evaluate(&to_binary_vec(i as usize, v)))
-			.sum()
+		match &self.domains {
+			Some(domains) => IPForSumcheck::compute_sum_over_domains(&self.g, domains),
+			None => IPForSumcheck::compute_sum_with_base(&self.g, self.base),
+		}
 	}
+
+    /// Number of variables of `g`.
+    pub fn num_vars(&self) -> usize {
+        self.g.num_vars()
+    }
+
+    /// Number of (nonzero) terms of `g`.
+    pub fn num_terms(&self) -> usize {
+        self.g.terms().len()
+    }
+
+    /// Number of messages sent so far.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// The sum of `g` over the hypercube this prover argues for.
+    pub fn asserted_sum(&self) -> F {
+        self.asserted_sum
+    }
+
+    /// Number of messages still to be sent.
+    pub fn rounds_remaining(&self) -> usize {
+        self.g.num_vars() - self.round
+    }
+
+    /// Challenges fixed into `g` so far, in round order.
+    pub fn fixed_challenges(&self) -> &[F] {
+        &self.randomness
+    }
+
+    /// Fixes the verifier's challenge `r` and advances to the next round without computing
+    /// its message, i.e. `prove_round` with `r` as verifier message, minus the message.
+    ///
+    /// As in `prove_round`, the prover must have sent its first message, and must still be
+    /// active.
+    pub fn fix_round(&mut self, r: F) {
+        if self.round >= self.g.num_vars {
+            panic!("Prover is no longer active...");
+        }
+        if self.round == 0 {
+            panic!("Prover should go first...");
+        }
+
+        self.randomness.push(r);
+
+        // Increment round
+        self.round += 1;
+    }
+
+    /// Panics in debug builds if the round counter disagrees with the challenges received, as
+    /// checked by `resume`.
+    pub fn assert_consistent(&self) {
+        if cfg!(debug_assertions) {
+            if let Err(e) = self.check_consistency() {
+                panic!("Inconsistent prover state: {e}");
+            }
+        }
+    }
+}
+
+/// Prover driven one message at a time, see `IPForSumcheck::prover_rounds`
+pub struct ProverRounds<F: Field + From<i32>> {
+    /// Prover state, whose round counter tracks the messages sent so far
+    state: ProverState<F>,
+    /// Every challenge received, including the one answering the last message
+    challenges: Vec<F>,
 }
 
-impl<F: Field + std::convert::From<i32>> IPForSumcheck<F> {
+impl<F: Field + From<i32>> ProverRounds<F> {
+    /// Receives the verifier's message for the previous round (`None` before the first round)
+    /// and produces the next prover message with `prove_round`, or `None` once every round has
+    /// been sent.
+    pub fn next_msg(&mut self, v_msg: Option<VerifierMsg<F>>) -> Option<ProverMsg<F>> {
+        if let Some(msg) = &v_msg {
+            self.challenges.push(msg.randomness);
+        }
+        if self.remaining_rounds() == 0 {
+            return None;
+        }
+
+        Some(IPForSumcheck::prove_round(&mut self.state, &v_msg))
+    }
+
+    /// Number of messages left to send.
+    pub fn remaining_rounds(&self) -> usize {
+        self.state.num_vars() - self.state.round
+    }
+
+    /// Challenges received so far, in order.
+    pub fn challenges_so_far(&self) -> &[F] {
+        &self.challenges
+    }
+
+    /// The underlying prover state.
+    pub fn into_inner(self) -> ProverState<F> {
+        self.state
+    }
+}
+
+/// Bound on the total degree of the polynomials accepted by `prover_init`, which keeps the powers
+/// taken in `evaluate_term` well away from overflow.
+pub const DEFAULT_MAX_TOTAL_DEGREE: usize = (u32::MAX / 2) as usize;
+
+/// Checks that `{0, ..., base - 1}` has the two points a sumcheck round needs at least.
+pub(crate) fn check_base(base: usize) -> Result<(), crate::Error> {
+    if base < 2 {
+        return Err(crate::Error::ProtocolError(format!(
+            "Expected a base of at least 2, got {base}."
+        )));
+    }
+
+    Ok(())
+}
+
+impl<F: Field + From<i32>> IPForSumcheck<F> {
     /// Initialize prover to argue for the sum of polynomial `g` over the boolean hypercube of dimension `num_vars`.
     ///
-    pub fn prover_init(polynomial: MultiPoly<F>) -> ProverState<F> {
-        if polynomial.num_vars == 0 {
-            panic!("Proving sumcheck for a constant polynomial is trivial...")
+    /// Fails if the total degree of `g` exceeds `DEFAULT_MAX_TOTAL_DEGREE`. A constant `g`,
+    /// with no variables, is a valid instance with no rounds: the prover sends no message and
+    /// the verifier only compares the asserted sum with `g`'s constant term.
+    pub fn prover_init(polynomial: MultiPoly<F>) -> Result<ProverState<F>, crate::Error> {
+        Self::prover_init_with_degree_bound(polynomial, DEFAULT_MAX_TOTAL_DEGREE)
+    }
+
+    /// Same as `prover_init`, but fails if the total degree of `g` exceeds `max_total_degree`.
+    ///
+    pub fn prover_init_with_degree_bound(
+        polynomial: MultiPoly<F>,
+        max_total_degree: usize,
+    ) -> Result<ProverState<F>, crate::Error> {
+        Self::prover_init_inner(polynomial, max_total_degree, 2, None)
+    }
+
+    /// Same as `prover_init`, but argues for the sum of `g` over `{0, ..., base - 1}^num_vars`.
+    ///
+    /// With `base = 2` this is exactly `prover_init`. Otherwise the sum is computed by
+    /// evaluating `g` at each of the `base^num_vars` points, and each round polynomial has
+    /// `base^(v - 1)` terms to sum, where `v` variables are left.
+    pub fn prover_init_with_base(
+        polynomial: MultiPoly<F>,
+        base: usize,
+    ) -> Result<ProverState<F>, crate::Error> {
+        Self::prover_init_inner(polynomial, DEFAULT_MAX_TOTAL_DEGREE, base, None)
+    }
+
+    /// Same as `prover_init`, but argues for the sum of `g` over `D_0 × ... × D_{v-1}` for the
+    /// per-variable `domains`, and sums each round polynomial over the remaining ones.
+    ///
+    /// Fails unless there is one domain per variable, each nonempty and without repeated
+    /// points. The sum is computed by evaluating `g` at every point of the product.
+    pub fn prover_init_with_domains(
+        polynomial: MultiPoly<F>,
+        domains: Domain<F>,
+    ) -> Result<ProverState<F>, crate::Error> {
+        check_domains(&domains, polynomial.num_vars)?;
+        Self::prover_init_inner(polynomial, DEFAULT_MAX_TOTAL_DEGREE, 2, Some(domains))
+    }
+
+    fn prover_init_inner(
+        polynomial: MultiPoly<F>,
+        max_total_degree: usize,
+        base: usize,
+        domains: Option<Domain<F>>,
+    ) -> Result<ProverState<F>, crate::Error> {
+        validate_poly(&polynomial)?;
+        check_base(base)?;
+
+        let total_degree = polynomial_total_degree(&polynomial);
+        if total_degree > max_total_degree {
+            return Err(crate::Error::PolynomialError(format!(
+                "Polynomial has total degree {total_degree}, exceeding the bound {max_total_degree}."
+            )));
         }
 
-        ProverState {
-            g: polynomial.clone(),
+        let mut prover_state = ProverState {
             randomness: Vec::with_capacity(polynomial.num_vars),
+            degree_bounds: max_degrees(&polynomial),
+            strict: false,
+            g: polynomial,
             round: 0,
+            asserted_sum: F::zero(),
+            base,
+            domains,
+        };
+        prover_state.asserted_sum = prover_state.slow_sum_g();
+
+        Ok(prover_state)
+    }
+
+    /// Same as `prover_init`, but `try_prove_round` fails with `PolynomialError` instead of only
+    /// debug-asserting if a round polynomial ever exceeds its bound in `degree_bounds`.
+    pub fn prover_init_strict(polynomial: MultiPoly<F>) -> Result<ProverState<F>, crate::Error> {
+        let mut prover_state = Self::prover_init(polynomial)?;
+        prover_state.strict = true;
+
+        Ok(prover_state)
+    }
+
+    /// Same as `prover_init`, but first merges duplicate monomials of `g` with
+    /// `normalize_polynomial` instead of rejecting them.
+    ///
+    /// The verifier must then check against the normalized polynomial, i.e. the prover's `g`.
+    pub fn prover_init_normalized(polynomial: MultiPoly<F>) -> Result<ProverState<F>, crate::Error> {
+        Self::prover_init(normalize_polynomial(polynomial))
+    }
+
+    /// Initialize a prover for `g`, as `prover_init`, that is driven by feeding it the
+    /// verifier's messages one at a time.
+    ///
+    pub fn prover_rounds(polynomial: MultiPoly<F>) -> Result<ProverRounds<F>, crate::Error> {
+        Ok(ProverRounds {
+            challenges: Vec::with_capacity(polynomial.num_vars),
+            state: Self::prover_init(polynomial)?,
+        })
+    }
+
+    /// Sum of polynomial `g` over the boolean hypercube, as `slow_sum_g` but without building a
+    /// prover state.
+    ///
+    /// Fails if `g` is malformed, see `validate_poly`.
+    pub fn compute_sum(g: &MultiPoly<F>) -> Result<F, crate::Error> {
+        validate_poly(g)?;
+
+        Ok(hypercube_sum(g))
+    }
+
+    /// Sum of polynomial `g` over `{0, ..., base - 1}^num_vars`, i.e. `compute_sum` for
+    /// `base = 2`, and one evaluation of `g` per point otherwise.
+    ///
+    pub fn compute_sum_with_base(g: &MultiPoly<F>, base: usize) -> F {
+        if base == 2 {
+            return hypercube_sum(g);
         }
+
+        let v = g.num_vars();
+        (0..base.pow(v as u32)).map(|i| g.evaluate(&to_base_vec(i, v, base))).sum()
+    }
+
+    /// Sum of polynomial `g` over `D_0 × ... × D_{v-1}`, with one evaluation of `g` per point.
+    ///
+    pub fn compute_sum_over_domains(g: &MultiPoly<F>, domains: &[Vec<F>]) -> F {
+        let sizes: Vec<usize> = domains.iter().map(Vec::len).collect();
+        (0..sizes.iter().product())
+            .map(|i| {
+                let point: Vec<F> = to_mixed_radix_vec(i, &sizes)
+                    .into_iter()
+                    .zip(domains)
+                    .map(|(t, d)| d[t])
+                    .collect();
+                g.evaluate(&point)
+            })
+            .sum()
+    }
+
+    /// Checks each claimed sum against the hypercube sum of the matching polynomial, computed
+    /// with `slow_sum_g`, e.g. as a sanity check before running the full protocol.
+    ///
+    /// Fails with `ClaimMismatch` at the index of the first mismatch.
+    pub fn verify_batch_sums(
+        polynomials: &[MultiPoly<F>],
+        claimed_sums: &[F],
+    ) -> Result<(), crate::Error> {
+        if polynomials.len() != claimed_sums.len() {
+            return Err(crate::Error::ProtocolError(format!(
+                "Got {} polynomials but {} claimed sums.",
+                polynomials.len(),
+                claimed_sums.len(),
+            )));
+        }
+
+        for (index, (g, claimed_sum)) in polynomials.iter().zip(claimed_sums).enumerate() {
+            if Self::compute_sum(g)? != *claimed_sum {
+                return Err(crate::Error::ClaimMismatch { index });
+            }
+        }
+
+        Ok(())
     }
 
     /// Receive message from verifier, generate prover message, and proceed to next round.
     ///
+    /// The message is brought into canonical form with `normalize_uni_poly`, and checked against
+    /// the round's bound in `degree_bounds` with a debug assertion. A strict prover reports a
+    /// message above its bound as an error through `try_prove_round` instead.
     pub fn prove_round(
         prover_state: &mut ProverState<F>,
         v_msg: &Option<VerifierMsg<F>>,
     ) -> ProverMsg<F> {
-        if prover_state.round > prover_state.g.num_vars {
+        let msg = Self::round_message(prover_state, v_msg);
+        let round = prover_state.round;
+        debug_assert!(
+            msg.check_degree(round + 1, prover_state.degree_bounds[round]).is_ok(),
+            "Prover's round polynomial is malformed"
+        );
+
+        // Increment round
+        prover_state.round += 1;
+
+        msg
+    }
+
+    /// Same as `prove_round`, but if `strict` is set, a round polynomial above its bound in
+    /// `degree_bounds` fails with `PolynomialError` and leaves `prover_state` as it was before
+    /// `v_msg`.
+    pub fn try_prove_round(
+        prover_state: &mut ProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> Result<ProverMsg<F>, crate::Error> {
+        let msg = Self::round_message(prover_state, v_msg);
+        let round = prover_state.round;
+        match msg.check_degree(round + 1, prover_state.degree_bounds[round]) {
+            Err(e) if prover_state.strict => {
+                if v_msg.is_some() {
+                    prover_state.randomness.pop();
+                }
+                return Err(crate::Error::PolynomialError(format!(
+                    "Prover's round polynomial is malformed: {e}"
+                )));
+            }
+            result => debug_assert!(result.is_ok(), "Prover's round polynomial is malformed"),
+        }
+
+        // Increment round
+        prover_state.round += 1;
+
+        Ok(msg)
+    }
+
+    // The canonical round polynomial after receiving `v_msg`, without advancing the round.
+    fn round_message(prover_state: &mut ProverState<F>, v_msg: &Option<VerifierMsg<F>>) -> ProverMsg<F> {
+        if prover_state.round >= prover_state.g.num_vars {
             panic!("Prover is no longer active...");
         }
 
@@ -151,11 +761,6 @@ impl<F: Field + std::convert::From<i32>> IPForSumcheck<F> {
         }
 
         // Compute partial sum
-        let gi = prover_state.gen_uni_polynomial(r);
-
-        // Increment round
-        prover_state.round += 1;
-
-        ProverMsg { gi }
+        ProverMsg::new(normalize_uni_poly(prover_state.gen_uni_polynomial(r)))
     }
 }
\ No newline at end of file
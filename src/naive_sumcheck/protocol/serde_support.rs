@@ -0,0 +1,177 @@
+//! `serde` support for messages, proofs and verifier output
+//!
+//! Field elements are encoded as lowercase hex strings of their compressed canonical
+//! serialization, i.e. little-endian for prime fields: 12 becomes `"0c00…00"`. Strings of the
+//! wrong length and values outside the field are rejected, never reduced.
+//!
+//! Univariate polynomials are encoded as lists of `[degree, coefficient]` pairs, in ascending
+//! degree and with nonzero coefficients only, as in their canonical form.
+use ark_ff::Field;
+use ark_std::{fmt::Write, string::String, vec::Vec};
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::naive_sumcheck::protocol::{
+    check_message_version,
+    proof::SumcheckProof,
+    prover::ProverMsg,
+    verifier::{VerifierMsg, VerifierOutput},
+};
+use crate::UniPoly;
+
+/// A field element, encoded as a hex string
+struct Hex<F>(F);
+
+impl<F: Field> Serialize for Hex<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::with_capacity(self.0.compressed_size());
+        self.0.serialize_compressed(&mut bytes).map_err(S::Error::custom)?;
+
+        let mut hex = String::with_capacity(2 * bytes.len());
+        for byte in bytes {
+            write!(hex, "{byte:02x}").map_err(S::Error::custom)?;
+        }
+        serializer.serialize_str(&hex)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for Hex<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        let len = F::zero().compressed_size();
+        if hex.len() != 2 * len || !hex.is_ascii() {
+            return Err(D::Error::custom(format!(
+                "Expected a field element of {} hex digits, got {hex:?}.",
+                2 * len
+            )));
+        }
+
+        let bytes = (0..len)
+            .map(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(D::Error::custom)?;
+        F::deserialize_compressed(&bytes[..])
+            .map(Hex)
+            .map_err(|_| D::Error::custom(format!("{hex:?} is not a canonical field element.")))
+    }
+}
+
+/// Hex encoding of every element of `elements`.
+fn to_hex<F: Field>(elements: &[F]) -> Vec<Hex<F>> {
+    elements.iter().copied().map(Hex).collect()
+}
+
+/// The elements decoded from `hex`.
+fn from_hex<F: Field>(hex: Vec<Hex<F>>) -> Vec<F> {
+    hex.into_iter().map(|Hex(e)| e).collect()
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct ProverMsgRepr<F: Field> {
+    version: u8,
+    gi: Vec<(usize, Hex<F>)>,
+}
+
+impl<F: Field> Serialize for ProverMsg<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ProverMsgRepr {
+            version: self.version,
+            gi: self.gi.iter().map(|(degree, coeff)| (*degree, Hex(*coeff))).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for ProverMsg<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ProverMsgRepr::<F>::deserialize(deserializer)?;
+        check_message_version(repr.version).map_err(D::Error::custom)?;
+
+        let coeffs: Vec<(usize, F)> = repr.gi.into_iter().map(|(d, Hex(c))| (d, c)).collect();
+        if coeffs.iter().any(|(_, c)| c.is_zero())
+            || coeffs.windows(2).any(|pair| pair[0].0 >= pair[1].0)
+        {
+            return Err(D::Error::custom(
+                "Polynomial coefficients must be nonzero and sorted by strictly ascending degree.",
+            ));
+        }
+
+        Ok(Self { version: repr.version, gi: UniPoly::from_coefficients_vec(coeffs) })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct VerifierMsgRepr<F: Field> {
+    version: u8,
+    randomness: Hex<F>,
+}
+
+impl<F: Field> Serialize for VerifierMsg<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VerifierMsgRepr { version: self.version, randomness: Hex(self.randomness) }
+            .serialize(serializer)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for VerifierMsg<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = VerifierMsgRepr::<F>::deserialize(deserializer)?;
+        check_message_version(repr.version).map_err(D::Error::custom)?;
+
+        Ok(Self { version: repr.version, randomness: repr.randomness.0 })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct VerifierOutputRepr<F: Field> {
+    r_vec: Vec<Hex<F>>,
+    expected_evaluation: Hex<F>,
+}
+
+impl<F: Field> Serialize for VerifierOutput<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VerifierOutputRepr {
+            r_vec: to_hex(&self.r_vec),
+            expected_evaluation: Hex(self.expected_evaluation),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for VerifierOutput<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = VerifierOutputRepr::<F>::deserialize(deserializer)?;
+
+        Ok(Self { r_vec: from_hex(repr.r_vec), expected_evaluation: repr.expected_evaluation.0 })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct SumcheckProofRepr<F: Field> {
+    prover_messages: Vec<ProverMsg<F>>,
+    verifier_randomness: Vec<Hex<F>>,
+}
+
+impl<F: Field> Serialize for SumcheckProof<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SumcheckProofRepr {
+            prover_messages: self.prover_messages.clone(),
+            verifier_randomness: to_hex(&self.verifier_randomness),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for SumcheckProof<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = SumcheckProofRepr::<F>::deserialize(deserializer)?;
+
+        Ok(Self {
+            prover_messages: repr.prover_messages,
+            verifier_randomness: from_hex(repr.verifier_randomness),
+        })
+    }
+}
@@ -0,0 +1,200 @@
+//! Typestate layer over the prover and verifier functions of `IPForSumcheck`
+//!
+//! Every step consumes the current state and returns the next one, so that running a party out
+//! of order is a compile error rather than a runtime panic: the prover sends its first message
+//! on construction and only ever answers a challenge afterwards, and only a finished verifier
+//! can be checked.
+//!
+//! ```
+//! # use ark_bls12_381::Fr;
+//! # use sumcheck::poly_utils::PolynomialBuilder;
+//! # use sumcheck::naive_sumcheck::protocol::typestate::{ProverActive, ProverStep, VerifierActive, VerifierStep};
+//! # use sumcheck::transcript::RngTranscript;
+//! let g = PolynomialBuilder::<Fr>::new()
+//!     .num_vars(3)
+//!     .add_term(2.into(), &[(0, 3)])
+//!     .add_term(1.into(), &[(0, 1), (2, 1)])
+//!     .add_term(1.into(), &[(1, 1), (2, 1)])
+//!     .build()
+//!     .unwrap();
+//! let mut transcript = RngTranscript::new(rand::thread_rng());
+//!
+//! let (mut prover_msg, mut prover) = ProverActive::start(g.clone()).unwrap();
+//! let mut verifier = VerifierActive::new(3).unwrap();
+//! let finished = loop {
+//!     let (verifier_msg, next) = verifier.verify_round(prover_msg, &mut transcript);
+//!     match (next, prover) {
+//!         (VerifierStep::Active(v), ProverStep::Active(p)) => {
+//!             verifier = v;
+//!             (prover_msg, prover) = p.prove_round(verifier_msg);
+//!         }
+//!         (VerifierStep::Finished(v), ProverStep::Finished(_)) => break v,
+//!         _ => unreachable!("both parties run for `num_vars` rounds"),
+//!     }
+//! };
+//! assert!(finished.verify(&g, 12.into()).is_ok());
+//! ```
+//!
+//! A verifier that has not received every message cannot be checked:
+//!
+//! ```compile_fail
+//! # use ark_bls12_381::Fr;
+//! # use sumcheck::naive_sumcheck::protocol::typestate::VerifierActive;
+//! let verifier = VerifierActive::<Fr>::new(3).unwrap();
+//! verifier.partial_verify(12.into());
+//! ```
+//!
+//! and a finished prover has no more messages to send:
+//!
+//! ```compile_fail
+//! # use ark_bls12_381::Fr;
+//! # use sumcheck::naive_sumcheck::protocol::{typestate::ProverFinished, verifier::VerifierMsg};
+//! fn next(prover: ProverFinished<Fr>) {
+//!     prover.prove_round(VerifierMsg::new(1.into()));
+//! }
+//! ```
+use ark_ff::Field;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::{VerifierMsg, VerifierOutput, VerifierState},
+};
+use crate::transcript::SumcheckTranscript;
+use crate::MultiPoly;
+
+/// Prover with at least one message left to send
+pub struct ProverActive<F: Field + From<i32>> {
+    /// Underlying prover state
+    state: ProverState<F>,
+}
+
+/// Prover that has sent its message for every round
+pub struct ProverFinished<F: Field + From<i32>> {
+    /// Underlying prover state
+    state: ProverState<F>,
+}
+
+/// State of the prover after sending a message
+pub enum ProverStep<F: Field + From<i32>> {
+    /// The prover expects another challenge
+    Active(ProverActive<F>),
+    /// That was the last round
+    Finished(ProverFinished<F>),
+}
+
+impl<F: Field + From<i32>> ProverStep<F> {
+    /// Wraps `state` once it has produced a message.
+    fn after_message(state: ProverState<F>) -> Self {
+        if state.round == state.num_vars() {
+            Self::Finished(ProverFinished { state })
+        } else {
+            Self::Active(ProverActive { state })
+        }
+    }
+}
+
+impl<F: Field + From<i32>> ProverActive<F> {
+    /// Initializes the prover for `g`, as `prover_init`, and produces its first message; fails if
+    /// there is no round to run.
+    pub fn start(g: MultiPoly<F>) -> Result<(ProverMsg<F>, ProverStep<F>), crate::Error> {
+        if g.num_vars == 0 {
+            return Err(crate::Error::PolynomialError("Expected at least one variable.".into()));
+        }
+
+        let mut state = IPForSumcheck::prover_init(g)?;
+        let msg = IPForSumcheck::prove_round(&mut state, &None);
+
+        Ok((msg, ProverStep::after_message(state)))
+    }
+
+    /// Answers the verifier's challenge for the previous round, as `prove_round`.
+    pub fn prove_round(mut self, v_msg: VerifierMsg<F>) -> (ProverMsg<F>, ProverStep<F>) {
+        let msg = IPForSumcheck::prove_round(&mut self.state, &Some(v_msg));
+
+        (msg, ProverStep::after_message(self.state))
+    }
+
+    /// The underlying prover state.
+    pub fn state(&self) -> &ProverState<F> {
+        &self.state
+    }
+}
+
+impl<F: Field + From<i32>> ProverFinished<F> {
+    /// The underlying prover state.
+    pub fn into_inner(self) -> ProverState<F> {
+        self.state
+    }
+}
+
+/// Verifier expecting at least one more prover message
+pub struct VerifierActive<F: Field> {
+    /// Underlying verifier state
+    state: VerifierState<F>,
+}
+
+/// Verifier that has received a message for every round
+pub struct VerifierFinished<F: Field> {
+    /// Underlying verifier state
+    state: VerifierState<F>,
+}
+
+/// State of the verifier after answering a message
+pub enum VerifierStep<F: Field> {
+    /// The verifier expects another message
+    Active(VerifierActive<F>),
+    /// That was the last round
+    Finished(VerifierFinished<F>),
+}
+
+impl<F: Field> VerifierActive<F> {
+    /// Initializes the verifier, as `verifier_init`; fails if there is no round to run.
+    pub fn new(num_vars: usize) -> Result<Self, crate::Error> {
+        if num_vars == 0 {
+            return Err(crate::Error::PolynomialError("Expected at least one variable.".into()));
+        }
+
+        Ok(Self { state: IPForSumcheck::verifier_init(num_vars) })
+    }
+
+    /// Answers `prover_msg` with a challenge drawn from `transcript`, as `verify_round`.
+    pub fn verify_round<T: SumcheckTranscript<F>>(
+        mut self,
+        prover_msg: ProverMsg<F>,
+        transcript: &mut T,
+    ) -> (VerifierMsg<F>, VerifierStep<F>) {
+        let v_msg = IPForSumcheck::verify_round(prover_msg, &mut self.state, transcript)
+            .expect("An active verifier has rounds left...");
+
+        let next = if self.state.finished {
+            VerifierStep::Finished(VerifierFinished { state: self.state })
+        } else {
+            VerifierStep::Active(self)
+        };
+
+        (v_msg, next)
+    }
+
+    /// The underlying verifier state.
+    pub fn state(&self) -> &VerifierState<F> {
+        &self.state
+    }
+}
+
+impl<F: Field> VerifierFinished<F> {
+    /// Intermediate checks of every round, as `partial_verify`.
+    pub fn partial_verify(self, asserted_sum: F) -> Result<VerifierOutput<F>, crate::Error> {
+        IPForSumcheck::partial_verify(self.state, asserted_sum)
+    }
+
+    /// Full verification against `g`, as `verify`.
+    pub fn verify(self, g: &MultiPoly<F>, asserted_sum: F) -> Result<(), crate::Error> {
+        IPForSumcheck::verify(g, self.state, asserted_sum)
+    }
+
+    /// The underlying verifier state.
+    pub fn into_inner(self) -> VerifierState<F> {
+        self.state
+    }
+}
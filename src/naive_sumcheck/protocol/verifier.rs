@@ -1,31 +1,419 @@
 //! Verifier
 use ark_ff::Field;
 use ark_poly::{DenseMVPolynomial, Polynomial};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::{rand::RngCore, vec::Vec};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt,
+    io::{Read, Write},
+    rand::RngCore,
+    string::ToString,
+    vec::Vec,
+};
 
-use crate::naive_sumcheck::protocol::{IPForSumcheck, prover::ProverMsg};
+use crate::naive_sumcheck::protocol::{
+    check_message_version, invalid_data, IPForSumcheck, MESSAGE_VERSION,
+    prover::{check_base, check_domains, Domain, ProverMsg},
+};
+use crate::poly_utils::validate_poly;
+use crate::traits::PolyOracle;
+use crate::transcript::SumcheckTranscript;
 use crate::{MultiPoly, UniPoly};
 
 /// Verifier Message
-#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+///
+/// Serialized as its version byte followed by `randomness`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VerifierMsg<F: Field> {
+    /// version of the wire format, `MESSAGE_VERSION` for every message built by this crate
+    pub(crate) version: u8,
     /// randomness sampled by verifier
     pub randomness: F,
 }
 
+impl<F: Field> VerifierMsg<F> {
+    /// A message in the current wire format.
+    pub fn new(randomness: F) -> Self {
+        Self { version: MESSAGE_VERSION, randomness }
+    }
+
+    /// A message carrying the challenge `randomness`, same as `new`.
+    pub fn from_field_element(randomness: F) -> Self {
+        Self::new(randomness)
+    }
+
+    /// Version of the wire format the message was built or decoded with.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl<F: Field> Default for VerifierMsg<F> {
+    /// A message carrying zero randomness.
+    fn default() -> Self {
+        Self::new(F::zero())
+    }
+}
+
+impl<F: Field> CanonicalSerialize for VerifierMsg<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.version.serialize_with_mode(&mut writer, compress)?;
+        self.randomness.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.version.serialized_size(compress) + self.randomness.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for VerifierMsg<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.randomness.check()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for VerifierMsg<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        check_message_version(version)?;
+        let randomness = F::deserialize_with_mode(&mut reader, compress, validate)?;
+
+        Ok(Self { version, randomness })
+    }
+}
+
+/// The two points each round polynomial is summed over, `0` and `1` for the boolean hypercube
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DomainPoint<F: Field> {
+    /// The point standing for `0`
+    pub zero: F,
+    /// The point standing for `1`
+    pub one: F,
+}
+
+impl<F: Field> DomainPoint<F> {
+    /// The endpoints of the boolean hypercube.
+    pub fn boolean() -> Self {
+        Self { zero: F::zero(), one: F::one() }
+    }
+}
+
+/// Checks that the two endpoints of `domain` are distinct.
+fn check_domain_point<F: Field>(domain: &DomainPoint<F>) -> Result<(), crate::Error> {
+    if domain.zero == domain.one {
+        return Err(crate::Error::ProtocolError("Domain endpoints should be distinct.".into()));
+    }
+
+    Ok(())
+}
+
+impl<F: Field> Default for DomainPoint<F> {
+    fn default() -> Self {
+        Self::boolean()
+    }
+}
+
 /// Verifier State
+#[derive(Clone)]
 pub struct VerifierState<F: Field> {
     /// Verifier's round counter
-    round: usize,
+    pub(crate) round: usize,
     /// Number of variables in the prover's claimed polynomial `g`
-    num_vars: usize,
+    pub(crate) num_vars: usize,
     /// If verifier is done
-    finished: bool,
+    pub(crate) finished: bool,
     /// a list storing the partial sums (univariate polynomials) sent by the prover at each round
-    partial_sums: Vec<UniPoly<F>>,   // Optimization: store polynomial evaluations instead
+    pub(crate) partial_sums: Vec<UniPoly<F>>,   // Optimization: store polynomial evaluations instead
     /// a vector for keeping track of the random field elements sampled by the verifier at each round
-    randomness: Vec<F>,
+    pub(crate) randomness: Vec<F>,
+    /// Points at which `partial_verify` evaluates each round polynomial
+    pub(crate) domain: DomainPoint<F>,
+    /// Each round polynomial is summed over `{0, ..., base - 1}`, 2 for the boolean hypercube
+    pub(crate) base: usize,
+    /// Per-variable domains, overriding `base` when set
+    pub(crate) domains: Option<Domain<F>>,
+    /// Degree bound of every round polynomial, enforced by `verify_round` when set
+    pub(crate) degree_bounds: Option<Vec<usize>>,
+}
+
+/// Reports how far the run has progressed instead of listing every round polynomial.
+impl<F: Field> fmt::Debug for VerifierState<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifierState")
+            .field("round", &self.round)
+            .field("num_vars", &self.num_vars)
+            .field("finished", &self.finished)
+            .field("partial_sums", &self.partial_sums.len())
+            .field("challenges", &self.randomness.len())
+            .field("domain", &self.domain)
+            .field("base", &self.base)
+            .field(
+                "domain_sizes",
+                &self.domains.as_ref().map(|d| d.iter().map(Vec::len).collect::<Vec<_>>()),
+            )
+            .field("degree_bounds", &self.degree_bounds)
+            .finish()
+    }
+}
+
+impl<F: Field> CanonicalSerialize for VerifierState<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.round.serialize_with_mode(&mut writer, compress)?;
+        self.num_vars.serialize_with_mode(&mut writer, compress)?;
+        self.finished.serialize_with_mode(&mut writer, compress)?;
+        self.partial_sums.serialize_with_mode(&mut writer, compress)?;
+        self.randomness.serialize_with_mode(&mut writer, compress)?;
+        self.domain.zero.serialize_with_mode(&mut writer, compress)?;
+        self.domain.one.serialize_with_mode(&mut writer, compress)?;
+        self.base.serialize_with_mode(&mut writer, compress)?;
+        self.domains.serialize_with_mode(&mut writer, compress)?;
+        self.degree_bounds.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.round.serialized_size(compress)
+            + self.num_vars.serialized_size(compress)
+            + self.finished.serialized_size(compress)
+            + self.partial_sums.serialized_size(compress)
+            + self.randomness.serialized_size(compress)
+            + self.domain.zero.serialized_size(compress)
+            + self.domain.one.serialized_size(compress)
+            + self.base.serialized_size(compress)
+            + self.domains.serialized_size(compress)
+            + self.degree_bounds.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for VerifierState<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.partial_sums.check()?;
+        self.randomness.check()?;
+        self.domain.zero.check()?;
+        self.domain.one.check()?;
+        self.domains.check()?;
+        self.check_consistency().map_err(invalid_data)
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for VerifierState<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let state = Self {
+            round: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            num_vars: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            finished: bool::deserialize_with_mode(&mut reader, compress, validate)?,
+            partial_sums: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            randomness: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            domain: DomainPoint {
+                zero: F::deserialize_with_mode(&mut reader, compress, validate)?,
+                one: F::deserialize_with_mode(&mut reader, compress, validate)?,
+            },
+            base: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            domains: Option::deserialize_with_mode(&mut reader, compress, validate)?,
+            degree_bounds: Option::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            state.check()?;
+        }
+
+        Ok(state)
+    }
+}
+
+impl<F: Field> VerifierState<F> {
+    /// Restores a state written with `serialize_compressed`, e.g. to finish a run that was
+    /// interrupted between two rounds.
+    ///
+    /// Fails unless one challenge was drawn for every message received, at most `num_vars` of
+    /// them, and the round counter and `finished` flag agree with that count.
+    pub fn resume(bytes: &[u8]) -> Result<Self, crate::Error> {
+        Ok(Self::deserialize_compressed(bytes)?)
+    }
+
+    /// Checks that the round counter and `finished` flag agree with the messages received.
+    fn check_consistency(&self) -> Result<(), crate::Error> {
+        check_base(self.base)?;
+        check_domain_point(&self.domain)?;
+        if self.domain != DomainPoint::boolean() && (self.base != 2 || self.domains.is_some()) {
+            return Err(crate::Error::InvalidState(
+                "Domain endpoints are only used for base 2 without per-variable domains.".into(),
+            ));
+        }
+        if let Some(domains) = &self.domains {
+            check_domains(domains, self.num_vars)?;
+        }
+        let received = self.partial_sums.len();
+        if self.randomness.len() != received || received > self.num_vars {
+            return Err(crate::Error::InvalidState(format!(
+                "Got {received} messages and {} challenges for {} rounds.",
+                self.randomness.len(),
+                self.num_vars
+            )));
+        }
+
+        // `verify_round` stops counting at the last round, `verifier_init` starts from 1.
+        let finished = received == self.num_vars;
+        let round = if finished { self.num_vars.max(1) } else { received + 1 };
+        if self.finished != finished || self.round != round {
+            return Err(crate::Error::InvalidState(format!(
+                "Round {} (finished: {}) does not follow {received} of {} messages.",
+                self.round, self.finished, self.num_vars
+            )));
+        }
+
+        if let Some(bounds) = &self.degree_bounds {
+            if bounds.len() != self.num_vars {
+                return Err(crate::Error::InvalidState(format!(
+                    "Got {} degree bounds for {} rounds.",
+                    bounds.len(),
+                    self.num_vars
+                )));
+            }
+            if let Err(e) = self.check_degrees(|i| bounds[i]) {
+                return Err(crate::Error::InvalidState(e.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Number of variables in the prover's claimed polynomial `g`, i.e. the number of rounds.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Current round, counted from 1 and stopping at the last one.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Whether a message was received for every round.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Number of prover messages still expected.
+    pub fn rounds_remaining(&self) -> usize {
+        if self.finished {
+            0
+        } else {
+            self.num_vars - self.round + 1
+        }
+    }
+
+    /// Challenges sampled so far, in round order.
+    pub fn challenges(&self) -> &[F] {
+        &self.randomness
+    }
+
+    /// Round polynomials received so far, in round order.
+    pub fn round_polynomials(&self) -> &[UniPoly<F>] {
+        &self.partial_sums
+    }
+
+    /// Points at which every round polynomial is summed for `base = 2` without per-variable
+    /// domains, set by `verifier_init_with_domain_point`.
+    pub fn domain(&self) -> DomainPoint<F> {
+        self.domain
+    }
+
+    /// Number of points each variable is summed over, 2 for the boolean hypercube.
+    pub fn base(&self) -> usize {
+        self.base
+    }
+
+    /// Per-variable domains, if the verifier was initialized with `verifier_init_with_domains`.
+    pub fn domains(&self) -> Option<&[Vec<F>]> {
+        self.domains.as_deref()
+    }
+
+    /// Per-round degree bounds, if the verifier was initialized with `verifier_init_with_degrees`.
+    pub fn degree_bounds(&self) -> Option<&[usize]> {
+        self.degree_bounds.as_deref()
+    }
+
+    /// Panics in debug builds if the counters disagree with the messages received, as checked
+    /// by `resume`.
+    pub fn assert_consistent(&self) {
+        if cfg!(debug_assertions) {
+            if let Err(e) = self.check_consistency() {
+                panic!("Inconsistent verifier state: {e}");
+            }
+        }
+    }
+
+    /// The challenges and the round polynomials received so far, in round order.
+    pub fn into_parts(self) -> (Vec<F>, Vec<UniPoly<F>>) {
+        (self.randomness, self.partial_sums)
+    }
+
+    /// Runs the checks of `partial_verify` on the first `k` rounds only, e.g. to check a long
+    /// transcript as its messages arrive.
+    ///
+    /// Returns the claim that the polynomial of round `k + 1` must sum to, i.e. `asserted_sum`
+    /// for `k = 0` and `g_k(r_k)` afterwards.
+    pub fn check_up_to_round(&self, asserted_sum: F, k: usize) -> Result<F, crate::Error> {
+        let received = self.partial_sums.len().min(self.randomness.len());
+        if k > received {
+            return Err(crate::Error::RoundMismatch { expected: k, got: received });
+        }
+
+        let mut expected_sum = asserted_sum;
+        for (i, (gi, r)) in self.partial_sums.iter().zip(&self.randomness).take(k).enumerate() {
+            let sum = self.round_sum(i, gi);
+            if sum != expected_sum {
+                return Err(crate::Error::sum_mismatch(i + 1, expected_sum, sum));
+            }
+
+            // Update expected_sum for the next round
+            expected_sum = gi.evaluate(r);
+        }
+
+        Ok(expected_sum)
+    }
+
+    /// Sum of `gi` over the domain of variable `i` (from 0): `Σ_{t ∈ D_i} gi(t)` for
+    /// per-variable domains, `gi(0) + gi(1)` at the endpoints of `domain` for `base = 2`, and
+    /// `Σ_{t=0}^{base-1} gi(t)` otherwise.
+    pub(crate) fn round_sum(&self, i: usize, gi: &UniPoly<F>) -> F {
+        if let Some(domains) = &self.domains {
+            return domains[i].iter().map(|t| gi.evaluate(t)).sum();
+        }
+        if self.base == 2 {
+            return gi.evaluate(&self.domain.zero) + gi.evaluate(&self.domain.one);
+        }
+
+        (0..self.base).map(|t| gi.evaluate(&F::from(t as u64))).sum()
+    }
+
+    /// Checks the polynomial received in every round `i` (from 0) against `bound(i)`.
+    pub(crate) fn check_degrees(&self, bound: impl Fn(usize) -> usize) -> Result<(), crate::Error> {
+        match self.partial_sums.iter().enumerate().find(|(i, gi)| gi.degree() > bound(*i)) {
+            Some((i, gi)) => Err(crate::Error::DegreeTooHigh {
+                round: i + 1,
+                got: gi.degree(),
+                bound: bound(i),
+            }),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Verifier's output when it is (almost) convinced.
@@ -37,50 +425,174 @@ pub struct VerifierOutput<F: Field> {
     pub expected_evaluation: F,
 }
 
-// A degree lookup table for all variables in `g`.
+impl<F: Field> VerifierOutput<F> {
+    /// Final check of the protocol: queries `oracle` for `g(r_vec)` and compares its answer
+    /// with `expected_evaluation`.
+    ///
+    /// `oracle` may be anything that can answer the query, e.g. an opening of a commitment to
+    /// `g` or a delegated evaluation.
+    pub fn verify_oracle_call(self, oracle: impl Fn(&[F]) -> F) -> Result<(), crate::Error> {
+        let oracle_eval = oracle(&self.r_vec);
+        if oracle_eval == self.expected_evaluation {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(self.expected_evaluation, oracle_eval))
+        }
+    }
+}
+
+/// Degree bound of the round polynomial in every round, all that a verifier needs to know about
+/// `g` besides its number of variables until the final query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DegreeInfo {
+    /// Bound on the degree of the round polynomial of every round `i` (from 0)
+    pub per_round_bounds: Vec<usize>,
+}
+
+impl DegreeInfo {
+    /// Bounds given explicitly, one per round.
+    pub fn new(per_round_bounds: Vec<usize>) -> Self {
+        Self { per_round_bounds }
+    }
+
+    /// Bounds of the standard protocol for `g`, i.e. `max_degrees(g)`.
+    pub fn from_poly<F: Field>(g: &MultiPoly<F>) -> Self {
+        Self::new(max_degrees(g))
+    }
+}
+
+/// A degree lookup table for all variables in `g`.
+///
+/// Makes a single flat pass over every `(variable, power)` pair of every term.
 pub fn max_degrees<F: Field>(g: &MultiPoly<F>) -> Vec<usize> {
-	let mut degrees: Vec<usize> = vec![0; g.num_vars()];
+    let mut degrees: Vec<usize> = vec![0; g.num_vars()];
 
-	cfg_into_iter!(g.terms()).for_each(|(_, term)| {
-		cfg_into_iter!(term).for_each(|(var, power)| {
-			if *power > degrees[*var] {
-				degrees[*var] = *power
-			}
-		});
-	});
+    for (var, power) in g.terms().iter().flat_map(|(_, term)| term.iter()) {
+        degrees[*var] = degrees[*var].max(*power);
+    }
 
-	degrees
+    degrees
 }
 
 impl<F: Field> IPForSumcheck<F> {
     /// Initializes the verifier
     ///
+    /// With no variables there is no round to run, so the state is finished from the start.
     pub fn verifier_init(num_variables: usize) -> VerifierState<F> {
+        Self::verifier_init_with_base(num_variables, 2)
+    }
+
+    /// Initializes the verifier for a sum over `{0, ..., base - 1}^num_variables`, as argued
+    /// for by a prover from `prover_init_with_base`.
+    ///
+    /// `partial_verify` then checks `Σ_{t=0}^{base-1} gi(t)` against each round's claim. With
+    /// `base = 2` this is exactly `verifier_init`.
+    pub fn verifier_init_with_base(num_variables: usize, base: usize) -> VerifierState<F> {
+        if base < 2 {
+            panic!("Base should be at least 2...");
+        }
+
         VerifierState {
             round: 1,
             num_vars: num_variables,
-            finished: false,
+            finished: num_variables == 0,
             partial_sums: Vec::with_capacity(num_variables),
             randomness: Vec::with_capacity(num_variables),
+            domain: DomainPoint::boolean(),
+            base,
+            domains: None,
+            degree_bounds: None,
+        }
+    }
+
+    /// Initializes the verifier with the degree bound of every round, so that `verify_round`
+    /// rejects an over-degree message as soon as it arrives and `verify` no longer derives the
+    /// bounds from `g`.
+    ///
+    /// Fails unless there is exactly one bound per variable.
+    pub fn verifier_init_with_degrees(
+        num_variables: usize,
+        degrees: DegreeInfo,
+    ) -> Result<VerifierState<F>, crate::Error> {
+        if degrees.per_round_bounds.len() != num_variables {
+            return Err(crate::Error::ProtocolError(format!(
+                "Expected {num_variables} degree bounds, got {}.",
+                degrees.per_round_bounds.len()
+            )));
         }
+
+        let mut verifier_state = Self::verifier_init(num_variables);
+        verifier_state.degree_bounds = Some(degrees.per_round_bounds);
+
+        Ok(verifier_state)
+    }
+
+    /// Initializes the verifier for a sum over `D_0 × ... × D_{v-1}`, as argued for by a
+    /// prover from `prover_init_with_domains`, with one round per domain.
+    ///
+    /// `partial_verify` then checks `Σ_{t ∈ D_i} gi(t)` against the claim of round `i + 1`.
+    /// Fails if a domain is empty or repeats a point.
+    pub fn verifier_init_with_domains(domains: Domain<F>) -> Result<VerifierState<F>, crate::Error> {
+        check_domains(&domains, domains.len())?;
+
+        let mut verifier_state = Self::verifier_init(domains.len());
+        verifier_state.domains = Some(domains);
+
+        Ok(verifier_state)
+    }
+
+    /// Initializes the verifier for a sum over `{zero, one}^v`, as argued for by a prover from
+    /// `prover_init_with_domains` with the same two points for every variable.
+    ///
+    /// `partial_verify` then checks `gi(zero) + gi(one)` against each round's claim. With
+    /// `DomainPoint::boolean()` this is exactly `verifier_init`. Fails if the two points coincide.
+    pub fn verifier_init_with_domain_point(
+        num_variables: usize,
+        domain: DomainPoint<F>,
+    ) -> Result<VerifierState<F>, crate::Error> {
+        check_domain_point(&domain)?;
+
+        let mut verifier_state = Self::verifier_init(num_variables);
+        verifier_state.domain = domain;
+
+        Ok(verifier_state)
     }
 
     /// Run verifier at current round, given a prover message.
     ///
-    /// `verify_round` only samples and stores randomness. Intermediate verifications
+    /// `verify_round` only draws and stores randomness. Intermediate verifications
     /// are postponed until `partial_verify` is invoked at the last step.
     /// The partial sums received from the prover are also stored for future use.
-    pub fn verify_round<R: RngCore>(
+    ///
+    /// The challenge comes from `transcript`, after it has absorbed `prover_msg`.
+    /// Sending more messages than there are variables is an error, and so is a message above
+    /// the round's degree bound if the verifier was initialized with `verifier_init_with_degrees`.
+    pub fn verify_round<T: SumcheckTranscript<F>>(
         prover_msg: ProverMsg<F>,
         verifier_state: &mut VerifierState<F>,
-        rng: &mut R,
-    ) -> Option<VerifierMsg<F>> {
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error> {
         if verifier_state.finished {
-            panic!("Incorrect verifier state: Verifier is already finished...");
+            return Err(crate::Error::RoundMismatch {
+                expected: verifier_state.num_vars,
+                got: verifier_state.partial_sums.len() + 1,
+            });
+        }
+
+        let i = verifier_state.partial_sums.len();
+        if let Some(bound) = verifier_state.degree_bounds.as_ref().map(|bounds| bounds[i]) {
+            if prover_msg.degree() > bound {
+                return Err(crate::Error::DegreeTooHigh {
+                    round: i + 1,
+                    got: prover_msg.degree(),
+                    bound,
+                });
+            }
         }
 
-        // Sample and store randomness for the current round
-        let v_msg = Self::sample_r(rng);
+        // Draw and store randomness for the current round
+        transcript.absorb_prover_msg(&prover_msg);
+        let v_msg = VerifierMsg::new(transcript.challenge());
         verifier_state.randomness.push(v_msg.randomness);
 
         verifier_state
@@ -94,7 +606,36 @@ impl<F: Field> IPForSumcheck<F> {
             verifier_state.round += 1;
         }
 
-        Some(v_msg)
+        Ok(v_msg)
+    }
+
+    /// Same as `verify_round`, except that `prover_msg` is checked against the running claim
+    /// before the challenge is drawn, so that a cheating prover is caught in the round it
+    /// deviates instead of after the last one.
+    ///
+    /// The claim is `asserted_sum` in round 1 and `g_{i-1}(r_{i-1})` afterwards, read off the
+    /// previous message and challenge, so a state may mix lazy and checked rounds. A mismatch
+    /// fails with `SumMismatch` for the current round and leaves `verifier_state` untouched.
+    pub fn verify_round_checked<T: SumcheckTranscript<F>>(
+        prover_msg: ProverMsg<F>,
+        verifier_state: &mut VerifierState<F>,
+        asserted_sum: F,
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error> {
+        // Once finished, `verify_round` reports the extra message.
+        if !verifier_state.finished {
+            let i = verifier_state.partial_sums.len();
+            let expected_sum = match i {
+                0 => asserted_sum,
+                _ => verifier_state.partial_sums[i - 1].evaluate(&verifier_state.randomness[i - 1]),
+            };
+            let sum = verifier_state.round_sum(i, &prover_msg.gi);
+            if sum != expected_sum {
+                return Err(crate::Error::sum_mismatch(i + 1, expected_sum, sum));
+            }
+        }
+
+        Self::verify_round(prover_msg, verifier_state, transcript)
     }
 
     /// `partial_verify` only performs the intermediate checks of the the sumcheck protocol.
@@ -104,76 +645,124 @@ impl<F: Field> IPForSumcheck<F> {
     /// If `asserted_sum` is correct, then polynomial `g` evaluated at the point `r_vec`
     /// should match `expected_evaluation`.
     /// Otherwise, w.h.p. those two will not be equal by the Schwartz-Zippel lemma.
+    ///
+    /// `verifier_state` may be passed by reference, so the same transcript can be checked
+    /// against several candidate sums.
     pub fn partial_verify(
-        verifier_state: VerifierState<F>,
+        verifier_state: impl Borrow<VerifierState<F>>,
         asserted_sum: F,
     ) -> Result<VerifierOutput<F>, crate::Error> {
-        if !verifier_state.finished {
-            panic!("Verifier has not finished yet...");
-        }
-
-        let mut expected_sum = asserted_sum;
-        if verifier_state.partial_sums.len() != verifier_state.num_vars {
-            panic!("Insufficient number of rounds...");
-        } else if verifier_state.randomness.len() != verifier_state.num_vars {
-            panic!("Insufficient random field elements...");
+        let verifier_state = verifier_state.borrow();
+        if !verifier_state.finished
+            || verifier_state.partial_sums.len() != verifier_state.num_vars
+            || verifier_state.randomness.len() != verifier_state.num_vars
+        {
+            return Err(crate::Error::RoundMismatch {
+                expected: verifier_state.num_vars,
+                got: verifier_state.partial_sums.len().min(verifier_state.randomness.len()),
+            });
         }
 
-        for i in 0..verifier_state.num_vars {
-            let gi = &verifier_state.partial_sums[i];
-            
-            let p0 = gi.evaluate(&0_u32.into());
-            let p1 = gi.evaluate(&1_u32.into());
-
-            if p0 + p1 != expected_sum {
-                return Err(crate::Error::Reject(Some(
-                    "Prover message is inconsistent with the claim.".into(),
-                )));
-            }
-
-            // Update expected_sum for the next iteration
-            expected_sum = gi.evaluate(&verifier_state.randomness[i]);
-        }
+        let expected_evaluation =
+            verifier_state.check_up_to_round(asserted_sum, verifier_state.num_vars)?;
 
         Ok(VerifierOutput {
-            r_vec: verifier_state.randomness,
-            expected_evaluation: expected_sum,
+            r_vec: verifier_state.randomness.clone(),
+            expected_evaluation,
         })
     }
 
+    /// Fast path for callers that already trust `g`, e.g. because it was committed to and
+    /// opened separately: runs the consistency checks of `partial_verify` without requiring
+    /// access to the polynomial.
+    ///
+    /// **This is not full verification.** Neither the per-round degree bounds nor the final
+    /// oracle query are checked; the caller is responsible for confirming that `g` evaluated at
+    /// `r_vec` equals `expected_evaluation` before accepting `asserted_sum`.
+    pub fn verify_consistency_only(
+        verifier_state: impl Borrow<VerifierState<F>>,
+        asserted_sum: F,
+    ) -> Result<VerifierOutput<F>, crate::Error> {
+        Self::partial_verify(verifier_state, asserted_sum)
+    }
+
     /// Full verification.
     ///
+    /// The round polynomials are bounded by `max_degrees(g)`, unless `verify_round` already
+    /// enforced the bounds the verifier was initialized with.
     pub fn verify(
         g: &MultiPoly<F>,
-        verifier_state: VerifierState<F>,
+        verifier_state: impl Borrow<VerifierState<F>>,
         asserted_sum: F,
     ) -> Result<(), crate::Error> {
-        let degrees = max_degrees(&g);
-
-        assert!((0..verifier_state.num_vars)
-            .all(|i| verifier_state.partial_sums[i].degree() <= degrees[i]));
-
-        if let Ok(v_out) = Self::partial_verify(verifier_state, asserted_sum) {
-            if g.evaluate(&v_out.r_vec) == v_out.expected_evaluation {
-                Ok(())
-            } else {
-                Err(crate::Error::Reject(Some(
-                    "Verification failed.".into(),
-                )))
-            }
-        } else {
-            Err(crate::Error::Reject(Some(
-                "Partial verification failed.".into(),
-            )))
+        let verifier_state = verifier_state.borrow();
+        validate_poly(g)?;
+        if verifier_state.degree_bounds.is_none() {
+            let degrees = max_degrees(g);
+            verifier_state.check_degrees(|i| degrees[i])?;
+        }
+
+        Self::partial_verify(verifier_state, asserted_sum)?
+            .verify_oracle_call(|r_vec| g.evaluate(&r_vec.to_vec()))
+    }
+
+    /// Full verification, with the final query answered by `oracle` instead of by evaluating
+    /// `g`, e.g. through the opening of a commitment to `g`.
+    ///
+    /// The round polynomials are bounded by `oracle`'s degree bounds.
+    pub fn verify_with_oracle(
+        verifier_state: impl Borrow<VerifierState<F>>,
+        asserted_sum: F,
+        oracle: &impl PolyOracle<F>,
+    ) -> Result<(), crate::Error> {
+        let verifier_state = verifier_state.borrow();
+        if verifier_state.num_vars != oracle.num_vars() {
+            return Err(crate::Error::RoundMismatch {
+                expected: oracle.num_vars(),
+                got: verifier_state.num_vars,
+            });
+        }
+        verifier_state.check_degrees(|i| oracle.degree_bound(i))?;
+
+        // The query may fail, e.g. on an invalid opening, so it is answered before the call.
+        let v_out = Self::partial_verify(verifier_state, asserted_sum)?;
+        let oracle_eval = oracle.query(&v_out.r_vec)?;
+        v_out.verify_oracle_call(|_| oracle_eval)
+    }
+
+    /// Full verification against pre-sampled `randomness`, e.g. challenges shared with other
+    /// protocols, instead of drawing them from a transcript.
+    ///
+    /// Expects one prover message and one challenge per variable of `g`.
+    pub fn verify_with_randomness(
+        g: &MultiPoly<F>,
+        prover_msgs: &[ProverMsg<F>],
+        randomness: Vec<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        let num_vars = g.num_vars();
+        if prover_msgs.len() != num_vars || randomness.len() != num_vars {
+            return Err(crate::Error::RoundMismatch {
+                expected: num_vars,
+                got: prover_msgs.len().min(randomness.len()),
+            });
         }
+
+        // The state `verifier_init` and `num_vars` calls to `verify_round` end in: the round
+        // counter starts from 1 and stops at the last round, so that it is never 0.
+        let mut verifier_state = Self::verifier_init(num_vars);
+        verifier_state.round = num_vars.max(1);
+        verifier_state.finished = true;
+        verifier_state.partial_sums = prover_msgs.iter().map(|msg| msg.gi.clone()).collect();
+        verifier_state.randomness = randomness;
+
+        Self::verify(g, verifier_state, asserted_sum)
     }
 
     /// Verifier sampling function.
     ///
     #[inline]
     pub fn sample_r<R: RngCore>(rng: &mut R) -> VerifierMsg<F> {
-        VerifierMsg {
-            randomness: F::rand(rng),
-        }
+        VerifierMsg::new(F::rand(rng))
     }
 }
\ No newline at end of file
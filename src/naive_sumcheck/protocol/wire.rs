@@ -0,0 +1,135 @@
+//! Framing of protocol messages over byte streams
+//!
+//! Every message is written as a one-byte tag, telling prover and verifier messages apart, the
+//! length of its payload as a little-endian `u64`, and the payload itself: the compressed
+//! `CanonicalSerialize` encoding of the message. `FramedStream` speaks this framing over any
+//! `Read + Write` stream, e.g. a `TcpStream`, as a `MsgChannel` for either party.
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::naive_sumcheck::protocol::{
+    interactive::MsgChannel,
+    prover::ProverMsg,
+    verifier::VerifierMsg,
+};
+
+/// Tag of a frame holding a `ProverMsg`
+pub const PROVER_MSG_TAG: u8 = 1;
+
+/// Tag of a frame holding a `VerifierMsg`
+pub const VERIFIER_MSG_TAG: u8 = 2;
+
+/// Largest payload `read_framed` accepts, so that a length read from the wire never makes
+/// the reader wait for an unbounded message
+pub const MAX_FRAME_LEN: usize = 1 << 24;
+
+/// Writes `msg` as a frame tagged with `tag`, and flushes the writer.
+fn write_frame<W: Write, M: CanonicalSerialize>(
+    mut writer: W,
+    tag: u8,
+    msg: &M,
+) -> Result<(), crate::Error> {
+    let mut frame = Vec::with_capacity(9 + msg.compressed_size());
+    frame.push(tag);
+    frame.extend_from_slice(&(msg.compressed_size() as u64).to_le_bytes());
+    msg.serialize_compressed(&mut frame)?;
+
+    writer.write_all(&frame)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads a frame that must be tagged with `tag` and decodes its payload as an `M`.
+///
+/// Running out of input, even mid-frame, is an `UnexpectedEof` I/O error.
+fn read_frame<R: Read, M: CanonicalDeserialize>(
+    mut reader: R,
+    tag: u8,
+) -> Result<M, crate::Error> {
+    let mut header = [0_u8; 9];
+    reader.read_exact(&mut header)?;
+    if header[0] != tag {
+        return Err(crate::Error::DeserializeError(format!(
+            "Expected a frame tagged {tag}, got tag {}.",
+            header[0]
+        )));
+    }
+    let len = u64::from_le_bytes(header[1..].try_into().unwrap());
+    if len > MAX_FRAME_LEN as u64 {
+        return Err(crate::Error::DeserializeError(format!(
+            "Frame of {len} bytes exceeds the limit of {MAX_FRAME_LEN}."
+        )));
+    }
+
+    // Grow the payload as it arrives, never allocating more than the input actually holds.
+    let len = len as usize;
+    let mut payload = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    while payload.len() < len {
+        let n = chunk.len().min(len - payload.len());
+        reader.read_exact(&mut chunk[..n])?;
+        payload.extend_from_slice(&chunk[..n]);
+    }
+
+    let mut bytes = &payload[..];
+    let msg = M::deserialize_compressed(&mut bytes)?;
+    if !bytes.is_empty() {
+        return Err(crate::Error::DeserializeError(format!(
+            "Frame has {} bytes left after its message.",
+            bytes.len()
+        )));
+    }
+
+    Ok(msg)
+}
+
+impl<F: Field> ProverMsg<F> {
+    /// Writes the message as a frame tagged `PROVER_MSG_TAG`.
+    pub fn write_framed<W: Write>(&self, writer: W) -> Result<(), crate::Error> {
+        write_frame(writer, PROVER_MSG_TAG, self)
+    }
+
+    /// Reads a message written by `write_framed`, failing on any other frame.
+    pub fn read_framed<R: Read>(reader: R) -> Result<Self, crate::Error> {
+        read_frame(reader, PROVER_MSG_TAG)
+    }
+}
+
+impl<F: Field> VerifierMsg<F> {
+    /// Writes the message as a frame tagged `VERIFIER_MSG_TAG`.
+    pub fn write_framed<W: Write>(&self, writer: W) -> Result<(), crate::Error> {
+        write_frame(writer, VERIFIER_MSG_TAG, self)
+    }
+
+    /// Reads a message written by `write_framed`, failing on any other frame.
+    pub fn read_framed<R: Read>(reader: R) -> Result<Self, crate::Error> {
+        read_frame(reader, VERIFIER_MSG_TAG)
+    }
+}
+
+/// A byte stream carrying framed messages, for either end of the protocol
+pub struct FramedStream<S: Read + Write>(pub S);
+
+impl<F: Field, S: Read + Write> MsgChannel<ProverMsg<F>, VerifierMsg<F>> for FramedStream<S> {
+    fn send(&mut self, msg: ProverMsg<F>) -> Result<(), crate::Error> {
+        msg.write_framed(&mut self.0)
+    }
+
+    fn recv(&mut self) -> Result<VerifierMsg<F>, crate::Error> {
+        VerifierMsg::read_framed(&mut self.0)
+    }
+}
+
+impl<F: Field, S: Read + Write> MsgChannel<VerifierMsg<F>, ProverMsg<F>> for FramedStream<S> {
+    fn send(&mut self, msg: VerifierMsg<F>) -> Result<(), crate::Error> {
+        msg.write_framed(&mut self.0)
+    }
+
+    fn recv(&mut self) -> Result<ProverMsg<F>, crate::Error> {
+        ProverMsg::read_framed(&mut self.0)
+    }
+}
@@ -0,0 +1,120 @@
+//! Zero-knowledge variant of the Sumcheck protocol
+//!
+//! The prover masks `g` with a random polynomial `ρ` that has the same number of
+//! variables and the same per-variable degree profile as `g`. After the prover
+//! announces the hypercube sum of `ρ`, the verifier picks a random challenge `c`
+//! and the ordinary sumcheck is run on `g + c·ρ`. The final claim about `g` is
+//! recovered by subtracting `c·ρ(r_vec)`.
+//!
+//! This is the honest-verifier formulation: instead of a commitment to `ρ`, the
+//! verifier is given oracle access to it during the final check.
+use ark_ff::Field;
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::polynomial::{DenseMVPolynomial, Polynomial};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{rand::RngCore, vec::Vec};
+
+use crate::naive_sumcheck::eval::hypercube_sum;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverState,
+    verifier::{max_degrees, VerifierMsg, VerifierState},
+};
+use crate::MultiPoly;
+
+/// Message carrying the hypercube sum of the prover's masking polynomial
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+pub struct MaskMsg<F: Field> {
+    /// sum of the masking polynomial `ρ` over the boolean hypercube
+    pub mask_sum: F,
+}
+
+/// Prover State for the zero-knowledge variant, before the masking challenge is known
+pub struct ZkProverState<F: Field + From<i32>> {
+    /// Polynomial for which we want to prove a relation
+    pub g: MultiPoly<F>,
+    /// Random masking polynomial with the same degree profile as `g`
+    pub mask: MultiPoly<F>,
+}
+
+/// Samples a random polynomial with the same number of variables and per-variable
+/// degrees as `g`, i.e. `ρ(x) = a_0 + Σ_i Σ_{k = 1..d_i} a_{i,k} x_i^k`.
+pub fn random_mask<F: Field, R: RngCore>(g: &MultiPoly<F>, rng: &mut R) -> MultiPoly<F> {
+    let mut terms = vec![(F::rand(rng), SparseTerm::new(vec![]))];
+
+    for (var, degree) in max_degrees(g).into_iter().enumerate() {
+        for power in 1..=degree {
+            terms.push((F::rand(rng), SparseTerm::new(vec![(var, power)])));
+        }
+    }
+
+    MultiPoly::from_coefficients_vec(g.num_vars(), terms)
+}
+
+impl<F: Field + From<i32>> ZkProverState<F> {
+    /// Oracle access to the masking polynomial, standing in for the opening of a commitment to `ρ`.
+    pub fn mask_oracle(&self, point: &[F]) -> F {
+        self.mask.evaluate(&point.to_vec())
+    }
+}
+
+impl<F: Field + From<i32>> IPForSumcheck<F> {
+    /// Initialize a zero-knowledge prover for `g`, sampling a masking polynomial from `rng`.
+    ///
+    /// Returns the prover state along with the first message, which announces the mask's sum.
+    /// As with `prover_init`, a polynomial without variables is a valid instance with no rounds;
+    /// its mask is a random constant.
+    pub fn prover_init_zk<R: RngCore>(
+        polynomial: MultiPoly<F>,
+        rng: &mut R,
+    ) -> (ZkProverState<F>, MaskMsg<F>) {
+        let mask = random_mask(&polynomial, rng);
+        let mask_sum = hypercube_sum(&mask);
+
+        (ZkProverState { g: polynomial, mask }, MaskMsg { mask_sum })
+    }
+
+    /// Receive the verifier's masking challenge `c` and produce an ordinary prover state for `g + c·ρ`.
+    ///
+    pub fn prover_apply_mask(
+        zk_state: ZkProverState<F>,
+        v_msg: &VerifierMsg<F>,
+    ) -> Result<ProverState<F>, crate::Error> {
+        let c = v_msg.randomness;
+        let masked = MultiPoly::from_coefficients_vec(
+            zk_state.g.num_vars(),
+            zk_state
+                .mask
+                .terms()
+                .iter()
+                .map(|(coeff, term)| (c * coeff, term.clone()))
+                .collect::<Vec<_>>(),
+        );
+
+        Self::prover_init(&zk_state.g + &masked)
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Full verification of the zero-knowledge variant.
+    ///
+    /// `verifier_state` must have been driven over the rounds of the sumcheck for `g + c·ρ`, where
+    /// `c` is the randomness in `mask_challenge`. `mask_oracle` answers the single query to `ρ`.
+    pub fn verify_zk(
+        g: &MultiPoly<F>,
+        mask_oracle: impl Fn(&[F]) -> F,
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+        mask_msg: &MaskMsg<F>,
+        mask_challenge: &VerifierMsg<F>,
+    ) -> Result<(), crate::Error> {
+        let c = mask_challenge.randomness;
+        let degrees = max_degrees(g);
+
+        verifier_state.check_degrees(|i| degrees[i])?;
+
+        // The rounds are over `g + c·mask`, so the final query is too.
+        Self::partial_verify(verifier_state, asserted_sum + c * mask_msg.mask_sum)?
+            .verify_oracle_call(|r_vec| g.evaluate(&r_vec.to_vec()) + c * mask_oracle(r_vec))
+    }
+}
@@ -0,0 +1,152 @@
+//! Streaming variant of the Sumcheck protocol with constant verifier state
+//!
+//! Rather than storing every partial sum until the end, the verifier checks each round as
+//! soon as it arrives and only keeps the current expected sum and the latest random point.
+//! Each round reduces the claim about `g` by one variable, and the final single-point claim
+//! is handed to a callback (e.g. a commitment opening, or another protocol instance).
+use ark_ff::Field;
+use ark_poly::Polynomial;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::VerifierMsg,
+};
+use crate::transcript::SumcheckTranscript;
+use crate::MultiPoly;
+
+/// Verifier with `O(1)` state
+pub struct RecursiveVerifier<F: Field> {
+    /// Number of rounds to run
+    num_vars: usize,
+    /// Number of rounds completed so far
+    round: usize,
+    /// Uniform bound on the degree of every round polynomial
+    degree_bound: usize,
+    /// Claimed value of the current partial sum
+    expected_sum: F,
+    /// The most recent random point
+    last_r: Option<F>,
+}
+
+/// Prover that generates each message on demand and ends with a single reduced claim
+pub struct RecursiveProver<F: Field + From<i32>> {
+    /// Underlying prover state
+    state: ProverState<F>,
+    /// The verifier's challenge for the last round, once received
+    final_r: Option<F>,
+}
+
+impl<F: Field> RecursiveVerifier<F> {
+    /// Initializes a verifier for a `num_vars`-round sumcheck of `asserted_sum`, where every
+    /// round polynomial must have degree at most `degree_bound`.
+    pub fn new(num_vars: usize, degree_bound: usize, asserted_sum: F) -> Self {
+        Self {
+            num_vars,
+            round: 0,
+            degree_bound,
+            expected_sum: asserted_sum,
+            last_r: None,
+        }
+    }
+
+    /// Checks a prover message against the current claim, then draws the next random point
+    /// from `transcript` and reduces the claim to `gi(r)`.
+    pub fn receive<T: SumcheckTranscript<F>>(
+        &mut self,
+        prover_msg: &ProverMsg<F>,
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error> {
+        if self.is_finished() {
+            return Err(crate::Error::RoundMismatch {
+                expected: self.num_vars,
+                got: self.round + 1,
+            });
+        }
+
+        let gi = &prover_msg.gi;
+        if gi.degree() > self.degree_bound {
+            return Err(crate::Error::DegreeTooHigh {
+                round: self.round + 1,
+                got: gi.degree(),
+                bound: self.degree_bound,
+            });
+        }
+        let sum = gi.evaluate(&F::zero()) + gi.evaluate(&F::one());
+        if sum != self.expected_sum {
+            return Err(crate::Error::sum_mismatch(self.round + 1, self.expected_sum, sum));
+        }
+
+        transcript.absorb_prover_msg(prover_msg);
+        let v_msg = VerifierMsg::new(transcript.challenge());
+        self.expected_sum = gi.evaluate(&v_msg.randomness);
+        self.last_r = Some(v_msg.randomness);
+        self.round += 1;
+
+        Ok(v_msg)
+    }
+
+    /// If all rounds have been received
+    pub fn is_finished(&self) -> bool {
+        self.round == self.num_vars
+    }
+
+    /// Hands the final query to `oracle`, which receives the latest random point and answers
+    /// with the evaluation of `g` at the point formed by all challenges; that answer must match
+    /// the claim the rounds reduced to.
+    ///
+    /// Every challenge was returned to the caller as a `VerifierMsg`, so the callback can
+    /// reconstruct the full point if it needs to. Without variables there are no rounds and no
+    /// challenge: `oracle` receives `None`, and its answer, the constant `g`, must match the
+    /// asserted sum.
+    pub fn finalize(self, oracle: impl FnOnce(Option<F>) -> F) -> Result<(), crate::Error> {
+        if !self.is_finished() {
+            return Err(crate::Error::RoundMismatch {
+                expected: self.num_vars,
+                got: self.round,
+            });
+        }
+
+        let oracle_eval = oracle(self.last_r);
+        if oracle_eval == self.expected_sum {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(self.expected_sum, oracle_eval))
+        }
+    }
+}
+
+impl<F: Field + From<i32>> RecursiveProver<F> {
+    /// Initializes the prover for `g`, failing as `prover_init` does.
+    pub fn new(g: MultiPoly<F>) -> Result<Self, crate::Error> {
+        Ok(Self {
+            state: IPForSumcheck::prover_init(g)?,
+            final_r: None,
+        })
+    }
+
+    /// Receives the verifier's latest challenge (`None` before the first round) and produces the
+    /// next message, or `None` once every round has been sent.
+    pub fn next_message(&mut self, challenge: Option<F>) -> Option<ProverMsg<F>> {
+        if self.state.round == self.state.g.num_vars {
+            self.final_r = self.final_r.or(challenge);
+            return None;
+        }
+
+        let v_msg = challenge.map(VerifierMsg::new);
+        Some(IPForSumcheck::prove_round(&mut self.state, &v_msg))
+    }
+
+    /// The single claim the protocol reduced to, `g(r_vec) = value`, available after the
+    /// last challenge has been received, or right away for a `g` without variables.
+    pub fn reduced_claim(&self) -> Option<(Vec<F>, F)> {
+        let mut r_vec = self.state.randomness.clone();
+        if self.state.g.num_vars > 0 {
+            r_vec.push(self.final_r?);
+        }
+        let value = self.state.g.evaluate(&r_vec);
+
+        Some((r_vec, value))
+    }
+}
@@ -0,0 +1,154 @@
+//! Prover for multilinear `g` that trades time for memory under a table budget
+//!
+//! `g` is given by an oracle for its values on the hypercube, indexed as in `to_binary_vec`,
+//! and is never tabulated in full. The first `k` rounds are answered by streaming over every
+//! boolean point, weighting each value by the equality polynomial of its first variables at the
+//! challenges so far, i.e. a table of at most `2^(k - 1)` entries. After `k` challenges, one more
+//! pass folds them into a table of the `2^(n - k)` values `g(r_1, ..., r_k, b)`, and the
+//! remaining rounds fold that table as the dense prover does.
+//!
+//! With `n` variables this costs `(k + 1) · 2^n` oracle calls and holds at most
+//! `2^k + 2^(n - k)` entries at a time: `k = 0` is the dense table prover, and `k = n / 2`
+//! needs about `2^(n / 2 + 1)` entries for `n / 2 + 1` passes over `g`. `ProverConfig` picks the
+//! smallest `k` whose tables fit in the budget.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{prover::ProverMsg, verifier::VerifierMsg};
+use crate::poly_utils::{build_eq_x_r_evals, uni_poly_from_evaluations};
+
+/// Memory budget of a `SqrtSpaceProver`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProverConfig {
+    /// Most field elements the prover may hold in its tables at once
+    pub max_table_entries: usize,
+}
+
+/// Prover State over a hypercube oracle for multilinear `g`, see the module documentation
+pub struct SqrtSpaceProver<F: Field, O: Fn(usize) -> F + Sync> {
+    /// Number of variables of `g`
+    num_vars: usize,
+    /// Oracle for `g` on the hypercube
+    oracle: O,
+    /// Number of rounds answered by streaming, before the table is built
+    split: usize,
+    /// Values of `g` with the challenges so far fixed, once at least `split` are received
+    table: Option<Vec<F>>,
+    /// randomness provided by the verifier
+    randomness: Vec<F>,
+    /// The current round number
+    round: usize,
+}
+
+impl<F: Field, O: Fn(usize) -> F + Sync> SqrtSpaceProver<F, O> {
+    /// Initialize prover to argue for the sum of multilinear `g` over the boolean hypercube,
+    /// given an `oracle` for `g(b)` at index `b`.
+    ///
+    /// Fails if no split fits in `config.max_table_entries`, i.e. if the budget is below
+    /// `2^k + 2^(num_vars - k)` for every `k`. A `g` without variables is valid and has no rounds,
+    /// as in `prover_init`.
+    pub fn new(num_vars: usize, oracle: O, config: ProverConfig) -> Result<Self, crate::Error> {
+        let entries = |k: usize| (1_usize << k).saturating_add(1 << (num_vars - k));
+        let split = (0..=num_vars)
+            .find(|k| entries(*k) <= config.max_table_entries)
+            .ok_or_else(|| {
+                crate::Error::ProtocolError(format!(
+                    "A budget of {} table entries is too small for {num_vars} variables, which need at least {}.",
+                    config.max_table_entries,
+                    entries(num_vars / 2),
+                ))
+            })?;
+
+        Ok(Self {
+            num_vars,
+            oracle,
+            split,
+            table: None,
+            randomness: Vec::with_capacity(num_vars),
+            round: 0,
+        })
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    ///
+    /// Rounds before the split make a full pass over the oracle, the round at the split makes
+    /// one more to build the table, and later rounds only fold the table.
+    pub fn prove_round(&mut self, v_msg: &Option<VerifierMsg<F>>) -> ProverMsg<F> {
+        if self.round >= self.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if self.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            self.randomness.push(msg.randomness);
+            if let Some(table) = &mut self.table {
+                let half = table.len() / 2;
+                for b in 0..half {
+                    table[b] = table[b] + msg.randomness * (table[b + half] - table[b]);
+                }
+                table.truncate(half);
+            }
+        } else if self.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        let i = self.randomness.len();
+        if i == self.split && self.table.is_none() {
+            self.table = Some(self.fold_prefix());
+        }
+
+        let evals = match &self.table {
+            Some(table) => {
+                let (low, high) = table.split_at(table.len() / 2);
+                [low.iter().sum(), high.iter().sum()]
+            }
+            None => {
+                // g(r, t, b) = Σ_a eq(a, r) · g(a, t, b) for multilinear g.
+                let weights = build_eq_x_r_evals(&self.randomness);
+                let rest = self.num_vars - i - 1;
+                let mut evals = [F::zero(); 2];
+                for (a, w) in weights.iter().enumerate() {
+                    for (t, eval) in evals.iter_mut().enumerate() {
+                        let base = (a << (rest + 1)) | (t << rest);
+                        let sum: F = (0..(1_usize << rest)).map(|b| (self.oracle)(base | b)).sum();
+                        *eval += *w * sum;
+                    }
+                }
+                evals
+            }
+        };
+        let gi = uni_poly_from_evaluations(&evals);
+
+        // Increment round
+        self.round += 1;
+
+        ProverMsg::new(gi)
+    }
+
+    /// Values `g(r, b)` for every boolean suffix `b`, where `r` are the challenges so far.
+    fn fold_prefix(&self) -> Vec<F> {
+        let weights = build_eq_x_r_evals(&self.randomness);
+        let rest = self.num_vars - self.randomness.len();
+        let mut table = vec![F::zero(); 1 << rest];
+        for (a, w) in weights.iter().enumerate() {
+            for (b, entry) in table.iter_mut().enumerate() {
+                *entry += *w * (self.oracle)((a << rest) | b);
+            }
+        }
+
+        table
+    }
+
+    /// Number of rounds answered by streaming over the oracle, before the table is built.
+    pub fn split(&self) -> usize {
+        self.split
+    }
+
+    /// Number of messages sent so far.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
@@ -0,0 +1,94 @@
+//! Prover that only has oracle access to `g`
+//!
+//! Nothing about `g` is stored, neither its terms nor a table of its evaluations: every round
+//! makes a fresh pass over the remaining hypercube, calling the oracle at `degree_bound + 1`
+//! values of the current variable for each of the `2^(v - i - 1)` boolean suffixes, and keeps
+//! only the running sums and the point being queried. Round `i` thus costs
+//! `(degree_bound + 1) · 2^(v - i - 1)` oracle calls, trading time for memory.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{prover::ProverMsg, verifier::VerifierMsg};
+use crate::poly_utils::uni_poly_from_evaluations;
+
+/// Prover State over an evaluation oracle for `g`
+pub struct StreamingProver<F: Field, O: Fn(&[F]) -> F + Sync> {
+    /// Number of variables of `g`
+    num_vars: usize,
+    /// Bound on the degree of `g` in every variable
+    degree_bound: usize,
+    /// Evaluation oracle for `g`
+    oracle: O,
+    /// randomness provided by the verifier
+    randomness: Vec<F>,
+    /// The current round number
+    round: usize,
+}
+
+impl<F: Field, O: Fn(&[F]) -> F + Sync> StreamingProver<F, O> {
+    /// Initialize prover to argue for the sum of `g` over the boolean hypercube, given an
+    /// `oracle` for `g` and a bound on its degree in each variable.
+    ///
+    /// A `g` without variables is valid and has no rounds, as in `prover_init`.
+    pub fn new(num_vars: usize, degree_bound: usize, oracle: O) -> Self {
+        Self {
+            num_vars,
+            degree_bound,
+            oracle,
+            randomness: Vec::with_capacity(num_vars),
+            round: 0,
+        }
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    ///
+    /// Calls the oracle `(degree_bound + 1) · 2^(num_vars - round - 1)` times.
+    pub fn prove_round(&mut self, v_msg: &Option<VerifierMsg<F>>) -> ProverMsg<F> {
+        if self.round >= self.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if self.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            self.randomness.push(msg.randomness);
+        } else if self.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        // The point is laid out as `(r_1, ..., r_i, X, b)`; `X` and `b` are overwritten in place.
+        let i = self.randomness.len();
+        let mut point = self.randomness.clone();
+        point.resize(self.num_vars, F::zero());
+        let suffix_len = self.num_vars - i - 1;
+
+        let mut evals = vec![F::zero(); self.degree_bound + 1];
+        for b in 0..(1_usize << suffix_len) {
+            for (j, x) in point[i + 1..].iter_mut().enumerate() {
+                *x = if (b >> (suffix_len - 1 - j)) & 1 == 1 { F::one() } else { F::zero() };
+            }
+            for (t, eval) in evals.iter_mut().enumerate() {
+                point[i] = F::from(t as u64);
+                *eval += (self.oracle)(&point);
+            }
+        }
+        let gi = uni_poly_from_evaluations(&evals);
+
+        // Increment round
+        self.round += 1;
+
+        ProverMsg::new(gi)
+    }
+
+    /// Number of variables of `g`.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Number of messages sent so far.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
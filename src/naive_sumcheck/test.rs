@@ -1,8 +1,32 @@
 use ark_bls12_381::Fr as Fr;
-use ark_std::vec::Vec;
+use ark_ff::{UniformRand, Zero};
+use ark_std::{rand::RngCore, vec::Vec};
+use std::sync::Arc;
 use ark_poly::polynomial::multivariate::{SparsePolynomial, SparseTerm, Term};
+use ark_poly::{DenseMVPolynomial, Polynomial};
 
-use crate::naive_sumcheck::protocol::IPForSumcheck;
+use crate::naive_sumcheck::distributed_prover::{Coordinator, DistributedProver, RoundChallenge, WorkerState};
+use crate::naive_sumcheck::eval::{hypercube_evaluations, hypercube_sum};
+use crate::naive_sumcheck::linear_time::LinearTimeMleProver;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck, MESSAGE_VERSION,
+    interactive::{MpscChannel, MsgChannel},
+    async_verifier::AsyncSumcheckVerifier,
+    proof::{CompressedSumcheckProof, ProofTranscript, SumcheckProof},
+    prover::{to_base_vec, to_binary_vec, to_mixed_radix_vec, ProverMsg, ProverState},
+    verifier::{max_degrees, DegreeInfo, DomainPoint, VerifierMsg, VerifierState},
+};
+use crate::naive_sumcheck::recursive::{RecursiveProver, RecursiveVerifier};
+use crate::naive_sumcheck::sqrt_space_prover::{ProverConfig, SqrtSpaceProver};
+use crate::naive_sumcheck::streaming_prover::StreamingProver;
+use crate::naive_sumcheck::univariate_skip::{restrict_skipped, skip_domain};
+use crate::naive_sumcheck::virtual_poly::VirtualPolynomial;
+use crate::naive_sumcheck::zerocheck::ZeroCheck;
+use crate::mle::DenseMLE;
+use crate::poly_utils::{multiply, poly_from_signed};
+use crate::testing::{random_multilinear_polynomial, random_sparse_polynomial, raw_polynomial, thaler_example};
+use crate::transcript::{Blake2Transcript, RngTranscript};
+use crate::UniPoly;
 
 
 pub type MultiPoly<F> = SparsePolynomial<F, SparseTerm>;
@@ -15,33 +39,2301 @@ fn test_protocol() {
     let mut rng = rand::thread_rng();
     let num_vars: usize = 3;
 
-    // CAVEAT: Indexing must start at zero in order to avoid a panic
-    // caused from improper indexing withing ark-poly:
-    // See:
-    // https://docs.rs/ark-poly/latest/src/ark_poly/polynomial/multivariate/mod.rs.html#113
-    let terms: Vec<(Fr, SparseTerm)> = vec![
-		(2.into(), SparseTerm::new(vec![(0, 3)])),
-		(1.into(), SparseTerm::new(vec![(0, 1), (2, 1)])),
-		(1.into(), SparseTerm::new(vec![(1, 1), (2, 1)])),
-	];
+    // Variable indices start at zero; `poly!` rejects any index >= num_vars.
+    let g = crate::poly!(Fr; vars = num_vars; (2, [(0, 3)]), (1, [(0, 1), (2, 1)]), (1, [(1, 1), (2, 1)]))
+        .unwrap();
 
-	let g = MultiPoly { num_vars, terms };
+    // The prover computes the sum it argues for on initialization.
+    assert_eq!(IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap().asserted_sum(), 12.into());
 
-    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone());
+    let (asserted_sum, v_out) = IPForSumcheck::<Fr>::run_interactive_protocol(&g, &mut rng)
+        .expect("Failed to verify...");
+    assert_eq!(asserted_sum, 12.into());
+    assert_eq!(v_out.r_vec.len(), num_vars);
+    assert!(IPForSumcheck::<Fr>::run_interactive_protocol_with_sum(&g, 13.into(), &mut rng).is_err());
+
+    // The same checks on random instances.
+    for _ in 0..20 {
+        let g = random_sparse_polynomial::<Fr, _>(num_vars, 8, 3, &mut rng);
+        let (asserted_sum, v_out) = IPForSumcheck::run_interactive_protocol(&g, &mut rng)
+            .expect("Failed to verify...");
+        assert_eq!(asserted_sum, IPForSumcheck::compute_sum(&g).unwrap());
+        assert_eq!(v_out.r_vec.len(), num_vars);
+        assert!(
+            IPForSumcheck::run_interactive_protocol_with_sum(&g, asserted_sum + Fr::from(1), &mut rng)
+                .is_err()
+        );
+    }
+}
+
+#[test]
+fn test_interactive_protocol() {
+    let mut rng = rand::thread_rng();
+
+    for num_vars in 1..=10 {
+        let g = random_sparse_polynomial::<Fr, _>(num_vars, num_vars + 2, 3, &mut rng);
+        let (asserted_sum, v_out) = IPForSumcheck::run_interactive_protocol(&g, &mut rng).unwrap();
+        assert_eq!(asserted_sum, IPForSumcheck::compute_sum(&g).unwrap());
+        assert_eq!(g.evaluate(&v_out.r_vec), v_out.expected_evaluation);
+
+        assert!(matches!(
+            IPForSumcheck::run_interactive_protocol_with_sum(&g, asserted_sum + Fr::from(1), &mut rng),
+            Err(crate::Error::SumMismatch { round: 1, .. })
+        ));
+    }
+}
+
+#[test]
+fn test_run() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    let proof = IPForSumcheck::<Fr>::run(&g, &mut rng).unwrap();
+    assert_eq!(proof.num_rounds(), 3);
+    assert!(proof.validate(g.num_vars, &max_degrees(&g)).is_ok());
+    IPForSumcheck::verify_with_randomness(&g, &proof.prover_messages, proof.verifier_randomness, Fr::from(12))
+        .expect("Failed to verify...");
+}
+
+#[test]
+fn test_channel_protocol() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(6, 12, 3, &mut rng);
+    let asserted_sum = IPForSumcheck::compute_sum(&g).unwrap();
+
+    let (mut prover_end, mut verifier_end) = MpscChannel::pair();
+    let prover_g = g.clone();
+    let prover = std::thread::spawn(move || IPForSumcheck::run_prover_async(prover_g, &mut prover_end));
+    let verifier = std::thread::spawn(move || {
+        IPForSumcheck::run_verifier_async(&g, asserted_sum, &mut Blake2Transcript::new(), &mut verifier_end)
+    });
+    prover.join().unwrap().expect("Prover failed...");
+    verifier.join().unwrap().expect("Failed to verify...");
+
+    // A prover that hangs up after two rounds.
+    let g = random_sparse_polynomial::<Fr, _>(6, 12, 3, &mut rng);
+    let (mut prover_end, mut verifier_end) = MpscChannel::pair();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let prover = std::thread::spawn(move || {
+        prover_end.send(IPForSumcheck::prove_round(&mut prover_state, &None))?;
+        let verifier_msg = Some(prover_end.recv()?);
+        prover_end.send(IPForSumcheck::prove_round(&mut prover_state, &verifier_msg))
+    });
+    let result = IPForSumcheck::run_verifier_async(&g, 0.into(), &mut Blake2Transcript::new(), &mut verifier_end);
+    prover.join().unwrap().unwrap();
+    // Depending on timing, answering the second message or waiting for the third one fails.
+    assert!(matches!(
+        result,
+        Err(crate::Error::IOError(e))
+            if [std::io::ErrorKind::BrokenPipe, std::io::ErrorKind::UnexpectedEof].contains(&e.kind())
+    ));
+
+    // A verifier that never answers.
+    let (prover_end, verifier_end) = MpscChannel::pair();
+    let mut prover_end = prover_end.with_timeout(std::time::Duration::from_millis(10));
+    let result = IPForSumcheck::run_prover_async(g.clone(), &mut prover_end);
+    assert!(matches!(result, Err(crate::Error::IOError(e)) if e.kind() == std::io::ErrorKind::TimedOut));
+    drop(verifier_end);
+    assert!(matches!(
+        IPForSumcheck::run_prover_async(g, &mut prover_end),
+        Err(crate::Error::IOError(e)) if e.kind() == std::io::ErrorKind::BrokenPipe
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_stream_protocol() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(6, 12, 3, &mut rng);
+    let asserted_sum = IPForSumcheck::compute_sum(&g).unwrap();
+
+    let (prover_end, verifier_end) = std::os::unix::net::UnixStream::pair().unwrap();
+    let prover_g = g.clone();
+    let prover = std::thread::spawn(move || IPForSumcheck::run_prover_over_stream(prover_g, prover_end));
+    IPForSumcheck::run_verifier_over_stream(&g, asserted_sum, &mut Blake2Transcript::new(), verifier_end)
+        .expect("Failed to verify...");
+    prover.join().unwrap().expect("Prover failed...");
+}
+
+#[test]
+fn test_corrupted_frame() {
+    let msg = ProverMsg::new(UniPoly::from_coefficients_vec(vec![(0, Fr::from(3)), (2, Fr::from(5))]));
+    let mut frame = Vec::new();
+    msg.write_framed(&mut frame).unwrap();
+    assert_eq!(ProverMsg::read_framed(&frame[..]).unwrap(), msg);
+
+    // Frames of the other party are refused.
+    assert!(matches!(VerifierMsg::<Fr>::read_framed(&frame[..]), Err(crate::Error::DeserializeError(_))));
+
+    // So is a payload that is not a message.
+    let mut corrupted = frame.clone();
+    corrupted[9] = MESSAGE_VERSION + 1;
+    assert!(matches!(ProverMsg::<Fr>::read_framed(&corrupted[..]), Err(crate::Error::DeserializeError(_))));
+
+    // And a length that disagrees with the payload, either way.
+    let mut corrupted = frame.clone();
+    corrupted[1] -= 1;
+    assert!(ProverMsg::<Fr>::read_framed(&corrupted[..]).is_err());
+    let mut corrupted = frame.clone();
+    corrupted[1] += 1;
+    corrupted.push(0);
+    assert!(matches!(ProverMsg::<Fr>::read_framed(&corrupted[..]), Err(crate::Error::DeserializeError(_))));
+    corrupted[8] = 0xff;
+    assert!(matches!(ProverMsg::<Fr>::read_framed(&corrupted[..]), Err(crate::Error::DeserializeError(_))));
+
+    // Running out of input mid-frame is an unexpected EOF.
+    for len in [0, 5, frame.len() - 1] {
+        assert!(matches!(
+            ProverMsg::<Fr>::read_framed(&frame[..len]),
+            Err(crate::Error::IOError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+        ));
+    }
+}
+
+// Drives every round of the protocol between `prover_state` and a fresh verifier.
+fn run_rounds<R: RngCore>(prover_state: &mut ProverState<Fr>, rng: &mut R) -> VerifierState<Fr> {
+    let num_vars = prover_state.g.num_vars;
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+
+    for _ in 0..num_vars {
+        let prover_message = IPForSumcheck::<Fr>::prove_round(prover_state, &verifier_msg);
+        verifier_msg = IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut RngTranscript::new(&mut *rng)).ok();
+    }
+
+    verifier_state
+}
+
+// Runs the zero-knowledge variant of the protocol, returning the verifier's decision
+// along with the first round message sent by the prover.
+fn run_zk_protocol(g: &MultiPoly<Fr>, asserted_sum: Fr) -> (Result<(), crate::Error>, UniPoly<Fr>) {
+    let mut rng = rand::thread_rng();
+
+    let (zk_state, mask_msg) = IPForSumcheck::<Fr>::prover_init_zk(g.clone(), &mut rng);
+    let mask_challenge = IPForSumcheck::<Fr>::sample_r(&mut rng);
+    let mask = zk_state.mask.clone();
+
+    let mut prover_state = IPForSumcheck::<Fr>::prover_apply_mask(zk_state, &mask_challenge).unwrap();
+    let verifier_state = run_rounds(&mut prover_state, &mut rng);
+    let first_msg = verifier_state.partial_sums.first().cloned().unwrap_or_else(UniPoly::zero);
+
+    let result = IPForSumcheck::<Fr>::verify_zk(
+        g,
+        |point| mask.evaluate(&point.to_vec()),
+        verifier_state,
+        asserted_sum,
+        &mask_msg,
+        &mask_challenge,
+    );
+
+    (result, first_msg)
+}
+
+#[test]
+fn test_zk_protocol() {
+    let g = thaler_example();
+
+    let (result, _) = run_zk_protocol(&g, 12.into());
+    result.expect("Failed to verify...");
+
+    let (result, _) = run_zk_protocol(&g, 13.into());
+    assert!(result.is_err());
+
+    // A constant has no rounds, only the final check.
+    let constant = crate::poly!(Fr; vars = 0; (7, [])).unwrap();
+    let (result, _) = run_zk_protocol(&constant, 7.into());
+    result.expect("Failed to verify...");
+    let (result, _) = run_zk_protocol(&constant, 8.into());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_zk_round_messages_are_masked() {
+    // Two different witnesses with the same hypercube sum (12).
+    let g1 = thaler_example();
+    let g2 = crate::poly!(Fr; vars = 3; (3, [(0, 1)])).unwrap();
+    assert_eq!(
+        IPForSumcheck::<Fr>::compute_sum(&g1).unwrap(),
+        IPForSumcheck::<Fr>::compute_sum(&g2).unwrap(),
+    );
+
+    for g in [g1, g2] {
+        let mut samples: Vec<Fr> = (0..32)
+            .map(|_| {
+                let (result, first_msg) = run_zk_protocol(&g, 12.into());
+                result.expect("Failed to verify...");
+                first_msg.evaluate(&Fr::zero())
+            })
+            .collect();
+        samples.sort();
+        samples.dedup();
+
+        // Without masking, gi(0) would be identical across every run.
+        assert!(samples.len() > 1);
+    }
+}
+
+#[test]
+fn test_error_source_chain() {
+    use ark_std::error::Error as _;
+
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    let verifier_state = run_rounds(&mut IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap(), &mut rng);
+
+    // A legacy rejection keeps the error that caused it as its source.
+    let cause = IPForSumcheck::<Fr>::verify(&g, verifier_state, 13.into()).unwrap_err();
+    let err = crate::Error::Reject(Some("Partial verification failed.".into()), Some(Box::new(cause)));
+    let source = err.source().expect("Rejection should carry its cause...");
+    assert!(matches!(
+        source.downcast_ref::<crate::Error>(),
+        Some(crate::Error::SumMismatch { round: 1, .. }),
+    ));
+
+    let io_err: crate::Error = ark_std::io::Error::from(ark_std::io::ErrorKind::UnexpectedEof).into();
+    let source = io_err.source().expect("IO errors should be preserved...");
+    assert_eq!(
+        source.downcast_ref::<ark_std::io::Error>().unwrap().kind(),
+        ark_std::io::ErrorKind::UnexpectedEof,
+    );
+}
+
+// Runs the ZeroCheck protocol for `f` against a freshly sampled `τ`.
+fn run_zerocheck(f: &MultiPoly<Fr>) -> Result<(), crate::Error> {
+    let mut rng = rand::thread_rng();
+    let tau = ZeroCheck::<Fr>::sample_tau(f.num_vars, &mut rng);
+
+    let mut prover_state = ZeroCheck::<Fr>::prove(f, &tau).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(f.num_vars);
+    let mut verifier_msg = None;
+
+    for _ in 0..f.num_vars {
+        let prover_message = ZeroCheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+
+    ZeroCheck::<Fr>::verify(f, &tau, verifier_state)
+}
+
+#[test]
+fn test_zerocheck() {
+    // f = x0^2 - x0 + x1·x2^3 - x1·x2 vanishes on the hypercube, but f(2, 0, 0) = 2.
+    let f = crate::poly!(Fr; vars = 3;
+        (1, [(0, 2)]),
+        (-1, [(0, 1)]),
+        (1, [(1, 1), (2, 3)]),
+        (-1, [(1, 1), (2, 1)]),
+    )
+    .unwrap();
+    assert_eq!(f.evaluate(&vec![2.into(), 0.into(), 0.into()]), Fr::from(2));
+
+    run_zerocheck(&f).expect("Failed to verify...");
+
+    // Adding x0·x1·x2 flips a single hypercube value, at (1, 1, 1).
+    let flipped = &f + &crate::poly!(Fr; vars = 3; (1, [(0, 1), (1, 1), (2, 1)])).unwrap();
+
+    for _ in 0..16 {
+        assert!(run_zerocheck(&flipped).is_err());
+    }
+
+    // `τ` needs one coordinate per variable of `f`.
+    let tau = ZeroCheck::<Fr>::sample_tau(2, &mut rand::thread_rng());
+    assert_eq!(
+        ZeroCheck::<Fr>::prove(&f, &tau).err(),
+        Some(crate::Error::RoundMismatch { expected: 3, got: 2 })
+    );
+}
+
+#[test]
+fn test_zerocheck_split() {
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    let f = crate::poly!(Fr; vars = 3;
+        (1, [(0, 2)]),
+        (-1, [(0, 1)]),
+        (1, [(1, 1), (2, 3)]),
+        (-1, [(1, 1), (2, 1)]),
+    )
+    .unwrap();
+    let flipped = &f + &crate::poly!(Fr; vars = 3; (1, [(0, 1), (1, 1), (2, 1)])).unwrap();
+    let degrees = max_degrees(&f);
+
+    for (g, seed) in [(&f, 1), (&flipped, 2), (&flipped, 3)] {
+        let tau = ZeroCheck::<Fr>::sample_tau(3, &mut ChaCha20Rng::from_seed([seed; 32]));
+        let mut prover_state = ZeroCheck::<Fr>::prove(g, &tau).unwrap();
+        let mut split_prover_state = ZeroCheck::<Fr>::prove(g, &tau).unwrap();
+        // The unoptimized eq-weighted prover, with eq(·, τ) expanded.
+        let eq = crate::poly_utils::build_eq_x_r(&tau);
+        let mut weighted_prover_state = IPForSumcheck::prover_init_weighted(g.clone(), eq.clone()).unwrap();
+        let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(3);
+        let mut split_verifier_state = ZeroCheck::<Fr>::verifier_init_split(g, &tau).unwrap();
+        let mut transcript = RngTranscript::new(ChaCha20Rng::from_seed([seed + 10; 32]));
+        let mut split_transcript = RngTranscript::new(ChaCha20Rng::from_seed([seed + 10; 32]));
+        let (mut verifier_msg, mut split_verifier_msg) = (None, None);
+
+        for degree in &degrees {
+            let prover_msg = ZeroCheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+            let split_msg = ZeroCheck::<Fr>::prove_round_split(&mut split_prover_state, &split_verifier_msg);
+            assert!(split_msg.degree() <= *degree);
+            assert!(prover_msg.degree() <= degree + 1);
+            assert_eq!(IPForSumcheck::prove_weighted_round(&mut weighted_prover_state, &verifier_msg), prover_msg);
+
+            verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).ok();
+            split_verifier_msg =
+                ZeroCheck::<Fr>::verify_round_split(split_msg, &mut split_verifier_state, &mut split_transcript).ok();
+            assert_eq!(verifier_msg, split_verifier_msg);
+        }
+
+        // The verifier reconstructs exactly the unsplit round polynomials.
+        assert_eq!(verifier_state.round_polynomials(), split_verifier_state.inner.round_polynomials());
+        let result = ZeroCheck::<Fr>::verify(g, &tau, verifier_state.clone());
+        assert_eq!(ZeroCheck::<Fr>::verify_split(g, split_verifier_state), result);
+        assert_eq!(IPForSumcheck::verify_weighted(g, &eq, verifier_state, Fr::zero()).is_ok(), result.is_ok());
+        assert_eq!(result.is_ok(), seed == 1);
+    }
+
+    // A split message of degree deg_j(f) + 1 is rejected right away.
+    let tau = ZeroCheck::<Fr>::sample_tau(3, &mut rand::thread_rng());
+    let mut prover_state = ZeroCheck::<Fr>::prove(&f, &tau).unwrap();
+    let mut verifier_state = ZeroCheck::<Fr>::verifier_init_split(&f, &tau).unwrap();
+    let prover_msg = ZeroCheck::<Fr>::prove_round(&mut prover_state, &None);
+    assert_eq!(
+        ZeroCheck::<Fr>::verify_round_split(prover_msg, &mut verifier_state, &mut RngTranscript::new(rand::thread_rng())),
+        Err(crate::Error::DegreeTooHigh { round: 1, got: 3, bound: 2 })
+    );
+
+    // `τ` needs one coordinate per variable of `f`.
+    assert_eq!(
+        ZeroCheck::<Fr>::verifier_init_split(&f, &tau[..2]).err(),
+        Some(crate::Error::RoundMismatch { expected: 3, got: 2 })
+    );
+}
+
+#[test]
+fn test_zerocheck_split_eq_tables() {
+    let mut rng = rand::thread_rng();
+
+    for num_vars in [1, 2, 4, 5] {
+        let f = random_sparse_polynomial::<Fr, _>(num_vars, 12, 2, &mut rng);
+        let tau = ZeroCheck::<Fr>::sample_tau(num_vars, &mut rng);
+        let mut prover_state = ZeroCheck::<Fr>::prove(&f, &tau).unwrap();
+        let mut weighted_prover_state =
+            IPForSumcheck::prover_init_weighted(f.clone(), crate::poly_utils::build_eq_x_r(&tau)).unwrap();
+        let mut verifier_msg = None;
+
+        for _ in 0..num_vars {
+            // Neither half-table ever grows past 2^(v/2) entries.
+            assert!(prover_state.eq_high.len().max(prover_state.eq_low.len()) <= 1 << (num_vars / 2));
+
+            // The split message, with eq_prefix · eq(X, τ_j) multiplied back in, is the message
+            // of the unoptimized prover for f·eq(·, τ).
+            let j = prover_state.inner.randomness.len() + usize::from(verifier_msg.is_some());
+            let split_msg = ZeroCheck::<Fr>::prove_round_split(&mut prover_state, &verifier_msg);
+            let eq_factor = UniPoly::from_coefficients_vec(vec![(0, Fr::from(1) - tau[j]), (1, tau[j] + tau[j] - Fr::from(1))]);
+            assert_eq!(
+                &split_msg.gi.mul(&eq_factor) * prover_state.eq_prefix,
+                IPForSumcheck::prove_weighted_round(&mut weighted_prover_state, &verifier_msg).gi
+            );
+            verifier_msg = Some(VerifierMsg::new(Fr::rand(&mut rng)));
+        }
+    }
+}
+
+#[test]
+fn test_verify_consistency_only() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    for (asserted_sum, accept) in [(12, true), (13, false)] {
+        let verifier_state =
+            run_rounds(&mut IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap(), &mut rng);
+
+        let result =
+            IPForSumcheck::<Fr>::verify_consistency_only(verifier_state, asserted_sum.into());
+        assert_eq!(result.is_ok(), accept);
+
+        // The oracle check is left to the caller.
+        if let Ok(v_out) = result {
+            assert_eq!(g.evaluate(&v_out.r_vec), v_out.expected_evaluation);
+        }
+    }
+}
+
+#[test]
+fn test_verify_oracle_call() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let verifier_state = run_rounds(&mut IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap(), &mut rng);
+
+    let v_out = IPForSumcheck::<Fr>::partial_verify(&verifier_state, 12.into()).unwrap();
+    v_out.verify_oracle_call(|r_vec| g.evaluate(&r_vec.to_vec())).expect("Failed to verify...");
+
+    // An oracle for another polynomial is caught.
+    let v_out = IPForSumcheck::<Fr>::partial_verify(&verifier_state, 12.into()).unwrap();
+    assert!(matches!(
+        v_out.verify_oracle_call(|r_vec| g.evaluate(&r_vec.to_vec()) + Fr::from(1)),
+        Err(crate::Error::FinalEvaluationMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_resumable_verification() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
     let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
     let mut verifier_msg = None;
 
-    let asserted_sum = prover_state.slow_sum_g();   // 12.into()
+    // Every prefix of an honest transcript is consistent, and only a prefix that was received
+    // can be checked.
+    assert_eq!(verifier_state.check_up_to_round(12.into(), 0).unwrap(), 12.into());
+    for k in 1..=g.num_vars {
+        let prover_msg = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg = IPForSumcheck::<Fr>::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+
+        let claim = verifier_state.check_up_to_round(12.into(), k).unwrap();
+        assert_eq!(claim, verifier_state.partial_sums[k - 1].evaluate(&verifier_state.randomness[k - 1]));
+        assert!(matches!(
+            verifier_state.check_up_to_round(12.into(), k + 1),
+            Err(crate::Error::RoundMismatch { expected, got }) if expected == k + 1 && got == k
+        ));
+        assert!(matches!(
+            verifier_state.check_up_to_round(13.into(), k),
+            Err(crate::Error::SumMismatch { round: 1, .. })
+        ));
+    }
+
+    // The finished state can be checked against several candidate sums.
+    assert!(IPForSumcheck::<Fr>::partial_verify(&verifier_state, 13.into()).is_err());
+    assert!(IPForSumcheck::<Fr>::verify(&g, &verifier_state, 13.into()).is_err());
+    assert!(IPForSumcheck::<Fr>::verify(&g, &verifier_state, 12.into()).is_ok());
+    let v_out = IPForSumcheck::<Fr>::partial_verify(&verifier_state, 12.into()).unwrap();
+
+    let (randomness, partial_sums) = verifier_state.into_parts();
+    assert_eq!(randomness, v_out.r_vec);
+    assert_eq!(partial_sums.len(), g.num_vars);
+}
+
+// Records the messages of an honest execution of the protocol for `g`.
+fn record_proof<R: RngCore>(g: &MultiPoly<Fr>, rng: &mut R) -> SumcheckProof<Fr> {
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_msg = None;
+    let mut prover_messages = Vec::with_capacity(g.num_vars);
+    let mut verifier_randomness = Vec::with_capacity(g.num_vars);
 
     for _ in 0..g.num_vars {
-        let prover_message = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+        prover_messages.push(IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg));
+
+        let v_msg = IPForSumcheck::<Fr>::sample_r(rng);
+        verifier_randomness.push(v_msg.randomness);
+        verifier_msg = Some(v_msg);
+    }
+
+    SumcheckProof { prover_messages, verifier_randomness }
+}
+
+#[test]
+fn test_proof_transcript() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let proof = record_proof(&g, &mut rng);
+
+    let transcript = ProofTranscript::new(proof.clone());
+    assert_eq!(transcript.len(), g.num_vars);
+
+    // Replay the transcript through a hand-rolled verifier.
+    let mut expected_sum: Fr = 12.into();
+    for (i, (p_msg, v_msg)) in proof.clone().into_iter().enumerate() {
+        assert_eq!(p_msg.gi, proof.prover_messages[i].gi);
+        assert_eq!(v_msg.randomness, proof.verifier_randomness[i]);
+
+        assert_eq!(p_msg.gi.evaluate(&Fr::zero()) + p_msg.gi.evaluate(&1.into()), expected_sum);
+        expected_sum = p_msg.gi.evaluate(&v_msg.randomness);
+    }
+    assert_eq!(g.evaluate(&proof.verifier_randomness), expected_sum);
+}
+
+// Checks the virtual prover against the naive prover on the expanded polynomial.
+fn check_virtual_against_expansion(virtual_poly: VirtualPolynomial<Fr>, expanded: MultiPoly<Fr>) {
+    let mut rng = rand::thread_rng();
+    let num_vars = expanded.num_vars;
+
+    let asserted_sum = virtual_poly.sum_over_hypercube();
+    assert_eq!(asserted_sum, IPForSumcheck::<Fr>::compute_sum(&expanded).unwrap());
+
+    let mut naive_state = IPForSumcheck::<Fr>::prover_init(expanded).unwrap();
+    let mut virtual_state = IPForSumcheck::<Fr>::prover_init_virtual(virtual_poly.clone());
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+
+    for _ in 0..num_vars {
+        let naive_msg = IPForSumcheck::<Fr>::prove_round(&mut naive_state, &verifier_msg);
+        let virtual_msg = IPForSumcheck::<Fr>::prove_round_virtual(&mut virtual_state, &verifier_msg);
 
-        let verif_msg =
-            IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut rng);
-        verifier_msg = verif_msg;
+        let x = Fr::rand(&mut rng);
+        assert_eq!(naive_msg.gi.evaluate(&x), virtual_msg.gi.evaluate(&x));
+        assert!(virtual_msg.gi.degree() <= virtual_poly.max_degree_per_round());
+
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(virtual_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+
+    IPForSumcheck::<Fr>::verify_virtual(&virtual_poly, verifier_state, asserted_sum)
+        .expect("Failed to verify...");
+}
+
+#[test]
+fn test_virtual_polynomial_two_factors() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 4;
+    let (a, b, d) = (
+        random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng),
+        random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng),
+        random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng),
+    );
+
+    // A(x)·B(x) + 3·D(x)
+    let mut virtual_poly = VirtualPolynomial::new(num_vars);
+    let [f_a, f_b, f_d] = [&a, &b, &d].map(|g| DenseMLE::from_evaluations(num_vars, hypercube_evaluations(g)).unwrap());
+    virtual_poly.add_product(1.into(), vec![f_a, f_b]).unwrap();
+    virtual_poly.add_product(3.into(), vec![f_d]).unwrap();
+    assert_eq!(virtual_poly.max_degree_per_round(), 2);
+
+    let three_d = MultiPoly::from_coefficients_vec(
+        num_vars,
+        d.terms.iter().map(|(c, t)| (*c * Fr::from(3), t.clone())).collect(),
+    );
+    check_virtual_against_expansion(virtual_poly, &multiply(&a, &b) + &three_d);
+}
+
+#[test]
+fn test_virtual_polynomial_three_factors() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 3;
+    let (a, b, c) = (
+        random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng),
+        random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng),
+        random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng),
+    );
+
+    let mut virtual_poly = VirtualPolynomial::new(num_vars);
+    let factors = [&a, &b, &c].map(|g| DenseMLE::from_evaluations(num_vars, hypercube_evaluations(g)).unwrap());
+    virtual_poly.add_product(1.into(), factors.to_vec()).unwrap();
+    assert_eq!(virtual_poly.max_degree_per_round(), 3);
+
+    // Factors must share the polynomial's variables.
+    let mismatched = DenseMLE::from_evaluations(1, vec![Fr::zero(); 2]).unwrap();
+    assert!(virtual_poly.add_product(1.into(), vec![mismatched]).is_err());
+
+
+    check_virtual_against_expansion(virtual_poly.clone(), multiply(&multiply(&a, &b), &c));
+
+    // A wrong claim is rejected.
+    let mut rng = rand::thread_rng();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init_virtual(virtual_poly.clone());
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+    for _ in 0..num_vars {
+        let prover_message = IPForSumcheck::<Fr>::prove_round_virtual(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    let wrong_sum = virtual_poly.sum_over_hypercube() + Fr::from(1);
+    assert!(IPForSumcheck::<Fr>::verify_virtual(&virtual_poly, verifier_state, wrong_sum).is_err());
+}
+
+#[test]
+fn test_weighted_sumcheck() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 4;
+    let g = random_sparse_polynomial::<Fr, _>(num_vars, 8, 3, &mut rng);
+    let w = random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng);
+    let expanded = multiply(&w, &g);
+
+    let mut weighted_state = IPForSumcheck::prover_init_weighted(g.clone(), w.clone()).unwrap();
+    let asserted_sum = weighted_state.asserted_sum();
+    assert_eq!(asserted_sum, IPForSumcheck::<Fr>::compute_sum(&expanded).unwrap());
+
+    // Same messages as the naive prover on the expanded product.
+    let mut naive_state = IPForSumcheck::<Fr>::prover_init(expanded).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+    for _ in 0..num_vars {
+        let weighted_msg = IPForSumcheck::prove_weighted_round(&mut weighted_state, &verifier_msg);
+        assert_eq!(weighted_msg, IPForSumcheck::prove_round(&mut naive_state, &verifier_msg));
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(weighted_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+
+    IPForSumcheck::verify_weighted(&g, &w, verifier_state.clone(), asserted_sum).expect("Failed to verify...");
+    assert!(IPForSumcheck::verify_weighted(&g, &w, verifier_state.clone(), asserted_sum + Fr::from(1)).is_err());
+    let other_weight = random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng);
+    assert!(matches!(
+        IPForSumcheck::verify_weighted(&g, &other_weight, verifier_state, asserted_sum),
+        Err(crate::Error::FinalEvaluationMismatch { .. })
+    ));
+
+    // Mismatched variables are refused by both parties.
+    let short = random_multilinear_polynomial::<Fr, _>(num_vars - 1, 1 << (num_vars - 1), &mut rng);
+    assert!(matches!(IPForSumcheck::prover_init_weighted(g.clone(), short.clone()), Err(crate::Error::PolynomialError(_))));
+    let verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    assert!(matches!(IPForSumcheck::verify_weighted(&g, &short, verifier_state, asserted_sum), Err(crate::Error::PolynomialError(_))));
+}
+
+#[test]
+fn test_product_prover() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 4;
+    let [a, b] = [(); 2].map(|_| random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng));
+    let [f, g] = [&a, &b].map(|p| DenseMLE::from_evaluations(num_vars, hypercube_evaluations(p)).unwrap());
+    let expanded = multiply(&a, &b);
+
+    let asserted_sum = IPForSumcheck::<Fr>::compute_sum(&expanded).unwrap();
+    let mut naive_state = IPForSumcheck::<Fr>::prover_init(expanded).unwrap();
+    let mut f_state = IPForSumcheck::<Fr>::prover_init_product(f.clone());
+    let mut g_state = IPForSumcheck::<Fr>::prover_init_product(g.clone());
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+
+    for _ in 0..num_vars {
+        let naive_msg = IPForSumcheck::<Fr>::prove_round(&mut naive_state, &verifier_msg);
+        let product_msg = IPForSumcheck::<Fr>::prove_product_round(&mut f_state, &mut g_state, &verifier_msg);
+
+        let x = Fr::rand(&mut rng);
+        assert_eq!(naive_msg.gi.evaluate(&x), product_msg.gi.evaluate(&x));
+
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(product_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    assert_eq!(f_state.randomness, g_state.randomness);
+
+    IPForSumcheck::<Fr>::verify_product(&f, &g, verifier_state, asserted_sum)
+        .expect("Failed to verify...");
+}
+
+#[test]
+fn test_streaming_prover() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 10;
+    let g = random_sparse_polynomial::<Fr, _>(num_vars, 20, 3, &mut rng);
+    let degree_bound = max_degrees(&g).into_iter().max().unwrap();
+
+    let asserted_sum = IPForSumcheck::<Fr>::compute_sum(&g).unwrap();
+    let mut naive_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut streaming = StreamingProver::new(num_vars, degree_bound, |x: &[Fr]| g.evaluate(&x.to_vec()));
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+
+    for _ in 0..num_vars {
+        let naive_msg = IPForSumcheck::<Fr>::prove_round(&mut naive_state, &verifier_msg);
+        let streaming_msg = streaming.prove_round(&verifier_msg);
+        assert_eq!(naive_msg, streaming_msg);
+
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(streaming_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    assert_eq!(streaming.round(), num_vars);
+
+    IPForSumcheck::<Fr>::verify(&g, verifier_state, asserted_sum).expect("Failed to verify...");
+}
+
+#[test]
+fn test_linear_time_prover() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 8;
+    let g = random_multilinear_polynomial::<Fr, _>(num_vars, 30, &mut rng);
+    let mle = DenseMLE::from_evaluations(num_vars, hypercube_evaluations(&g)).unwrap();
+
+    let asserted_sum = IPForSumcheck::<Fr>::compute_sum(&g).unwrap();
+    let mut naive_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut linear = LinearTimeMleProver::new(mle);
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+
+    for _ in 0..num_vars {
+        let naive_msg = IPForSumcheck::<Fr>::prove_round(&mut naive_state, &verifier_msg);
+        let linear_msg = linear.prove_round(&verifier_msg);
+        assert_eq!(naive_msg, linear_msg);
+
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(linear_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    assert_eq!((linear.num_vars(), linear.round()), (num_vars, num_vars));
+
+    IPForSumcheck::<Fr>::verify(&g, verifier_state, asserted_sum).expect("Failed to verify...");
+}
+
+#[test]
+fn test_sqrt_space_prover() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 6;
+    let g = random_multilinear_polynomial::<Fr, _>(num_vars, 12, &mut rng);
+    let oracle = |b: usize| g.evaluate(&to_binary_vec(b, num_vars));
+
+    // From the dense table (split 0) down to the smallest budget, 2^3 + 2^3.
+    for (max_table_entries, split) in [(1 << 7, 0), (40, 1), (20, 2), (16, 3)] {
+        let config = ProverConfig { max_table_entries };
+        let mut prover = SqrtSpaceProver::new(num_vars, oracle, config).unwrap();
+        assert_eq!(prover.split(), split);
+
+        let mut naive_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+        let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+        let mut verifier_msg = None;
+        for _ in 0..num_vars {
+            let naive_msg = IPForSumcheck::<Fr>::prove_round(&mut naive_state, &verifier_msg);
+            let msg = prover.prove_round(&verifier_msg);
+            assert_eq!(naive_msg, msg);
+
+            verifier_msg = IPForSumcheck::<Fr>::verify_round(msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        }
+        IPForSumcheck::<Fr>::verify(&g, verifier_state, IPForSumcheck::<Fr>::compute_sum(&g).unwrap())
+            .expect("Failed to verify...");
     }
-    let result = IPForSumcheck::<Fr>::verify(&g, verifier_state, asserted_sum)
+    assert!(SqrtSpaceProver::new(num_vars, oracle, ProverConfig { max_table_entries: 15 }).is_err());
+}
+
+#[test]
+fn test_provers_without_variables() {
+    let seven = DenseMLE::from_evaluations(0, vec![Fr::from(7)]).unwrap();
+    let two = DenseMLE::from_evaluations(0, vec![Fr::from(2)]).unwrap();
+    let verifier_state = || IPForSumcheck::<Fr>::verifier_init(0);
+
+    // No rounds: every prover is done right away, and the claim is checked at the empty point.
+    let mut virtual_poly = VirtualPolynomial::new(0);
+    virtual_poly.add_product(Fr::from(3), vec![seven.clone(), two.clone()]).unwrap();
+    let virtual_state = IPForSumcheck::<Fr>::prover_init_virtual(virtual_poly.clone());
+    assert_eq!((virtual_state.round, virtual_poly.sum_over_hypercube()), (0, Fr::from(42)));
+    assert!(IPForSumcheck::<Fr>::verify_virtual(&virtual_poly, verifier_state(), Fr::from(42)).is_ok());
+    assert!(IPForSumcheck::<Fr>::verify_virtual(&virtual_poly, verifier_state(), Fr::from(41)).is_err());
+
+    let f_state = IPForSumcheck::<Fr>::prover_init_product(seven.clone());
+    assert_eq!(f_state.round, 0);
+    assert!(IPForSumcheck::<Fr>::verify_product(&seven, &two, verifier_state(), Fr::from(14)).is_ok());
+
+    let streaming = StreamingProver::new(0, 0, |_: &[Fr]| Fr::from(7));
+    assert_eq!((streaming.num_vars(), streaming.round()), (0, 0));
+    let linear = LinearTimeMleProver::new(seven);
+    assert_eq!((linear.num_vars(), linear.round()), (0, 0));
+    let sqrt_space = SqrtSpaceProver::new(0, |_| Fr::from(7), ProverConfig { max_table_entries: 2 }).unwrap();
+    assert_eq!((sqrt_space.split(), sqrt_space.round()), (0, 0));
+}
+
+#[test]
+fn test_sqrt_space_prover_under_budget() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 20;
+    let oracle = |b: usize| Fr::from((b as u64 * 7919 + 13) % 65537);
+
+    // The dense table alone would need 2^20 entries.
+    let config = ProverConfig { max_table_entries: 1 << 11 };
+    let mut prover = SqrtSpaceProver::new(num_vars, oracle, config).unwrap();
+    assert_eq!(prover.split(), 10);
+
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+    let mut verifier_msg = None;
+    for _ in 0..num_vars {
+        let msg = prover.prove_round(&verifier_msg);
+        verifier_msg = IPForSumcheck::<Fr>::verify_round(msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+
+    // Only the check tabulates g.
+    let g = DenseMLE::from_evaluations(num_vars, (0..1 << num_vars).map(oracle).collect()).unwrap();
+    IPForSumcheck::<Fr>::partial_verify(&verifier_state, g.sum())
+        .unwrap()
+        .verify_oracle_call(|r_vec| g.evaluate(r_vec).unwrap())
+        .expect("Failed to verify...");
+}
+
+#[test]
+fn test_distributed_prover() {
+    let mut rng = rand::thread_rng();
+
+    // 4 workers share all but two rounds; with 8 workers on 3 variables every round is sharded.
+    for (g, num_workers) in [(random_sparse_polynomial::<Fr, _>(6, 16, 3, &mut rng), 4), (thaler_example(), 8)] {
+        let mut naive_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+        let mut distributed = DistributedProver::new(g.clone(), num_workers).unwrap();
+        let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+        let mut verifier_msg = None;
+
+        for _ in 0..g.num_vars {
+            let naive_msg = IPForSumcheck::<Fr>::prove_round(&mut naive_state, &verifier_msg);
+            let msg = distributed.prove_round(&verifier_msg).unwrap();
+            assert_eq!(naive_msg, msg);
+
+            verifier_msg = IPForSumcheck::<Fr>::verify_round(msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        }
+        IPForSumcheck::<Fr>::verify(&g, verifier_state, IPForSumcheck::<Fr>::compute_sum(&g).unwrap())
+            .expect("Failed to verify...");
+    }
+
+    // A worker only accepts the challenge for its next round.
+    let g = thaler_example();
+    let mut coordinator = Coordinator::<Fr>::new(g.num_vars, 2).unwrap();
+    let mut worker = WorkerState::new(g.clone(), 1, 2).unwrap();
+    let first = coordinator.challenge(&None);
+    let contributions = vec![
+        WorkerState::new(g.clone(), 0, 2).unwrap().round_contribution(&first).unwrap(),
+        worker.round_contribution(&first).unwrap(),
+    ];
+    assert!(coordinator.combine(contributions[..1].to_vec()).is_err());
+    assert!(coordinator.combine(contributions).is_ok());
+    assert!(worker.round_contribution(&first).is_err());
+    let skipped = RoundChallenge { round: 3, randomness: Some(Fr::from(5)) };
+    assert!(worker.round_contribution(&skipped).is_err());
+
+    assert!(DistributedProver::new(g.clone(), 3).is_err());
+    assert!(DistributedProver::new(g, 16).is_err());
+}
+
+#[test]
+fn test_compute_sum() {
+    let g = thaler_example();
+    assert_eq!(IPForSumcheck::<Fr>::compute_sum(&g).unwrap(), Fr::from(12));
+
+    // A constant polynomial sums to itself over the (single-point) hypercube.
+    let constant = crate::poly!(Fr; vars = 0; (7, [])).unwrap();
+    assert_eq!(IPForSumcheck::<Fr>::compute_sum(&constant).unwrap(), Fr::from(7));
+}
+
+#[test]
+fn test_hypercube_evaluations() {
+    let mut rng = rand::thread_rng();
+    let constant = crate::poly!(Fr; vars = 0; (7, [])).unwrap();
+
+    for g in [thaler_example(), constant, random_sparse_polynomial::<Fr, _>(5, 12, 3, &mut rng)] {
+        assert_eq!(hypercube_sum(&g), hypercube_evaluations(&g).into_iter().sum::<Fr>());
+        let evaluations = hypercube_evaluations(&g);
+        assert_eq!(evaluations.len(), 1 << g.num_vars);
+        for (i, e) in evaluations.iter().enumerate() {
+            assert_eq!(*e, g.evaluate(&to_binary_vec(i, g.num_vars)));
+        }
+    }
+}
+
+#[test]
+fn test_hypercube_sum_of_non_canonical_terms() {
+    // `x_0^0` is the constant 1, and `x_0·x_0` is `x_0^2`.
+    let zero_power = raw_polynomial(1, vec![(Fr::from(1), vec![(0, 0)])]);
+    let repeated = raw_polynomial(1, vec![(Fr::from(1), vec![(0, 1), (0, 1)])]);
+
+    for (g, sum) in [(zero_power, Fr::from(2)), (repeated, Fr::from(1))] {
+        assert_eq!(hypercube_sum(&g), sum);
+        assert_eq!(hypercube_evaluations(&g).into_iter().sum::<Fr>(), sum);
+    }
+}
+
+#[test]
+fn test_recursive_sumcheck() {
+    let mut transcript = RngTranscript::new(rand::thread_rng());
+    let g = thaler_example();
+
+    let mut prover = RecursiveProver::new(g.clone()).unwrap();
+    let mut verifier = RecursiveVerifier::new(g.num_vars, 3, Fr::from(12));
+    let mut challenges = Vec::new();
+    let mut challenge = None;
+
+    while let Some(prover_message) = prover.next_message(challenge) {
+        let v_msg = verifier.receive(&prover_message, &mut transcript).unwrap();
+        challenges.push(v_msg.randomness);
+        challenge = Some(v_msg.randomness);
+    }
+    assert!(prover.next_message(challenge).is_none());
+    assert!(verifier.is_finished());
+
+    // Both sides reduced the sum to the same single claim.
+    let (r_vec, value) = prover.reduced_claim().unwrap();
+    assert_eq!(r_vec, challenges);
+    verifier
+        .finalize(|last_r| {
+            assert_eq!(last_r, Some(r_vec[2]));
+            g.evaluate(&challenges)
+        })
         .expect("Failed to verify...");
+    assert_eq!(value, g.evaluate(&r_vec));
+
+    // A wrong claim is rejected in the very first round.
+    let mut prover = RecursiveProver::new(g.clone()).unwrap();
+    let mut verifier = RecursiveVerifier::new(g.num_vars, 3, Fr::from(13));
+    assert!(verifier.receive(&prover.next_message(None).unwrap(), &mut transcript).is_err());
+}
+
+#[test]
+fn test_recursive_sumcheck_constant() {
+    let g = crate::poly!(Fr; vars = 0; (7, [])).unwrap();
+
+    // No rounds: the claim is `g` itself, checked against the asserted sum.
+    let mut prover = RecursiveProver::new(g.clone()).unwrap();
+    assert!(prover.next_message(None).is_none());
+    assert_eq!(prover.reduced_claim(), Some((Vec::new(), Fr::from(7))));
+
+    for (asserted_sum, accepted) in [(Fr::from(7), true), (Fr::from(8), false)] {
+        let verifier = RecursiveVerifier::new(0, 0, asserted_sum);
+        assert!(verifier.is_finished());
+        let result = verifier.finalize(|last_r| {
+            assert_eq!(last_r, None);
+            g.evaluate(&Vec::new())
+        });
+        assert_eq!(result.is_ok(), accepted);
+    }
+}
+
+#[test]
+fn test_message_versioning() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let proof = record_proof(&g, &mut rng);
+
+    let p_msg = proof.prover_messages[0].clone();
+    let v_msg = IPForSumcheck::<Fr>::sample_r(&mut rng);
+    assert_eq!((p_msg.version(), v_msg.version()), (MESSAGE_VERSION, MESSAGE_VERSION));
+    assert_eq!(ProverMsg::from_uni_poly(p_msg.gi.clone()), p_msg);
+    assert_eq!(VerifierMsg::from_field_element(v_msg.randomness), v_msg);
+
+    // Round trip, with the version byte written first.
+    let mut bytes = Vec::new();
+    p_msg.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(bytes[0], MESSAGE_VERSION);
+    let decoded = ProverMsg::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+    assert_eq!(decoded.gi, p_msg.gi);
+
+    let mut v_bytes = Vec::new();
+    v_msg.serialize_compressed(&mut v_bytes).unwrap();
+    let decoded = VerifierMsg::<Fr>::deserialize_compressed(&v_bytes[..]).unwrap();
+    assert_eq!(decoded.randomness, v_msg.randomness);
+
+    let mut proof_bytes = Vec::new();
+    proof.serialize_compressed(&mut proof_bytes).unwrap();
+    let decoded = SumcheckProof::<Fr>::deserialize_compressed(&proof_bytes[..]).unwrap();
+    assert_eq!(decoded.verifier_randomness, proof.verifier_randomness);
+
+    // Other failures keep their own kind.
+    let err: crate::Error =
+        VerifierMsg::<Fr>::deserialize_compressed(&v_bytes[..1]).unwrap_err().into();
+    assert!(matches!(err, crate::Error::IOError(_)));
 
-	assert_eq!(result, ());
-}
\ No newline at end of file
+    // Messages from an unknown version are rejected.
+    for bytes in [&mut bytes, &mut v_bytes] {
+        bytes[0] = MESSAGE_VERSION + 1;
+    }
+    let err: crate::Error = ProverMsg::<Fr>::deserialize_compressed(&bytes[..]).unwrap_err().into();
+    assert!(matches!(err, crate::Error::DeserializeError(_)));
+    let err: crate::Error =
+        VerifierMsg::<Fr>::deserialize_compressed(&v_bytes[..]).unwrap_err().into();
+    assert!(matches!(err, crate::Error::DeserializeError(_)));
+}
+
+#[test]
+fn test_verify_batch_sums() {
+    let mut rng = rand::thread_rng();
+    let polynomials = vec![thaler_example(), random_multilinear_polynomial::<Fr, _>(2, 1 << 2, &mut rng)];
+    let mut sums: Vec<Fr> = polynomials.iter().map(|g| IPForSumcheck::<Fr>::compute_sum(g).unwrap()).collect();
+
+    IPForSumcheck::<Fr>::verify_batch_sums(&polynomials, &sums).expect("Failed to verify...");
+    assert!(IPForSumcheck::<Fr>::verify_batch_sums(&polynomials, &sums[..1]).is_err());
+
+    sums[1] += Fr::from(1);
+    assert_eq!(
+        IPForSumcheck::<Fr>::verify_batch_sums(&polynomials, &sums),
+        Err(crate::Error::ClaimMismatch { index: 1 }),
+    );
+}
+
+#[test]
+fn test_state_accessors() {
+    let g = thaler_example();
+
+    let prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    drop(g);
+    assert_eq!((prover_state.num_vars(), prover_state.num_terms()), (3, 3));
+    assert_eq!(IPForSumcheck::<Fr>::verifier_init(3).num_vars(), 3);
+
+    let domain = IPForSumcheck::<Fr>::verifier_init(3).domain();
+    assert_eq!((domain.zero, domain.one), (Fr::zero(), Fr::from(1)));
+    assert_eq!(domain, DomainPoint::default());
+}
+
+#[test]
+fn test_round_accessors() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+    let mut verifier_msg = None;
+    let mut prover_msgs = Vec::new();
+
+    for k in 1..=2 {
+        let prover_msg = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+        prover_msgs.push(prover_msg.gi.clone());
+        verifier_msg = IPForSumcheck::<Fr>::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        prover_state.assert_consistent();
+        verifier_state.assert_consistent();
+
+        assert_eq!(prover_state.round(), k);
+        assert_eq!(prover_state.fixed_challenges(), &verifier_state.challenges()[..k - 1]);
+        assert_eq!((verifier_state.round(), verifier_state.num_vars()), (k + 1, 3));
+        assert!(!verifier_state.is_finished());
+        assert_eq!(verifier_state.challenges().len(), k);
+        assert_eq!(verifier_state.round_polynomials(), &prover_msgs[..]);
+        assert_eq!((prover_state.rounds_remaining(), verifier_state.rounds_remaining()), (3 - k, 3 - k));
+    }
+    assert_eq!(verifier_state.challenges()[1], verifier_msg.clone().unwrap().randomness);
+
+    // After the last round neither party has anything left to do.
+    let prover_msg = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+    IPForSumcheck::<Fr>::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).unwrap();
+    assert!(verifier_state.is_finished());
+    assert_eq!((prover_state.rounds_remaining(), verifier_state.rounds_remaining()), (0, 0));
+    assert_eq!(IPForSumcheck::<Fr>::verifier_init(0).rounds_remaining(), 0);
+}
+
+#[test]
+fn test_fix_round() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(5, 10, 3, &mut rng);
+    let r: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    let mut honest = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut skipping = IPForSumcheck::prover_init(g).unwrap();
+    IPForSumcheck::prove_round(&mut honest, &None);
+    IPForSumcheck::prove_round(&mut skipping, &None);
+
+    // Skipping the messages of rounds 2 and 3 leaves the prover where the honest one is.
+    for r in &r[..2] {
+        IPForSumcheck::prove_round(&mut honest, &Some(VerifierMsg::new(*r)));
+        skipping.fix_round(*r);
+        skipping.assert_consistent();
+    }
+    assert_eq!((skipping.round(), skipping.fixed_challenges()), (3, &r[..2]));
+    for r in &r[2..] {
+        assert_eq!(
+            IPForSumcheck::prove_round(&mut skipping, &Some(VerifierMsg::new(*r))),
+            IPForSumcheck::prove_round(&mut honest, &Some(VerifierMsg::new(*r)))
+        );
+    }
+
+    // Only challenges for messages already sent can be fixed.
+    let fresh = IPForSumcheck::<Fr>::prover_init(thaler_example()).unwrap();
+    for mut state in [fresh, skipping] {
+        assert!(std::panic::catch_unwind(move || state.fix_round(Fr::from(1))).is_err());
+    }
+}
+
+// Known-answer transcript of the Thaler example under seed `[7; 32]`.
+const SEEDED_CHALLENGES: [&str; 3] = [
+    "31059447353795035699400709583032093400514749761114346984306222067386645718303",
+    "32796166335020211323866496302364975011710661759924488742346716947294213863482",
+    "20067168353374094133532148687203281893541954818162596068157820439447707343074",
+];
+
+#[test]
+fn test_seeded_fixture() {
+    use ark_std::str::FromStr;
+
+    let g = thaler_example();
+    let seed = [7; 32];
+
+    let (asserted_sum, proof, challenges) =
+        IPForSumcheck::<Fr>::run_protocol_seeded(&g, seed).unwrap();
+    assert_eq!(asserted_sum, Fr::from(12));
+    assert_eq!(challenges, proof.verifier_randomness);
+
+    let expected: Vec<Fr> = SEEDED_CHALLENGES.iter().map(|c| Fr::from_str(c).unwrap()).collect();
+    assert_eq!(challenges, expected);
+
+    // Re-running gives the same transcript, which replays against the same seed only.
+    let (_, again, _) = IPForSumcheck::<Fr>::run_protocol_seeded(&g, seed).unwrap();
+    assert_eq!(again.verifier_randomness, challenges);
+    IPForSumcheck::<Fr>::verify_seeded(&g, &proof, asserted_sum, seed).expect("Failed to verify...");
+    assert!(IPForSumcheck::<Fr>::verify_seeded(&g, &proof, asserted_sum, [8; 32]).is_err());
+}
+
+#[tokio::test]
+async fn test_async_verifier() {
+    use tokio::sync::mpsc;
+
+    let g = thaler_example();
+    let verifier = AsyncSumcheckVerifier::new(g.num_vars, RngTranscript::new(rand::rngs::OsRng));
+
+    // The prover runs in its own task, exchanging messages over channels.
+    let (prover_tx, mut prover_rx) = mpsc::channel::<ProverMsg<Fr>>(1);
+    let (verifier_tx, mut verifier_rx) = mpsc::channel::<VerifierMsg<Fr>>(1);
+    let prover = tokio::spawn({
+        let g = g.clone();
+        async move {
+            let mut prover_state = IPForSumcheck::<Fr>::prover_init(g).unwrap();
+            let mut verifier_msg = None;
+            for _ in 0..prover_state.num_vars() {
+                let msg = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+                prover_tx.send(msg).await.unwrap();
+                verifier_msg = verifier_rx.recv().await;
+            }
+        }
+    });
+
+    let handle = verifier.clone();
+    while let Some(msg) = prover_rx.recv().await {
+        let v_msg = handle.receive_message(msg).await.unwrap();
+        if verifier_tx.send(v_msg).await.is_err() {
+            break;
+        }
+    }
+    prover.await.unwrap();
+
+    assert!(verifier.is_finished().unwrap());
+    let extra = ProverMsg::new(UniPoly::from_coefficients_vec(vec![]));
+    assert!(verifier.receive_message(extra).await.is_err());
+    verifier.verify(&g, 12.into()).expect("Failed to verify...");
+}
+
+#[test]
+fn test_structured_errors() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let honest_state = || {
+        run_rounds(&mut IPForSumcheck::<Fr>::prover_init(thaler_example()).unwrap(), &mut rand::thread_rng())
+    };
+
+    // A wrong claim is caught in the first round.
+    assert_eq!(
+        IPForSumcheck::<Fr>::verify(&g, honest_state(), 13.into()),
+        Err(crate::Error::SumMismatch { round: 1, expected: "13".into(), got: "12".into() }),
+    );
+
+    // Round 2 carries x1 + x1^2 instead of its honest counterpart of degree 1.
+    let mut verifier_state = honest_state();
+    let honest = verifier_state.partial_sums[1].clone();
+    let square = UniPoly::from_coefficients_vec(vec![(1, Fr::from(1)), (2, Fr::from(1))]);
+    verifier_state.partial_sums[1] = &honest + &square;
+    assert_eq!(
+        IPForSumcheck::<Fr>::verify(&g, verifier_state, 12.into()),
+        Err(crate::Error::DegreeTooHigh { round: 2, got: 2, bound: 1 }),
+    );
+
+    // A consistent transcript that does not match g at the random point.
+    let other = crate::poly!(Fr; vars = 3; (3, [(0, 1)])).unwrap();
+    let verifier_state = run_rounds(&mut IPForSumcheck::<Fr>::prover_init(other).unwrap(), &mut rng);
+    assert!(matches!(
+        IPForSumcheck::<Fr>::verify(&g, verifier_state, 12.into()),
+        Err(crate::Error::FinalEvaluationMismatch { .. }),
+    ));
+
+    // Too few rounds, and one round too many.
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+    let first = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &None);
+    let mut transcript = RngTranscript::new(&mut rng);
+    IPForSumcheck::<Fr>::verify_round(first.clone(), &mut verifier_state, &mut transcript).unwrap();
+    assert_eq!(
+        IPForSumcheck::<Fr>::partial_verify(verifier_state, 12.into()).err(),
+        Some(crate::Error::RoundMismatch { expected: 3, got: 1 }),
+    );
+
+    let mut verifier_state = honest_state();
+    let err = IPForSumcheck::<Fr>::verify_round(first, &mut verifier_state, &mut transcript);
+    assert_eq!(err.err(), Some(crate::Error::RoundMismatch { expected: 3, got: 4 }));
+
+    // Clones compare equal to the original.
+    let err = crate::Error::DegreeTooHigh { round: 2, got: 2, bound: 1 };
+    assert_eq!(err.clone(), err);
+    assert_ne!(err, crate::Error::ChallengeMismatch { round: 2 });
+}
+
+#[test]
+fn test_prover_init_degree_bound() {
+    let g = thaler_example();
+    assert!(IPForSumcheck::<Fr>::prover_init_with_degree_bound(g.clone(), 3).is_ok());
+    assert!(IPForSumcheck::<Fr>::prover_init_with_degree_bound(g, 2).is_err());
+
+    // x0^(2^32) would overflow the u32 powers in `evaluate_term`.
+    let huge = crate::poly!(Fr; vars = 2; (1, [(0, 1 << 32), (1, 1)])).unwrap();
+    assert!(IPForSumcheck::<Fr>::prover_init(huge).is_err());
+}
+
+#[test]
+fn test_rejection_details() {
+    let mut rng = rand::thread_rng();
+    let g = random_multilinear_polynomial::<Fr, _>(4, 1 << 4, &mut rng);
+    let asserted_sum = IPForSumcheck::<Fr>::compute_sum(&g).unwrap();
+
+    // Shift round 2 by a constant, so that gi(0) + gi(1) is off by two.
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_state = run_rounds(&mut prover_state, &mut rng);
+    let expected = verifier_state.partial_sums[0].evaluate(&verifier_state.randomness[0]);
+    let one = UniPoly::from_coefficients_vec(vec![(0, Fr::from(1))]);
+    verifier_state.partial_sums[1] = &verifier_state.partial_sums[1] + &one;
+
+    let err = IPForSumcheck::<Fr>::verify(&g, verifier_state, asserted_sum).unwrap_err();
+    assert_eq!(err, crate::Error::sum_mismatch(2, expected, expected + Fr::from(2)));
+    let message = err.to_string();
+    assert!(message.contains("round 2"));
+    assert!(message.contains(&expected.to_string()));
+    assert!(message.contains(&(expected + Fr::from(2)).to_string()));
+}
+
+#[test]
+fn test_message_round_trip() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    let mut rng = rand::thread_rng();
+    let gi = UniPoly::from_coefficients_vec(vec![(0, Fr::from(3)), (2, Fr::from(5))]);
+    let p_msg = ProverMsg::new(gi);
+    assert_eq!(p_msg.degree(), 2);
+    assert_eq!(p_msg.evaluate(&Fr::from(2)), Fr::from(23));
+    assert_eq!(p_msg.eval_at(Fr::from(2)), Fr::from(23));
+    assert_eq!((p_msg.eval_at_zero(), p_msg.eval_at_one()), (Fr::from(3), Fr::from(8)));
+
+    // The zero polynomial, as sent for a `g` whose terms cancel out.
+    let zero_msg = ProverMsg::<Fr>::default();
+    assert_eq!(zero_msg, ProverMsg::new(UniPoly::from_coefficients_vec(vec![])));
+    assert_eq!((zero_msg.degree(), zero_msg.evaluate(&Fr::from(7))), (0, Fr::zero()));
+
+    for msg in [p_msg, zero_msg] {
+        for compressed in [true, false] {
+            let mut bytes = Vec::new();
+            if compressed {
+                msg.serialize_compressed(&mut bytes).unwrap();
+                assert_eq!(ProverMsg::deserialize_compressed(&bytes[..]).unwrap(), msg);
+            } else {
+                msg.serialize_uncompressed(&mut bytes).unwrap();
+                assert_eq!(ProverMsg::deserialize_uncompressed(&bytes[..]).unwrap(), msg);
+            }
+        }
+    }
+
+    for msg in [VerifierMsg::new(Fr::rand(&mut rng)), VerifierMsg::default()] {
+        let mut bytes = Vec::new();
+        msg.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), msg.compressed_size());
+        assert_eq!(VerifierMsg::<Fr>::deserialize_compressed(&bytes[..]).unwrap(), msg);
+    }
+    assert_eq!(VerifierMsg::<Fr>::default().randomness, Fr::zero());
+}
+
+#[test]
+fn test_verify_with_randomness() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let proof = record_proof(&g, &mut rng);
+    let (msgs, randomness) = (&proof.prover_messages, proof.verifier_randomness.clone());
+
+    assert!(IPForSumcheck::verify_with_randomness(&g, msgs, randomness.clone(), Fr::from(12)).is_ok());
+
+    // Messages answer the challenge of the previous round, so only the last one may change.
+    let mut other = randomness.clone();
+    other[2] += Fr::from(1);
+    assert!(IPForSumcheck::verify_with_randomness(&g, msgs, other.clone(), Fr::from(12)).is_ok());
+    other[0] += Fr::from(1);
+    assert!(matches!(
+        IPForSumcheck::verify_with_randomness(&g, msgs, other, Fr::from(12)),
+        Err(crate::Error::SumMismatch { round: 2, .. })
+    ));
+    assert!(matches!(
+        IPForSumcheck::verify_with_randomness(&g, msgs, randomness.clone(), Fr::from(13)),
+        Err(crate::Error::SumMismatch { round: 1, .. })
+    ));
+    assert_eq!(
+        IPForSumcheck::verify_with_randomness(&g, msgs, randomness[..2].to_vec(), Fr::from(12)),
+        Err(crate::Error::RoundMismatch { expected: 3, got: 2 })
+    );
+    assert_eq!(
+        IPForSumcheck::verify_with_randomness(&g, &msgs[..2], randomness, Fr::from(12)),
+        Err(crate::Error::RoundMismatch { expected: 3, got: 2 })
+    );
+
+    // With no variables there is nothing to send and `g` is its own sum.
+    let constant = MultiPoly::from_coefficients_vec(0, vec![(Fr::from(5), SparseTerm::new(vec![]))]);
+    assert!(IPForSumcheck::verify_with_randomness(&constant, &[], vec![], Fr::from(5)).is_ok());
+    assert!(IPForSumcheck::verify_with_randomness(&constant, &[], vec![], Fr::from(6)).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    use crate::naive_sumcheck::protocol::verifier::VerifierOutput;
+
+    let mut rng = rand::thread_rng();
+    let g = random_multilinear_polynomial::<Fr, _>(5, 1 << 5, &mut rng);
+    let (asserted_sum, proof, r_vec) = IPForSumcheck::run_protocol_seeded(&g, [3; 32]).unwrap();
+
+    let json = serde_json::to_string(&proof).unwrap();
+    let decoded: SumcheckProof<Fr> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.prover_messages, proof.prover_messages);
+    assert_eq!(decoded.verifier_randomness, proof.verifier_randomness);
+    assert!(IPForSumcheck::verify_seeded(&g, &decoded, asserted_sum, [3; 32]).is_ok());
+
+    let v_msg = VerifierMsg::new(r_vec[0]);
+    let decoded: VerifierMsg<Fr> = serde_json::from_str(&serde_json::to_string(&v_msg).unwrap()).unwrap();
+    assert_eq!(decoded, v_msg);
+
+    let v_out = VerifierOutput { r_vec: r_vec.clone(), expected_evaluation: g.evaluate(&r_vec) };
+    let decoded: VerifierOutput<Fr> =
+        serde_json::from_str(&serde_json::to_string(&v_out).unwrap()).unwrap();
+    assert_eq!((decoded.r_vec, decoded.expected_evaluation), (v_out.r_vec, v_out.expected_evaluation));
+
+    // Field elements are the hex digits of their compressed little-endian encoding.
+    let json = serde_json::to_value(VerifierMsg::new(Fr::from(12))).unwrap();
+    assert_eq!(json["randomness"], format!("0c{}", "00".repeat(31)));
+
+    // A JSON array cut short is a parsing error...
+    let json = serde_json::to_string(&proof).unwrap();
+    assert!(serde_json::from_str::<SumcheckProof<Fr>>(&json[..json.len() / 2]).is_err());
+
+    // ...while a well-formed proof missing its last round is rejected by the verifier.
+    let mut value = serde_json::to_value(&proof).unwrap();
+    value["prover_messages"].as_array_mut().unwrap().pop();
+    let truncated: SumcheckProof<Fr> = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        IPForSumcheck::verify_seeded(&g, &truncated, asserted_sum, [3; 32]),
+        Err(crate::Error::RoundMismatch { expected: 5, got: 4 })
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_rejects_invalid_field_elements() {
+    let parse = |randomness: String| {
+        serde_json::from_value::<VerifierMsg<Fr>>(serde_json::json!({
+            "version": MESSAGE_VERSION,
+            "randomness": randomness,
+        }))
+    };
+
+    assert!(parse(format!("0c{}", "00".repeat(31))).is_ok());
+    // Values beyond the modulus are rejected rather than reduced.
+    assert!(parse("ff".repeat(32)).is_err());
+    assert!(parse("0c".into()).is_err());
+    assert!(parse(format!("zz{}", "00".repeat(31))).is_err());
+
+    let unsorted = serde_json::json!({
+        "version": MESSAGE_VERSION,
+        "gi": [[2, format!("01{}", "00".repeat(31))], [1, format!("01{}", "00".repeat(31))]],
+    });
+    assert!(serde_json::from_value::<ProverMsg<Fr>>(unsorted).is_err());
+    let zero = serde_json::json!({ "version": MESSAGE_VERSION, "gi": [[0, "00".repeat(32)]] });
+    assert!(serde_json::from_value::<ProverMsg<Fr>>(zero).is_err());
+}
+
+#[test]
+fn test_proof_bytes() {
+    use crate::naive_sumcheck::protocol::proof::PROOF_FORMAT_VERSION;
+
+    let g = thaler_example();
+    let bounds = max_degrees(&g);
+    let (asserted_sum, proof, _) = IPForSumcheck::run_protocol_seeded(&g, [5; 32]).unwrap();
+    let bytes = proof.to_bytes();
+
+    assert_eq!(SumcheckProof::<Fr>::from_bytes(&bytes, &bounds), Ok(proof));
+
+    // Header: magic, version, number of rounds, then one degree per round.
+    let degree_at = |round: usize| 13 + 8 * round;
+    let patch = |offset: usize, value: u64| {
+        let mut bytes = bytes.clone();
+        bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+        SumcheckProof::<Fr>::from_bytes(&bytes, &bounds)
+    };
+    assert!(matches!(patch(0, 0), Err(crate::Error::InvalidMagic)));
+    let mut other_version = bytes.clone();
+    other_version[4] = PROOF_FORMAT_VERSION + 1;
+    assert_eq!(
+        SumcheckProof::<Fr>::from_bytes(&other_version, &bounds),
+        Err(crate::Error::UnsupportedVersion { expected: PROOF_FORMAT_VERSION, got: 2 })
+    );
+    assert!(matches!(patch(5, 1 << 40), Err(crate::Error::RoundMismatch { expected: 3, .. })));
+    assert_eq!(
+        patch(degree_at(1), u64::MAX),
+        Err(crate::Error::DegreeTooHigh { round: 2, got: usize::MAX, bound: 1 })
+    );
+    // An inflated list length is caught before anything is read from the list.
+    assert!(matches!(patch(degree_at(3), 1 << 40), Err(crate::Error::DeserializeError(_))));
+    assert_eq!(
+        SumcheckProof::<Fr>::from_bytes(&bytes, &bounds[..2]),
+        Err(crate::Error::RoundMismatch { expected: 2, got: 3 })
+    );
+
+    // Every truncation fails, and so does any trailing data.
+    for len in 0..bytes.len() {
+        assert_eq!(
+            SumcheckProof::<Fr>::from_bytes(&bytes[..len], &bounds),
+            Err(crate::Error::TruncatedProof)
+        );
+    }
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(SumcheckProof::<Fr>::from_bytes(&extended, &bounds).is_err());
+
+    // No single-byte mutation yields a proof that verifies.
+    for i in 0..bytes.len() {
+        for flip in [0x01, 0x80, 0xff] {
+            let mut mutated = bytes.clone();
+            mutated[i] ^= flip;
+            if let Ok(proof) = SumcheckProof::<Fr>::from_bytes(&mutated, &bounds) {
+                assert!(IPForSumcheck::verify_seeded(&g, &proof, asserted_sum, [5; 32]).is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_proof_size() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use crate::naive_sumcheck::protocol::proof::ProofStats;
+
+    let g = thaler_example();
+    let (asserted_sum, proof, _) = IPForSumcheck::run_protocol_seeded(&g, [7; 32]).unwrap();
+
+    // Per round: a version byte, a length and one (degree, coefficient) pair per term, where
+    // g_1 has three terms and g_2, g_3 two each. Then a length and three challenges.
+    let expected = 8 + 3 * (1 + 8) + 7 * (8 + 32) + 8 + 3 * 32;
+    assert_eq!(
+        proof.stats(),
+        ProofStats {
+            num_rounds: 3,
+            degrees: vec![3, 1, 1],
+            bytes_compressed: expected,
+            bytes_uncompressed: expected,
+        }
+    );
+    assert_eq!(proof.size_in_bytes(), 419);
+    assert_eq!(proof.size_bytes(), 419);
+    // The first round polynomial, of degree 3, dominates.
+    assert_eq!(proof.per_round_sizes(), vec![1 + 8 + 3 * 40, 1 + 8 + 2 * 40, 1 + 8 + 2 * 40]);
+
+    // Either encoding verifies.
+    let mut compressed = Vec::new();
+    proof.serialize_compressed(&mut compressed).unwrap();
+    let mut uncompressed = Vec::new();
+    proof.serialize_uncompressed(&mut uncompressed).unwrap();
+    for decoded in [
+        SumcheckProof::<Fr>::deserialize_compressed(&compressed[..]).unwrap(),
+        SumcheckProof::<Fr>::deserialize_uncompressed(&uncompressed[..]).unwrap(),
+    ] {
+        assert!(IPForSumcheck::verify_seeded(&g, &decoded, asserted_sum, [7; 32]).is_ok());
+    }
+}
+
+#[test]
+fn test_typestate_matches_free_functions() {
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use crate::naive_sumcheck::protocol::typestate::{
+        ProverActive, ProverStep, VerifierActive, VerifierStep,
+    };
+
+    let g = thaler_example::<Fr>();
+    let (asserted_sum, proof, r_vec) = IPForSumcheck::run_protocol_seeded(&g, [9; 32]).unwrap();
+    let mut transcript = RngTranscript::new(ChaCha20Rng::from_seed([9; 32]));
+
+    let (mut prover_msg, mut prover) = ProverActive::start(g.clone()).unwrap();
+    let mut verifier = VerifierActive::new(g.num_vars).unwrap();
+    let mut prover_messages = Vec::new();
+    let finished = loop {
+        prover_messages.push(prover_msg.clone());
+        let (verifier_msg, next) = verifier.verify_round(prover_msg, &mut transcript);
+        match (next, prover) {
+            (VerifierStep::Active(v), ProverStep::Active(p)) => {
+                verifier = v;
+                (prover_msg, prover) = p.prove_round(verifier_msg);
+            }
+            (VerifierStep::Finished(v), ProverStep::Finished(_)) => break v,
+            _ => panic!("Prover and verifier disagree on the number of rounds..."),
+        }
+    };
+
+    assert_eq!(prover_messages, proof.prover_messages);
+    let v_out = finished.partial_verify(asserted_sum).unwrap();
+    assert_eq!(v_out.r_vec, r_vec);
+    assert!(VerifierActive::<Fr>::new(0).is_err());
+}
+
+#[test]
+fn test_prover_rounds() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut rounds = IPForSumcheck::<Fr>::prover_rounds(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+    let mut verifier_msg = None;
+    assert_eq!(rounds.remaining_rounds(), 3);
+
+    while let Some(prover_message) = rounds.next_msg(verifier_msg.take()) {
+        verifier_msg = IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    assert_eq!(rounds.remaining_rounds(), 0);
+    assert_eq!(rounds.challenges_so_far(), &verifier_state.randomness[..]);
+    assert!(rounds.next_msg(None).is_none());
+
+    IPForSumcheck::<Fr>::verify(&g, verifier_state, Fr::from(12))
+        .expect("Failed to verify...");
+}
+
+#[test]
+fn test_prover_state_fork() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let first = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &None);
+
+    // Both branches start from the state after round 1 and answer different challenges.
+    let branches = [prover_state.clone(), prover_state];
+    let mut transcripts = Vec::new();
+    for mut state in branches {
+        let randomness: Vec<Fr> = (0..g.num_vars).map(|_| Fr::rand(&mut rng)).collect();
+        let mut prover_msgs = vec![first.clone()];
+        for r in &randomness[..g.num_vars - 1] {
+            prover_msgs.push(IPForSumcheck::<Fr>::prove_round(&mut state, &Some(VerifierMsg::new(*r))));
+        }
+        assert_eq!(state.randomness, randomness[..g.num_vars - 1]);
+
+        IPForSumcheck::verify_with_randomness(&g, &prover_msgs, randomness.clone(), Fr::from(12))
+            .expect("Failed to verify...");
+        transcripts.push(prover_msgs);
+    }
+    assert_ne!(transcripts[0][1], transcripts[1][1]);
+}
+
+#[test]
+fn test_verifier_state_fork() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+    let first = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &None);
+    let first_challenge =
+        IPForSumcheck::<Fr>::verify_round(first, &mut verifier_state, &mut RngTranscript::new(&mut rng)).unwrap();
+
+    // Both copies of each party continue from round 2, under independent challenges.
+    let branches = [(prover_state.clone(), verifier_state.clone()), (prover_state, verifier_state)];
+    let mut challenges = Vec::new();
+    for (mut prover_state, mut verifier_state) in branches {
+        let mut verifier_msg = Some(first_challenge.clone());
+        for _ in 1..g.num_vars {
+            let prover_msg = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+            verifier_msg = IPForSumcheck::<Fr>::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        }
+        assert_eq!(
+            format!("{verifier_state:?}"),
+            format!(
+                "VerifierState {{ round: 3, num_vars: 3, finished: true, partial_sums: 3, challenges: 3, domain: {:?}, base: 2, domain_sizes: None, degree_bounds: None }}",
+                DomainPoint::<Fr>::boolean()
+            )
+        );
+
+        challenges.push(verifier_state.randomness.clone());
+        IPForSumcheck::<Fr>::verify(&g, verifier_state, 12.into()).expect("Failed to verify...");
+    }
+    assert_eq!(challenges[0][0], challenges[1][0]);
+    assert_ne!(challenges[0][1], challenges[1][1]);
+
+    let prover_state = IPForSumcheck::<Fr>::prover_init(g).unwrap();
+    assert_eq!(
+        format!("{prover_state:?}"),
+        "ProverState { num_vars: 3, num_terms: 3, total_degree: 3, round: 0, challenges: 0 }"
+    );
+}
+
+#[test]
+fn test_memoized_prover() {
+    let mut rng = rand::thread_rng();
+
+    for g in [thaler_example(), random_sparse_polynomial::<Fr, _>(4, 8, 3, &mut rng), random_sparse_polynomial(5, 16, 1, &mut rng)] {
+        let asserted_sum = IPForSumcheck::compute_sum(&g).unwrap();
+        let memoized = IPForSumcheck::<Fr>::prover_init_memoized(&g).unwrap();
+
+        // Several executions share the table, each matching the naive prover for its own randomness.
+        for execution in 0..3 {
+            let mut memoized_state = memoized.restart();
+            assert!(Arc::ptr_eq(&memoized_state.table, &memoized.table));
+            let mut naive_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+            let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+            let mut verifier_msg = None;
+
+            for _ in 0..g.num_vars {
+                let naive_msg = IPForSumcheck::prove_round(&mut naive_state, &verifier_msg);
+                let memoized_msg = IPForSumcheck::prove_round_memoized(&mut memoized_state, &verifier_msg);
+                assert_eq!(memoized_msg, naive_msg, "execution {execution}");
+
+                verifier_msg = IPForSumcheck::verify_round(memoized_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+            }
+
+            IPForSumcheck::verify(&g, verifier_state, asserted_sum).expect("Failed to verify...");
+        }
+    }
+
+    // A multilinear polynomial is tabulated over the boolean hypercube only.
+    let g = random_sparse_polynomial::<Fr, _>(6, 16, 1, &mut rng);
+    let memoized = IPForSumcheck::<Fr>::prover_init_memoized(&g).unwrap();
+    assert_eq!(memoized.table.evaluations.len(), 1 << 6);
+}
+
+#[test]
+fn test_invalid_polynomials_fail_fast() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    // x5 in a 3-variable polynomial, which ark-poly would only catch by panicking.
+    let mut out_of_range = g.clone();
+    out_of_range.terms.push((1.into(), SparseTerm::new(vec![(5, 1)])));
+    assert!(matches!(
+        IPForSumcheck::<Fr>::prover_init(out_of_range.clone()),
+        Err(crate::Error::PolynomialError(_))
+    ));
+    assert!(matches!(IPForSumcheck::<Fr>::compute_sum(&out_of_range), Err(crate::Error::PolynomialError(_))));
+    let verifier_state = run_rounds(&mut IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap(), &mut rng);
+    assert!(matches!(
+        IPForSumcheck::<Fr>::verify(&out_of_range, verifier_state, 12.into()),
+        Err(crate::Error::PolynomialError(_))
+    ));
+
+    // 2·x0^3 split into two terms.
+    let mut duplicated = g.clone();
+    let cube = duplicated.terms.iter().position(|(c, _)| *c == Fr::from(2)).unwrap();
+    duplicated.terms[cube].0 = 1.into();
+    duplicated.terms.push(duplicated.terms[cube].clone());
+    assert!(matches!(IPForSumcheck::<Fr>::compute_sum(&duplicated), Err(crate::Error::PolynomialError(_))));
+    assert!(IPForSumcheck::<Fr>::prover_init(duplicated.clone()).is_err());
+    let verifier_state = run_rounds(&mut IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap(), &mut rng);
+    assert!(IPForSumcheck::<Fr>::verify(&duplicated, verifier_state, 12.into()).is_err());
+
+    // Unless the caller asks for the two halves to be merged back.
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init_normalized(duplicated).unwrap();
+    assert_eq!(prover_state.g, g);
+    let verifier_state = run_rounds(&mut prover_state, &mut rng);
+    IPForSumcheck::<Fr>::verify(&prover_state.g, verifier_state, 12.into()).expect("Failed to verify...");
+}
+
+#[test]
+fn test_constant_polynomial() {
+    let mut rng = rand::thread_rng();
+    let g = crate::poly!(Fr; vars = 0; (7, [])).unwrap();
+
+    // No rounds are run, and only the constant term is compared with the claim.
+    let (asserted_sum, v_out) = IPForSumcheck::<Fr>::run_interactive_protocol(&g, &mut rng).unwrap();
+    assert_eq!(asserted_sum, Fr::from(7));
+    assert!(v_out.r_vec.is_empty());
+    assert!(matches!(
+        IPForSumcheck::<Fr>::run_interactive_protocol_with_sum(&g, 8.into(), &mut rng),
+        Err(crate::Error::FinalEvaluationMismatch { .. })
+    ));
+
+    let mut rounds = IPForSumcheck::<Fr>::prover_rounds(g.clone()).unwrap();
+    assert_eq!(rounds.remaining_rounds(), 0);
+    assert!(rounds.next_msg(None).is_none());
+
+    // The verifier is finished from the start and rejects any message.
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(0);
+    assert!(verifier_state.finished);
+    assert!(IPForSumcheck::verify_round(
+        ProverMsg::new(UniPoly::zero()),
+        &mut verifier_state,
+        &mut RngTranscript::new(&mut rng),
+    )
+    .is_err());
+    assert!(IPForSumcheck::<Fr>::verify(&g, IPForSumcheck::verifier_init(0), 7.into()).is_ok());
+    assert!(IPForSumcheck::<Fr>::verify(&g, IPForSumcheck::verifier_init(0), 8.into()).is_err());
+
+    let proof = IPForSumcheck::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+    assert_eq!(proof.num_rounds(), 0);
+    assert!(IPForSumcheck::verify_non_interactive(&g, &proof, 7.into(), &mut Blake2Transcript::new()).is_ok());
+    assert!(IPForSumcheck::verify_non_interactive(&g, &proof, 8.into(), &mut Blake2Transcript::new()).is_err());
+}
+
+#[test]
+fn test_zero_polynomial() {
+    let mut rng = rand::thread_rng();
+
+    for num_vars in [1, 4] {
+        let g = MultiPoly::<Fr> { num_vars, terms: vec![] };
+        assert_eq!(IPForSumcheck::compute_sum(&g).unwrap(), Fr::zero());
+
+        // Every round polynomial is zero.
+        let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+        let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+        let mut verifier_msg = None;
+        for _ in 0..num_vars {
+            let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+            assert!(prover_msg.gi.is_zero());
+            verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        }
+        IPForSumcheck::verify(&g, verifier_state, Fr::zero()).expect("Failed to verify...");
+
+        assert!(IPForSumcheck::run_interactive_protocol(&g, &mut rng).is_ok());
+        assert!(matches!(
+            IPForSumcheck::run_interactive_protocol_with_sum(&g, 5.into(), &mut rng),
+            Err(crate::Error::SumMismatch { round: 1, .. })
+        ));
+
+        // The same in the non-interactive form, including a byte encoding round trip.
+        let proof = IPForSumcheck::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+        let decoded = SumcheckProof::from_bytes(&proof.to_bytes(), &max_degrees(&g)).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(IPForSumcheck::verify_non_interactive(&g, &decoded, Fr::zero(), &mut Blake2Transcript::new()).is_ok());
+        assert!(IPForSumcheck::verify_non_interactive(&g, &decoded, 1.into(), &mut Blake2Transcript::new()).is_err());
+
+        let mut memoized = IPForSumcheck::prover_init_memoized(&g).unwrap();
+        assert!(IPForSumcheck::prove_round_memoized(&mut memoized, &None).gi.is_zero());
+    }
+}
+
+#[test]
+fn test_negative_sum() {
+    let mut rng = rand::thread_rng();
+
+    // 3·x0·x1 - 2·x2 - 5·x0^2·x2 sums to 3·2 - 2·4 - 5·2 = -12 over the hypercube.
+    let g = poly_from_signed::<Fr>(3, &[(3, &[(0, 1), (1, 1)]), (-2, &[(2, 1)]), (-5, &[(0, 2), (2, 1)])])
+        .unwrap();
+    let (asserted_sum, _) = IPForSumcheck::<Fr>::run_interactive_protocol(&g, &mut rng)
+        .expect("Failed to verify...");
+    assert_eq!(asserted_sum, -Fr::from(12));
+    assert!(IPForSumcheck::<Fr>::run_interactive_protocol_with_sum(&g, 12.into(), &mut rng).is_err());
+}
+
+#[test]
+fn test_proof_validate() {
+    let g = thaler_example::<Fr>();
+    let bounds = max_degrees(&g);
+    let proof = IPForSumcheck::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+    assert!(proof.validate(3, &bounds).is_ok());
+
+    // Missing round, missing challenge and wrong number of bounds.
+    let mut short = proof.clone();
+    short.prover_messages.pop();
+    assert_eq!(short.validate(3, &bounds), Err(crate::Error::RoundMismatch { expected: 3, got: 2 }));
+    let mut short = proof.clone();
+    short.verifier_randomness.pop();
+    assert_eq!(short.validate(3, &bounds), Err(crate::Error::RoundMismatch { expected: 3, got: 2 }));
+    assert!(matches!(proof.validate(3, &bounds[..2]), Err(crate::Error::ProtocolError(_))));
+
+    // A round polynomial above its bound is rejected before any transcript is replayed.
+    let mut high = proof.clone();
+    high.prover_messages[1] = ProverMsg::new(UniPoly::from_coefficients_vec(vec![(2, 1.into())]));
+    let expected = Err(crate::Error::DegreeTooHigh { round: 2, got: 2, bound: 1 });
+    assert_eq!(high.validate(3, &bounds), expected);
+    assert_eq!(
+        IPForSumcheck::verify_non_interactive(&g, &high, 12.into(), &mut Blake2Transcript::new()),
+        expected
+    );
+}
+
+#[test]
+fn test_suspend_and_resume() {
+    use ark_serialize::CanonicalSerialize;
+
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(5, 12, 3, &mut rng);
+    let asserted_sum = IPForSumcheck::<Fr>::compute_sum(&g).unwrap();
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+    let mut verifier_msg = None;
+
+    let mut run = |prover_state: &mut ProverState<Fr>, verifier_state: &mut VerifierState<Fr>, rounds| {
+        for _ in 0..rounds {
+            let prover_msg = IPForSumcheck::<Fr>::prove_round(prover_state, &verifier_msg);
+            verifier_msg = IPForSumcheck::<Fr>::verify_round(prover_msg, verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        }
+    };
+    run(&mut prover_state, &mut verifier_state, 3);
+
+    let mut prover_bytes = Vec::new();
+    prover_state.serialize_compressed(&mut prover_bytes).unwrap();
+    let mut verifier_bytes = Vec::new();
+    verifier_state.serialize_compressed(&mut verifier_bytes).unwrap();
+
+    let mut prover_state = ProverState::<Fr>::resume(&prover_bytes).unwrap();
+    let mut verifier_state = VerifierState::<Fr>::resume(&verifier_bytes).unwrap();
+    assert_eq!(prover_state.round, 3);
+    run(&mut prover_state, &mut verifier_state, g.num_vars - 3);
+    IPForSumcheck::<Fr>::verify(&g, verifier_state, asserted_sum).expect("Failed to verify...");
+
+    // A state whose round counter disagrees with its challenges is not resumed.
+    let mut corrupt = ProverState::<Fr>::resume(&prover_bytes).unwrap();
+    corrupt.round += 1;
+    let mut bytes = Vec::new();
+    corrupt.serialize_compressed(&mut bytes).unwrap();
+    assert!(matches!(ProverState::<Fr>::resume(&bytes), Err(crate::Error::InvalidState(_))));
+
+    // The verifier's round counter comes first in its encoding.
+    let mut bytes = verifier_bytes.clone();
+    bytes[0] += 1;
+    assert!(matches!(VerifierState::<Fr>::resume(&bytes), Err(crate::Error::InvalidState(_))));
+    bytes[0] = 0;
+    assert!(matches!(VerifierState::<Fr>::resume(&bytes), Err(crate::Error::InvalidState(_))));
+}
+
+
+#[test]
+fn test_base_hypercube() {
+    use ark_serialize::CanonicalSerialize;
+
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    // Brute-force sum over {0, 1, 2}^3.
+    let mut brute_force = Fr::zero();
+    for x0 in 0..3_u64 {
+        for x1 in 0..3_u64 {
+            for x2 in 0..3_u64 {
+                brute_force += g.evaluate(&vec![Fr::from(x0), Fr::from(x1), Fr::from(x2)]);
+            }
+        }
+    }
+    assert_eq!(IPForSumcheck::compute_sum_with_base(&g, 3), brute_force);
+    assert_eq!(to_base_vec::<Fr>(5, 3, 3), [0, 1, 2].map(Fr::from));
+
+    let mut prover_state = IPForSumcheck::prover_init_with_base(g.clone(), 3).unwrap();
+    assert_eq!(prover_state.asserted_sum(), brute_force);
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init_with_base(3, 3);
+    let mut verifier_msg = None;
+    for _ in 0..3 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    IPForSumcheck::verify(&g, verifier_state.clone(), brute_force).expect("Failed to verify...");
+    assert!(IPForSumcheck::verify(&g, verifier_state.clone(), brute_force + Fr::from(1)).is_err());
+
+    // The base survives a round trip, and a boolean verifier rejects the same transcript.
+    let mut bytes = Vec::new();
+    verifier_state.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(VerifierState::<Fr>::resume(&bytes).unwrap().base(), 3);
+    verifier_state.base = 2;
+    assert!(matches!(
+        IPForSumcheck::verify(&g, verifier_state, brute_force),
+        Err(crate::Error::SumMismatch { .. })
+    ));
+
+    // Base 2 is the boolean hypercube, message for message.
+    let g = random_sparse_polynomial::<Fr, _>(4, 10, 3, &mut rng);
+    let mut boolean = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut base_two = IPForSumcheck::prover_init_with_base(g, 2).unwrap();
+    assert_eq!(base_two.asserted_sum(), boolean.asserted_sum());
+    let mut verifier_msg = None;
+    for _ in 0..4 {
+        let prover_msg = IPForSumcheck::prove_round(&mut base_two, &verifier_msg);
+        assert_eq!(prover_msg, IPForSumcheck::prove_round(&mut boolean, &verifier_msg));
+        verifier_msg = Some(VerifierMsg::new(Fr::rand(&mut rng)));
+    }
+    for i in 0..16 {
+        assert_eq!(to_base_vec::<Fr>(i, 4, 2), to_binary_vec::<Fr>(i, 4));
+    }
+
+    assert!(IPForSumcheck::prover_init_with_base(thaler_example::<Fr>(), 1).is_err());
+}
+
+#[test]
+fn test_per_variable_domains() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let domains: Vec<Vec<Fr>> = vec![[0, 1].map(Fr::from).to_vec(), [0, 1, 2, 5].map(Fr::from).to_vec(), [3, 7, 11].map(Fr::from).to_vec()];
+
+    let mut nested = Fr::zero();
+    for x0 in &domains[0] {
+        for x1 in &domains[1] {
+            for x2 in &domains[2] {
+                nested += g.evaluate(&vec![*x0, *x1, *x2]);
+            }
+        }
+    }
+    assert_eq!(IPForSumcheck::compute_sum_over_domains(&g, &domains), nested);
+    assert_eq!(to_mixed_radix_vec(23, &[2, 4, 3]), [1, 3, 2]);
+
+    let mut prover_state = IPForSumcheck::prover_init_with_domains(g.clone(), domains.clone()).unwrap();
+    assert_eq!(prover_state.asserted_sum(), nested);
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init_with_domains(domains.clone()).unwrap();
+    let mut verifier_msg = None;
+    for _ in 0..3 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    assert_eq!(verifier_state.domains(), Some(&domains[..]));
+    IPForSumcheck::verify(&g, verifier_state.clone(), nested).expect("Failed to verify...");
+    assert!(matches!(
+        IPForSumcheck::verify(&g, verifier_state, nested + Fr::from(1)),
+        Err(crate::Error::SumMismatch { round: 1, .. })
+    ));
+
+    // Empty domains, repeated points and a missing domain are rejected at init.
+    let mut empty = domains.clone();
+    empty[1].clear();
+    let mut repeated = domains.clone();
+    repeated[2].push(Fr::from(7));
+    for bad in [empty, repeated, domains[..2].to_vec()] {
+        assert!(matches!(IPForSumcheck::prover_init_with_domains(g.clone(), bad.clone()), Err(crate::Error::ProtocolError(_))));
+        if bad.len() == 3 {
+            assert!(matches!(IPForSumcheck::<Fr>::verifier_init_with_domains(bad), Err(crate::Error::ProtocolError(_))));
+        }
+    }
+}
+
+#[test]
+fn test_sumcheck_over_domain_point() {
+    use ark_serialize::CanonicalSerialize;
+
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+    let domain = DomainPoint { zero: Fr::from(3), one: Fr::from(-2) };
+    let domains = vec![vec![domain.zero, domain.one]; 3];
+    let sum = IPForSumcheck::compute_sum_over_domains(&g, &domains);
+
+    let mut prover_state = IPForSumcheck::prover_init_with_domains(g.clone(), domains).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init_with_domain_point(3, domain).unwrap();
+    assert_eq!(verifier_state.domain(), domain);
+    let mut verifier_msg = None;
+    for _ in 0..3 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+    }
+    let mut bytes = Vec::new();
+    verifier_state.serialize_compressed(&mut bytes).unwrap();
+    let restored = VerifierState::<Fr>::resume(&bytes).unwrap();
+    assert_eq!(restored.domain(), domain);
+    IPForSumcheck::verify(&g, restored, sum).expect("Failed to verify...");
+    assert!(matches!(
+        IPForSumcheck::verify(&g, verifier_state, IPForSumcheck::compute_sum(&g).unwrap()),
+        Err(crate::Error::SumMismatch { round: 1, .. })
+    ));
+
+    let degenerate = DomainPoint { zero: Fr::from(5), one: Fr::from(5) };
+    assert!(matches!(
+        IPForSumcheck::<Fr>::verifier_init_with_domain_point(3, degenerate),
+        Err(crate::Error::ProtocolError(_))
+    ));
+}
+
+// Runs the protocol with the first `k` variables skipped, optionally shifting the first message.
+fn run_skip<R: RngCore>(g: &MultiPoly<Fr>, k: usize, shift: UniPoly<Fr>, rng: &mut R) -> VerifierState<Fr> {
+    let mut prover_state = IPForSumcheck::prover_init_skip(g.clone(), k).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init_skip(g.num_vars, k);
+    let mut verifier_msg = None;
+    for round in 0..prover_state.num_rounds() {
+        let mut prover_msg = IPForSumcheck::prove_round_skip(&mut prover_state, &verifier_msg);
+        if round == 0 {
+            assert!(prover_msg.degree() < 1 << k);
+            prover_msg = ProverMsg::new(prover_msg.gi + shift.clone());
+        }
+        verifier_msg = Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut *rng)).unwrap());
+    }
+    assert!(std::panic::catch_unwind(move || IPForSumcheck::prove_round_skip(&mut prover_state, &verifier_msg)).is_err());
+
+    verifier_state
+}
+
+#[test]
+fn test_univariate_skip() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 5;
+
+    for g in [random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng), random_sparse_polynomial::<Fr, _>(num_vars, 12, 3, &mut rng)] {
+        let sum = IPForSumcheck::compute_sum(&g).unwrap();
+
+        // Skipped and unskipped protocols accept and reject the same claims.
+        for k in 1..=num_vars {
+            let skipped = run_skip(&g, k, UniPoly::zero(), &mut rng);
+            assert_eq!(skipped.num_vars(), 1 + num_vars - k);
+            let unskipped = run_rounds(&mut IPForSumcheck::prover_init(g.clone()).unwrap(), &mut rng);
+            for claim in [sum, sum + Fr::from(1)] {
+                assert_eq!(
+                    IPForSumcheck::verify_skip(&g, k, skipped.clone(), claim).is_ok(),
+                    IPForSumcheck::verify(&g, &unskipped, claim).is_ok(),
+                );
+            }
+            IPForSumcheck::verify_skip(&g, k, skipped, sum).expect("Failed to verify...");
+
+            // A shifted first message breaks the sum over the skip domain, and one of degree 2^k
+            // the bound.
+            let verifier_state = run_skip(&g, k, UniPoly::from_coefficients_vec(vec![(0, Fr::from(1))]), &mut rng);
+            assert!(matches!(IPForSumcheck::verify_skip(&g, k, verifier_state, sum), Err(crate::Error::SumMismatch { round: 1, .. })));
+            let verifier_state = run_skip(&g, k, UniPoly::from_coefficients_vec(vec![(1 << k, Fr::from(1))]), &mut rng);
+            assert!(matches!(IPForSumcheck::verify_skip(&g, k, verifier_state, sum), Err(crate::Error::DegreeTooHigh { round: 1, .. })));
+        }
+
+        // At a point j of the skip domain, the restriction fixes the first k variables to bits(j).
+        let k = 3;
+        let x: Vec<Fr> = (0..num_vars - k).map(|_| Fr::rand(&mut rng)).collect();
+        for (j, point) in skip_domain::<Fr>(k).into_iter().enumerate() {
+            let mut full = to_binary_vec::<Fr>(j, k);
+            full.extend(&x);
+            assert_eq!(restrict_skipped(&g, k, point).evaluate(&x), g.evaluate(&full));
+        }
+    }
+
+    // For multilinear g, skipping a single variable is the ordinary protocol.
+    let g = random_multilinear_polynomial::<Fr, _>(3, 1 << 3, &mut rng);
+    let mut skip_state = IPForSumcheck::prover_init_skip(g.clone(), 1).unwrap();
+    let mut prover_state = IPForSumcheck::prover_init(g).unwrap();
+    let mut verifier_msg = None;
+    for _ in 0..3 {
+        assert_eq!(
+            IPForSumcheck::prove_round_skip(&mut skip_state, &verifier_msg),
+            IPForSumcheck::prove_round(&mut prover_state, &verifier_msg)
+        );
+        verifier_msg = Some(VerifierMsg::new(Fr::rand(&mut rng)));
+    }
+}
+
+#[test]
+fn test_virtual_evaluation_rounds() {
+    let mut rng = rand::thread_rng();
+    let num_vars = 6;
+    let mut virtual_poly = VirtualPolynomial::new(num_vars);
+    let factors: Vec<DenseMLE<Fr>> = (0..3)
+        .map(|_| random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng))
+        .map(|g| DenseMLE::from_evaluations(num_vars, hypercube_evaluations(&g)).unwrap())
+        .collect();
+    virtual_poly.add_product(1.into(), factors.clone()).unwrap();
+    virtual_poly.add_product(5.into(), factors[..1].to_vec()).unwrap();
+    let sum = virtual_poly.sum_over_hypercube();
+
+    // Runs both paths on the same messages, shifting the evaluations of `tampered` rounds.
+    let mut run = |tampered: &[usize]| {
+        let mut prover_state = IPForSumcheck::prover_init_virtual(virtual_poly.clone());
+        let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+        let mut round_evals = Vec::new();
+        let mut verifier_msg = None;
+        for round in 0..num_vars {
+            let mut evals = IPForSumcheck::prove_round_virtual_evaluations(&mut prover_state, &verifier_msg);
+            if tampered.contains(&round) {
+                evals[1] += Fr::from(1);
+            }
+            let prover_msg = ProverMsg::new(crate::poly_utils::uni_poly_from_evaluations(&evals));
+            round_evals.push(evals);
+            verifier_msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).ok();
+        }
+        (verifier_state, round_evals)
+    };
+
+    for tampered in [vec![], vec![0], vec![num_vars - 1], vec![2, 3]] {
+        let (verifier_state, round_evals) = run(&tampered);
+        for claim in [sum, sum + Fr::from(1)] {
+            let coefficients = IPForSumcheck::verify_virtual(&virtual_poly, verifier_state.clone(), claim);
+            let evaluations = IPForSumcheck::verify_virtual_evaluations(&virtual_poly, &round_evals, verifier_state.challenges(), claim);
+            assert_eq!(coefficients, evaluations);
+            assert_eq!(evaluations.is_ok(), tampered.is_empty() && claim == sum);
+        }
+    }
+
+    // Too many evaluations exceed the degree bound, too few rounds the round count.
+    let (verifier_state, mut round_evals) = run(&[]);
+    let challenges = verifier_state.challenges();
+    assert!(matches!(
+        IPForSumcheck::verify_virtual_evaluations(&virtual_poly, &round_evals[..num_vars - 1], &challenges[..num_vars - 1], sum),
+        Err(crate::Error::RoundMismatch { .. })
+    ));
+    round_evals[0].push(Fr::from(1));
+    assert!(matches!(
+        IPForSumcheck::verify_virtual_evaluations(&virtual_poly, &round_evals, challenges, sum),
+        Err(crate::Error::DegreeTooHigh { round: 1, got: 4, bound: 3 })
+    ));
+}
+
+#[test]
+fn test_compressed_proof() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use crate::transcript::{FsConfig, SumcheckTranscript};
+
+    let g = thaler_example();
+    let sum = IPForSumcheck::compute_sum(&g).unwrap();
+    let bound = |g: &MultiPoly<Fr>, sum: Fr| {
+        let mut transcript = Blake2Transcript::new();
+        transcript.absorb_instance(&FsConfig::new(g, sum));
+        transcript
+    };
+    let proof = IPForSumcheck::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+
+    let compressed = proof.compress_non_interactive(&mut bound(&g, sum)).unwrap();
+    assert_eq!(compressed.num_rounds(), 3);
+    // Only the three challenges, and the length of their list, are dropped.
+    assert_eq!(compressed.compressed_size() + 8 + 3 * 32, proof.compressed_size());
+
+    let mut bytes = Vec::new();
+    compressed.serialize_compressed(&mut bytes).unwrap();
+    let decoded = CompressedSumcheckProof::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+    assert_eq!(decoded.decompress(&mut bound(&g, sum)), proof);
+    assert!(IPForSumcheck::verify_non_interactive(&g, &decoded.decompress(&mut bound(&g, sum)), sum, &mut Blake2Transcript::new()).is_ok());
+
+    // A transcript bound to another claim derives other challenges.
+    let other = decoded.decompress(&mut bound(&g, sum + Fr::from(1)));
+    assert_ne!(other, proof);
+    assert!(matches!(
+        IPForSumcheck::verify_non_interactive(&g, &other, sum, &mut Blake2Transcript::new()),
+        Err(crate::Error::ChallengeMismatch { round: 1 })
+    ));
+    assert!(matches!(
+        proof.compress_non_interactive(&mut Blake2Transcript::new()),
+        Err(crate::Error::ChallengeMismatch { round: 1 })
+    ));
+
+    // Interactive challenges are not derivable, so such proofs do not compress.
+    let (_, seeded, _) = IPForSumcheck::run_protocol_seeded(&g, [7; 32]).unwrap();
+    assert!(seeded.compress_non_interactive(&mut bound(&g, sum)).is_err());
+}
+
+#[test]
+fn test_verifier_degree_bounds() {
+    let g = thaler_example();
+    let sum = IPForSumcheck::compute_sum(&g).unwrap();
+    assert_eq!(DegreeInfo::from_poly(&g), DegreeInfo::new(vec![3, 1, 1]));
+    let mut transcript = RngTranscript::new(rand::thread_rng());
+
+    // A verifier that only knows the number of variables and the bounds.
+    let mut prover_state = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init_with_degrees(3, DegreeInfo::new(vec![3, 1, 1])).unwrap();
+    let mut verifier_msg = None;
+    for _ in 0..3 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg = Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+    assert_eq!(verifier_state.degree_bounds(), Some(&[3, 1, 1][..]));
+    let v_out = IPForSumcheck::partial_verify(&verifier_state, sum).unwrap();
+    assert_eq!(g.evaluate(&v_out.r_vec), v_out.expected_evaluation);
+    assert!(IPForSumcheck::verify(&g, &verifier_state, sum).is_ok());
+
+    // An over-degree message in round 3 is rejected on arrival, before a challenge is drawn.
+    let mut prover_state = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init_with_degrees(3, DegreeInfo::from_poly(&g)).unwrap();
+    let mut verifier_msg = None;
+    for _ in 0..2 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg = Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap());
+    }
+    let high = ProverMsg::new(UniPoly::from_coefficients_vec(vec![(0, Fr::from(1)), (2, Fr::from(1))]));
+    assert_eq!(
+        IPForSumcheck::verify_round(high, &mut verifier_state, &mut transcript),
+        Err(crate::Error::DegreeTooHigh { round: 3, got: 2, bound: 1 })
+    );
+    assert_eq!(verifier_state.challenges().len(), 2);
+
+    // A state holding an over-degree message does not resume.
+    use ark_serialize::CanonicalSerialize;
+    let mut tampered = verifier_state.clone();
+    tampered.partial_sums[1] = UniPoly::from_coefficients_vec(vec![(2, Fr::from(1))]);
+    let mut bytes = Vec::new();
+    tampered.serialize_compressed(&mut bytes).unwrap();
+    assert!(matches!(VerifierState::<Fr>::resume(&bytes), Err(crate::Error::InvalidState(_))));
+
+    // One bound per variable, no more and no less.
+    for bounds in [vec![3, 1], vec![3, 1, 1, 1]] {
+        assert!(matches!(
+            IPForSumcheck::<Fr>::verifier_init_with_degrees(3, DegreeInfo::new(bounds)),
+            Err(crate::Error::ProtocolError(_))
+        ));
+    }
+}
+
+#[test]
+fn test_canonical_round_messages() {
+    use ark_serialize::CanonicalSerialize;
+    use crate::poly_utils::{is_canonical_uni_poly, normalize_uni_poly};
+
+    // The x0^2 terms cancel over x1 and x2, leaving g_1 = 4·x0 of degree 1 below the bound 2.
+    let g = crate::poly!(Fr; vars = 3; (1, [(0, 2), (1, 1)]), (-1, [(0, 2), (2, 1)]), (1, [(0, 1)])).unwrap();
+    let mut prover_state = IPForSumcheck::prover_init(g.clone()).unwrap();
+    assert_eq!(prover_state.degree_bounds, max_degrees(&g));
+
+    // Listing the round polynomial of every suffix side by side repeats degrees.
+    let naive = UniPoly::from_coefficients_vec(
+        (0..4)
+            .flat_map(|b| prover_state.evaluate_gi(to_binary_vec(b, 3)).iter().copied().collect::<Vec<_>>())
+            .collect(),
+    );
+    assert!(!is_canonical_uni_poly(&naive));
+
+    let msg = IPForSumcheck::prove_round(&mut prover_state, &None);
+    assert_eq!(msg.gi, normalize_uni_poly(naive));
+    assert_eq!(msg.gi, UniPoly::from_coefficients_vec(vec![(1, Fr::from(4))]));
+    assert!(is_canonical_uni_poly(&msg.gi));
+    assert_eq!(msg.check_degree(1, 2), Ok(()));
+    assert_eq!(msg.check_degree(1, 0), Err(crate::Error::DegreeTooHigh { round: 1, got: 1, bound: 0 }));
+
+    // Duplicates are merged and cancelled coefficients dropped.
+    let (a, b) = (Fr::from(3), Fr::from(5));
+    let messy = UniPoly::from_coefficients_vec(vec![(0, Fr::zero()), (1, a), (1, -a), (2, a), (2, b)]);
+    assert_eq!(normalize_uni_poly(messy), UniPoly::from_coefficients_vec(vec![(2, a + b)]));
+
+    // A strict prover refuses to send a message above the bound it was given.
+    let mut strict_state = IPForSumcheck::prover_init_strict(g.clone()).unwrap();
+    let mut bytes = Vec::new();
+    strict_state.serialize_compressed(&mut bytes).unwrap();
+    let resumed = ProverState::<Fr>::resume(&bytes).unwrap();
+    assert!(resumed.strict);
+    assert_eq!(resumed.degree_bounds, strict_state.degree_bounds);
+
+    let msg = IPForSumcheck::try_prove_round(&mut strict_state, &None).unwrap();
+    strict_state.degree_bounds[1] = 0;
+    let v_msg = Some(VerifierMsg::new(Fr::from(3)));
+    assert!(matches!(
+        IPForSumcheck::try_prove_round(&mut strict_state, &v_msg),
+        Err(crate::Error::PolynomialError(e)) if e.contains("malformed")
+    ));
+    assert_eq!((strict_state.round, strict_state.randomness.len()), (1, 0));
+
+    // Once the bound is restored, the same challenge is answered as usual.
+    strict_state.degree_bounds[1] = resumed.degree_bounds[1];
+    let next = IPForSumcheck::try_prove_round(&mut strict_state, &v_msg).unwrap();
+    assert_eq!(next.eval_at_zero() + next.eval_at_one(), msg.gi.evaluate(&Fr::from(3)));
+}
+
+#[test]
+fn test_verify_round_checked() {
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    let mut rng = rand::thread_rng();
+    let num_vars = 6;
+    let g = random_multilinear_polynomial::<Fr, _>(num_vars, 1 << num_vars, &mut rng);
+    let sum = IPForSumcheck::compute_sum(&g).unwrap();
+
+    // Runs until the verifier aborts, with the prover shifting gi by `tamper` in round `deviate`.
+    let run = |deviate: Option<usize>, tamper: &UniPoly<Fr>, eager: bool| {
+        let mut prover_state = IPForSumcheck::prover_init(g.clone()).unwrap();
+        let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(num_vars);
+        let mut transcript = RngTranscript::new(ChaCha20Rng::from_seed([4; 32]));
+        let mut verifier_msg = None;
+        for round in 0..num_vars {
+            let mut prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+            if deviate == Some(round) {
+                prover_msg = ProverMsg::new(&prover_msg.gi + tamper);
+            }
+            let result = if eager {
+                IPForSumcheck::verify_round_checked(prover_msg, &mut verifier_state, sum, &mut transcript)
+            } else {
+                IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript)
+            };
+            match result {
+                Ok(v_msg) => verifier_msg = Some(v_msg),
+                Err(e) => return (verifier_state, Err(e)),
+            }
+        }
+        let result = IPForSumcheck::verify(&g, &verifier_state, sum);
+        (verifier_state, result)
+    };
+
+    // Honest runs accept in both modes, with identical transcripts.
+    let (lazy_state, lazy) = run(None, &UniPoly::zero(), false);
+    let (eager_state, eager) = run(None, &UniPoly::zero(), true);
+    assert!(lazy.is_ok() && eager.is_ok());
+    assert_eq!(lazy_state.challenges(), eager_state.challenges());
+
+    // A constant shift breaks gi(0) + gi(1) in the round it is sent...
+    let shift = UniPoly::from_coefficients_vec(vec![(0, Fr::from(1))]);
+    // ...while X(X - 1) keeps it, but moves gi(r) and so the next round's claim.
+    let bump = UniPoly::from_coefficients_vec(vec![(1, -Fr::from(1)), (2, Fr::from(1))]);
+    for deviate in 0..num_vars {
+        let (state, result) = run(Some(deviate), &shift, true);
+        assert!(matches!(result, Err(crate::Error::SumMismatch { round, .. }) if round == deviate + 1));
+        assert_eq!(state.challenges().len(), deviate);
+
+        let (_, result) = run(Some(deviate), &shift, false);
+        assert!(matches!(result, Err(crate::Error::SumMismatch { round, .. }) if round == deviate + 1));
+
+        let (state, result) = run(Some(deviate), &bump, true);
+        if deviate + 1 < num_vars {
+            assert!(matches!(result, Err(crate::Error::SumMismatch { round, .. }) if round == deviate + 2));
+            assert_eq!(state.challenges().len(), deviate + 1);
+        } else {
+            // No round follows the last one, but g is multilinear, so `verify` bounds its degree.
+            assert!(matches!(result, Err(crate::Error::DegreeTooHigh { round, got: 2, bound: 1 }) if round == num_vars));
+        }
+    }
+}
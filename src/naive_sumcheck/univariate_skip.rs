@@ -0,0 +1,211 @@
+//! Univariate skip: the first `k` rounds collapsed into a single round
+//!
+//! The first `k` variables are replaced by one variable `Y` over the domain
+//! `H = {0, ..., 2^k - 1}`, where `Y = j` stands for the bits of `j` (`x_0` as the most
+//! significant bit). With `L_j` the Lagrange basis of `H`, the polynomial
+//! `ĝ(Y, x) = Σ_j L_j(Y) · g(bits(j), x)` sums over `H × {0, 1}^(n-k)` to the sum of `g` over
+//! the hypercube. The first round polynomial `s(Y) = Σ_x ĝ(Y, x)` has degree `< 2^k` and must
+//! sum over `H` to the claim. After the challenge `r`, the protocol continues with ordinary
+//! rounds for the restriction `g_r(x) = ĝ(r, x)` in the remaining `n - k` variables, and the
+//! final query takes `2^k` evaluations of `g`, folded into one of `g_r`.
+//!
+//! This saves `k - 1` rounds of interaction. Only the values of `g` at boolean points of the
+//! first `k` variables matter, so for `k = 1` and `g` multilinear in `x_0` the protocol is the
+//! ordinary one.
+use ark_ff::Field;
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::polynomial::{DenseMVPolynomial, Polynomial};
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::eval::{hypercube_evaluations, hypercube_sum};
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::{max_degrees, VerifierMsg, VerifierState},
+};
+use crate::poly_utils::{uni_poly_from_evaluations, validate_poly};
+use crate::MultiPoly;
+
+/// Prover State for the protocol with the first `k` rounds skipped
+#[derive(Clone, Debug)]
+pub struct SkipProverState<F: Field + From<i32>> {
+    /// Polynomial for which we want to prove a relation, in all of its variables
+    pub g: MultiPoly<F>,
+    /// Number of leading variables collapsed into the first round
+    pub k: usize,
+    /// Prover for the restriction of `g` by the skip challenge, once it is received
+    pub rest: Option<ProverState<F>>,
+    /// The current round number, counting the skipped block as one round
+    pub round: usize,
+    /// Sum of `g` over the boolean hypercube
+    pub asserted_sum: F,
+}
+
+impl<F: Field + From<i32>> SkipProverState<F> {
+    /// Number of rounds of the protocol, i.e. `1 + num_vars - k`.
+    pub fn num_rounds(&self) -> usize {
+        1 + self.g.num_vars - self.k
+    }
+}
+
+/// The domain `H = {0, ..., 2^k - 1}` of the skip variable.
+pub fn skip_domain<F: Field>(k: usize) -> Vec<F> {
+    (0..1_u64 << k).map(F::from).collect()
+}
+
+/// `L_j(r)` for every `j` of `skip_domain(k)`.
+fn lagrange_weights<F: Field>(k: usize, r: F) -> Vec<F> {
+    let domain = skip_domain::<F>(k);
+    domain
+        .iter()
+        .map(|j| {
+            let (numerator, denominator) = domain
+                .iter()
+                .filter(|i| *i != j)
+                .fold((F::one(), F::one()), |(num, den), i| (num * (r - i), den * (*j - i)));
+            numerator * denominator.inverse().unwrap()
+        })
+        .collect()
+}
+
+/// The restriction `g_r(x) = Σ_j L_j(r) · g(bits(j), x)` of `g` to the skip challenge `r`, in
+/// the last `num_vars - k` variables of `g`.
+pub fn restrict_skipped<F: Field>(g: &MultiPoly<F>, k: usize, r: F) -> MultiPoly<F> {
+    // On boolean points a term only depends on which of the first k variables it contains, so
+    // its weight is the sum of L_j(r) over every j whose bits include them.
+    let mut weights = lagrange_weights(k, r);
+    for bit in 0..k {
+        for mask in 0..weights.len() {
+            if mask & 1 << bit == 0 {
+                let high = weights[mask | 1 << bit];
+                weights[mask] += high;
+            }
+        }
+    }
+
+    let terms = g
+        .terms()
+        .iter()
+        .map(|(coeff, term)| {
+            let support = term
+                .iter()
+                .filter(|(var, _)| *var < k)
+                .fold(0_usize, |mask, (var, _)| mask | 1 << (k - 1 - var));
+            let rest = term.iter().filter(|(var, _)| *var >= k).map(|(var, power)| (var - k, *power));
+            (*coeff * weights[support], SparseTerm::new(rest.collect()))
+        })
+        .collect();
+
+    MultiPoly::from_coefficients_vec(g.num_vars - k, terms)
+}
+
+/// Panics unless `1 <= k <= num_vars`.
+fn check_skip(num_vars: usize, k: usize) {
+    if k == 0 || k > num_vars {
+        panic!("Number of skipped variables should be between 1 and the number of variables...");
+    }
+}
+
+impl<F: Field + From<i32>> IPForSumcheck<F> {
+    /// Initialize prover to argue for the sum of `g` over the boolean hypercube, with the first
+    /// `k` variables collapsed into the first round.
+    ///
+    /// Panics unless `1 <= k <= num_vars`.
+    pub fn prover_init_skip(polynomial: MultiPoly<F>, k: usize) -> Result<SkipProverState<F>, crate::Error> {
+        validate_poly(&polynomial)?;
+        check_skip(polynomial.num_vars, k);
+
+        Ok(SkipProverState {
+            asserted_sum: hypercube_sum(&polynomial),
+            g: polynomial,
+            k,
+            rest: None,
+            round: 0,
+        })
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    ///
+    /// The first message is `s(Y)`, of degree `< 2^k`; the verifier's answer to it is the skip
+    /// challenge, and every later message is that of an ordinary prover for the restriction.
+    pub fn prove_round_skip(
+        prover_state: &mut SkipProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        if prover_state.round >= prover_state.num_rounds() {
+            panic!("Prover is no longer active...");
+        }
+
+        let gi = match (prover_state.round, v_msg) {
+            (0, None) => {
+                // s(j) is the sum of g over the hypercube with its first k variables set to bits(j).
+                let evaluations = hypercube_evaluations(&prover_state.g);
+                let block = evaluations.len() >> prover_state.k;
+                let sums: Vec<F> = evaluations.chunks(block).map(|chunk| chunk.iter().sum()).collect();
+                uni_poly_from_evaluations(&sums)
+            }
+            (0, Some(_)) => panic!("Prover should go first..."),
+            (_, None) => panic!("Verifier message should not be empty..."),
+            (1, Some(msg)) => {
+                let restriction = restrict_skipped(&prover_state.g, prover_state.k, msg.randomness);
+                let rest = prover_state.rest.insert(
+                    Self::prover_init(restriction).expect("Restriction of a valid polynomial is valid..."),
+                );
+                Self::prove_round(rest, &None).gi
+            }
+            (_, Some(_)) => {
+                let rest = prover_state.rest.as_mut().unwrap();
+                Self::prove_round(rest, v_msg).gi
+            }
+        };
+
+        // Increment round
+        prover_state.round += 1;
+
+        ProverMsg::new(gi)
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Initializes the verifier for the protocol with the first `k` of `num_variables`
+    /// variables collapsed into the first round, i.e. with `1 + num_variables - k` rounds.
+    ///
+    /// The first round polynomial is summed over `skip_domain(k)`, every later one over
+    /// `{0, 1}`. Panics unless `1 <= k <= num_variables`.
+    pub fn verifier_init_skip(num_variables: usize, k: usize) -> VerifierState<F> {
+        check_skip(num_variables, k);
+
+        let boolean = vec![F::zero(), F::one()];
+        let domains = ark_std::iter::once(skip_domain(k))
+            .chain(ark_std::iter::repeat_n(boolean, num_variables - k))
+            .collect();
+        Self::verifier_init_with_domains(domains).expect("Skip domains are valid...")
+    }
+
+    /// Full verification of the protocol with the first `k` variables of `g` skipped.
+    ///
+    /// The first round polynomial is bounded by `2^k - 1` and every later one by the degree of
+    /// `g` in its variable. The final check compares the restriction of `g` by the first
+    /// challenge, evaluated at the remaining ones, with the claim the rounds reduced to.
+    pub fn verify_skip(
+        g: &MultiPoly<F>,
+        k: usize,
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        validate_poly(g)?;
+        check_skip(g.num_vars, k);
+        if verifier_state.num_vars != 1 + g.num_vars - k {
+            return Err(crate::Error::RoundMismatch {
+                expected: 1 + g.num_vars - k,
+                got: verifier_state.num_vars,
+            });
+        }
+
+        let degrees = max_degrees(g);
+        verifier_state.check_degrees(|i| if i == 0 { (1 << k) - 1 } else { degrees[k + i - 1] })?;
+
+        Self::partial_verify(verifier_state, asserted_sum)?
+            .verify_oracle_call(|r_vec| restrict_skipped(g, k, r_vec[0]).evaluate(&r_vec[1..].to_vec()))
+    }
+}
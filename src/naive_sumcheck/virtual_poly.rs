@@ -0,0 +1,263 @@
+//! Sumcheck over sums of products of multilinear polynomials
+//!
+//! A virtual polynomial `Σ_j c_j · Π_k f_{j,k}(x)` is never expanded into coefficient form:
+//! every factor is kept as its evaluation table, and each round polynomial is computed by
+//! evaluating the factors at `t = 0..=degree` and interpolating.
+//!
+//! The round polynomial may also be sent as these evaluations, which the verifier checks with
+//! `interpolate_uni_poly` without ever forming coefficients.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::ProverMsg,
+    verifier::{VerifierMsg, VerifierState},
+};
+use crate::poly_utils::{interpolate_uni_poly, uni_poly_from_evaluations};
+
+/// Sum of products of multilinear polynomials in the same variables
+#[derive(Clone, Debug)]
+pub struct VirtualPolynomial<F: Field> {
+    /// Number of variables shared by every factor
+    pub num_vars: usize,
+    /// List of `(coefficient, factors)` pairs
+    pub products: Vec<(F, Vec<DenseMLE<F>>)>,
+}
+
+/// Prover State for a virtual polynomial
+pub struct VirtualProverState<F: Field> {
+    /// Virtual polynomial with the variables fixed so far already folded into its factors
+    pub poly: VirtualPolynomial<F>,
+    /// randomness provided by the verifier
+    pub randomness: Vec<F>,
+    /// The current round number
+    pub round: usize,
+}
+
+impl<F: Field> VirtualPolynomial<F> {
+    /// An empty (i.e. zero) virtual polynomial in `num_vars` variables.
+    pub fn new(num_vars: usize) -> Self {
+        Self { num_vars, products: Vec::new() }
+    }
+
+    /// Adds `coefficient · Π factors` to the polynomial.
+    pub fn add_product(
+        &mut self,
+        coefficient: F,
+        factors: Vec<DenseMLE<F>>,
+    ) -> Result<(), crate::Error> {
+        if let Some(f) = factors.iter().find(|f| f.num_vars != self.num_vars) {
+            return Err(crate::Error::PolynomialError(format!(
+                "Factor has {} variables, expected {}.",
+                f.num_vars, self.num_vars,
+            )));
+        }
+
+        self.products.push((coefficient, factors));
+        Ok(())
+    }
+
+    /// Number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Degree bound of every round polynomial, i.e. the length of the longest product.
+    pub fn max_degree_per_round(&self) -> usize {
+        self.products.iter().map(|(_, factors)| factors.len()).max().unwrap_or(0)
+    }
+
+    /// Sum of the polynomial over the boolean hypercube.
+    pub fn sum_over_hypercube(&self) -> F {
+        (0..(1 << self.num_vars))
+            .map(|b| {
+                self.products
+                    .iter()
+                    .map(|(coeff, factors)| {
+                        factors.iter().fold(*coeff, |acc, f| acc * f.evaluations[b])
+                    })
+                    .sum::<F>()
+            })
+            .sum()
+    }
+
+    /// Evaluates the whole expression at `point`.
+    pub fn evaluate(&self, point: &[F]) -> Result<F, crate::Error> {
+        self.products.iter().try_fold(F::zero(), |sum, (coeff, factors)| {
+            let product = factors
+                .iter()
+                .try_fold(*coeff, |acc, f| Ok::<F, crate::Error>(acc * f.evaluate(point)?))?;
+            Ok(sum + product)
+        })
+    }
+
+    /// Fixes the first variable of every factor to `r`.
+    pub fn fix_first_variable(&self, r: F) -> Self {
+        Self {
+            num_vars: self.num_vars - 1,
+            products: self
+                .products
+                .iter()
+                .map(|(coeff, factors)| {
+                    (*coeff, factors.iter().map(|f| f.fix_first_variable(r)).collect())
+                })
+                .collect(),
+        }
+    }
+
+    /// Evaluations of the current round polynomial `Σ_b p(t, b)` at `t = 0..=degree`.
+    ///
+    /// Makes a single pass over the tables: at each suffix `b`, every factor is evaluated at
+    /// successive `t` by adding its slope `f(1, b) - f(0, b)`, so no multiplication by `t` and
+    /// no polynomial arithmetic is needed.
+    pub fn round_evaluations(&self, degree: usize) -> Vec<F> {
+        let half = 1 << (self.num_vars - 1);
+        let mut evals = vec![F::zero(); degree + 1];
+        let mut products = vec![F::zero(); degree + 1];
+        let mut values = vec![F::zero(); degree + 1];
+
+        for b in 0..half {
+            for (coeff, factors) in &self.products {
+                products.iter_mut().for_each(|p| *p = *coeff);
+                for f in factors {
+                    let (low, high) = (f.evaluations[b], f.evaluations[b + half]);
+                    let slope = high - low;
+                    values[0] = low;
+                    for t in 1..=degree {
+                        values[t] = values[t - 1] + slope;
+                    }
+                    products.iter_mut().zip(&values).for_each(|(p, v)| *p *= v);
+                }
+                evals.iter_mut().zip(&products).for_each(|(e, p)| *e += p);
+            }
+        }
+
+        evals
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Initialize prover to argue for the sum of virtual polynomial `poly` over the boolean hypercube.
+    ///
+    /// A `poly` without variables is valid and has no rounds, as in `prover_init`.
+    pub fn prover_init_virtual(poly: VirtualPolynomial<F>) -> VirtualProverState<F> {
+        VirtualProverState {
+            randomness: Vec::with_capacity(poly.num_vars),
+            poly,
+            round: 0,
+        }
+    }
+
+    /// Receive message from verifier, generate prover message, and proceed to next round.
+    ///
+    /// The current factors are folded with the verifier's challenge before the round
+    /// polynomial is computed from its evaluations at `t = 0..=max_degree_per_round`, see
+    /// `prove_round_virtual_evaluations`.
+    pub fn prove_round_virtual(
+        prover_state: &mut VirtualProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        ProverMsg::new(uni_poly_from_evaluations(&Self::prove_round_virtual_evaluations(
+            prover_state,
+            v_msg,
+        )))
+    }
+
+    /// Same as `prove_round_virtual`, but returns the round polynomial as its evaluations at
+    /// `t = 0..=max_degree_per_round`, for `verify_virtual_evaluations`.
+    pub fn prove_round_virtual_evaluations(
+        prover_state: &mut VirtualProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> Vec<F> {
+        if prover_state.round >= prover_state.poly.num_vars + prover_state.randomness.len() {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if prover_state.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            prover_state.randomness.push(msg.randomness);
+            prover_state.poly = prover_state.poly.fix_first_variable(msg.randomness);
+        } else if prover_state.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        let degree = prover_state.poly.max_degree_per_round();
+        let evals = prover_state.poly.round_evaluations(degree);
+
+        // Increment round
+        prover_state.round += 1;
+
+        evals
+    }
+
+    /// Full verification for a virtual polynomial.
+    ///
+    /// Only the per-round degree bound and a final evaluation of the whole expression at
+    /// `r_vec` are needed on top of the usual consistency checks.
+    pub fn verify_virtual(
+        poly: &VirtualPolynomial<F>,
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        let degree = poly.max_degree_per_round();
+        verifier_state.check_degrees(|_| degree)?;
+
+        let v_out = Self::partial_verify(verifier_state, asserted_sum)?;
+        let poly_eval = poly.evaluate(&v_out.r_vec)?;
+        if poly_eval == v_out.expected_evaluation {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, poly_eval))
+        }
+    }
+
+    /// Full verification for a virtual polynomial, given every round polynomial as its
+    /// evaluations at `t = 0, 1, ...` and the challenge drawn after each.
+    ///
+    /// Runs the same checks as `verify_virtual`: a round may have at most
+    /// `max_degree_per_round + 1` evaluations, must sum to the claim at `t = 0, 1` and reduces
+    /// it to `interpolate_uni_poly(evals, r)`.
+    pub fn verify_virtual_evaluations(
+        poly: &VirtualPolynomial<F>,
+        round_evals: &[Vec<F>],
+        randomness: &[F],
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        if round_evals.len() != poly.num_vars || randomness.len() != poly.num_vars {
+            return Err(crate::Error::RoundMismatch {
+                expected: poly.num_vars,
+                got: round_evals.len().min(randomness.len()),
+            });
+        }
+
+        let degree = poly.max_degree_per_round();
+        let mut expected_sum = asserted_sum;
+        for (i, (evals, r)) in round_evals.iter().zip(randomness).enumerate() {
+            if evals.len() > degree + 1 {
+                return Err(crate::Error::DegreeTooHigh {
+                    round: i + 1,
+                    got: evals.len() - 1,
+                    bound: degree,
+                });
+            }
+
+            let sum = interpolate_uni_poly(evals, F::zero()) + interpolate_uni_poly(evals, F::one());
+            if sum != expected_sum {
+                return Err(crate::Error::sum_mismatch(i + 1, expected_sum, sum));
+            }
+            expected_sum = interpolate_uni_poly(evals, *r);
+        }
+
+        let poly_eval = poly.evaluate(randomness)?;
+        if poly_eval == expected_sum {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(expected_sum, poly_eval))
+        }
+    }
+}
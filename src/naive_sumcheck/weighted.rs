@@ -0,0 +1,127 @@
+//! Weighted sumcheck of `Σ_x w(x) · g(x)` for a public weight `w`
+//!
+//! Expanding `w · g` into a single sparse polynomial multiplies the term counts of the factors.
+//! Instead, both factors keep their own prover state, and each round multiplies the univariate
+//! restrictions `g(r, X, b)` and `w(r, X, b)` pointwise at every boolean suffix `b`, so the round
+//! polynomial in `x_i` has degree at most `deg_i(g) + deg_i(w)`. Since `w` is public, the
+//! verifier evaluates it at the random point itself.
+use ark_ff::{Field, Zero};
+use ark_poly::polynomial::{DenseMVPolynomial, Polynomial};
+
+use crate::naive_sumcheck::eval::hypercube_evaluations;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{to_binary_vec, ProverMsg, ProverState},
+    verifier::{max_degrees, VerifierMsg, VerifierState},
+};
+use crate::{MultiPoly, UniPoly};
+
+/// Prover State for the weighted sum of `g` by `w`
+#[derive(Clone, Debug)]
+pub struct WeightedProverState<F: Field + From<i32>> {
+    /// Prover state for `g`, which also tracks the verifier's randomness
+    pub g: ProverState<F>,
+    /// Prover state for the weight `w`, fixed to the same randomness
+    pub w: ProverState<F>,
+    /// `Σ_x w(x) · g(x)` over the boolean hypercube, computed once by `prover_init_weighted`
+    pub asserted_sum: F,
+}
+
+/// Fails unless the weight `w` is in the same variables as `g`.
+fn check_num_vars<F: Field>(g: &MultiPoly<F>, w: &MultiPoly<F>) -> Result<(), crate::Error> {
+    if g.num_vars != w.num_vars {
+        return Err(crate::Error::PolynomialError(format!(
+            "Weight has {} variables, expected {}.",
+            w.num_vars, g.num_vars,
+        )));
+    }
+
+    Ok(())
+}
+
+impl<F: Field + From<i32>> WeightedProverState<F> {
+    /// The weighted sum this prover argues for.
+    pub fn asserted_sum(&self) -> F {
+        self.asserted_sum
+    }
+}
+
+impl<F: Field + From<i32>> IPForSumcheck<F> {
+    /// Initialize prover to argue for the sum of `w · g` over the boolean hypercube.
+    ///
+    /// Fails if `g` and `w` are not in the same number of variables.
+    pub fn prover_init_weighted(
+        g: MultiPoly<F>,
+        w: MultiPoly<F>,
+    ) -> Result<WeightedProverState<F>, crate::Error> {
+        check_num_vars(&g, &w)?;
+        let asserted_sum = hypercube_evaluations(&g)
+            .into_iter()
+            .zip(hypercube_evaluations(&w))
+            .map(|(g_b, w_b)| g_b * w_b)
+            .sum();
+
+        Ok(WeightedProverState {
+            g: Self::prover_init(g)?,
+            w: Self::prover_init(w)?,
+            asserted_sum,
+        })
+    }
+
+    /// Receive message from verifier, generate prover message for `w · g`, and proceed to next
+    /// round in both factors.
+    pub fn prove_weighted_round(
+        prover_state: &mut WeightedProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        let (g, w) = (&mut prover_state.g, &mut prover_state.w);
+        if g.round >= g.g.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if g.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            g.randomness.push(msg.randomness);
+            w.randomness.push(msg.randomness);
+        } else if g.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        // remaining number of "non-fixed" variables
+        let v = g.g.num_vars() - g.randomness.len();
+
+        // Σ_b g(r, X, b) · w(r, X, b), where the leading bit of each point is a placeholder for X
+        let gi = (0..(1_usize << (v - 1))).fold(UniPoly::<F>::zero(), |sum, b| {
+            let points = to_binary_vec::<F>(b, v);
+            sum + g.evaluate_gi(points.clone()).mul(&w.evaluate_gi(points))
+        });
+
+        // Increment round
+        g.round += 1;
+        w.round += 1;
+
+        ProverMsg::new(gi)
+    }
+
+    /// Full verification for the sum of `w · g`.
+    ///
+    /// Every round polynomial in `x_i` has degree at most `deg_i(g) + deg_i(w)`, and the final
+    /// check evaluates both `g` and the public `w` at `r_vec`. Fails if `g` and `w` are not in
+    /// the same number of variables.
+    pub fn verify_weighted(
+        g: &MultiPoly<F>,
+        w: &MultiPoly<F>,
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+    ) -> Result<(), crate::Error> {
+        check_num_vars(g, w)?;
+        let (g_degrees, w_degrees) = (max_degrees(g), max_degrees(w));
+        verifier_state.check_degrees(|i| g_degrees[i] + w_degrees[i])?;
+
+        Self::partial_verify(verifier_state, asserted_sum)?
+            .verify_oracle_call(|r_vec| g.evaluate(&r_vec.to_vec()) * w.evaluate(&r_vec.to_vec()))
+    }
+}
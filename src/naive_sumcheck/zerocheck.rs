@@ -0,0 +1,258 @@
+//! ZeroCheck protocol built on top of the Sumcheck protocol
+//!
+//! Proving that `f` vanishes on the whole boolean hypercube reduces to a sumcheck of
+//! `f(x)·eq(x, τ)` with asserted sum zero, for a verifier-chosen point `τ`. The product
+//! is never expanded: the prover keeps `eq` implicit and only ever evaluates `f`.
+//!
+//! After the first `j` challenges, `eq(r, X, b; τ) = Π_{i < j} eq(r_i, τ_i) · eq(X, τ_j) ·
+//! eq(b, τ_{j+1..})`, where the leading scalar and the linear factor are known to the verifier.
+//! With `prove_round_split` the prover sends only `Σ_b eq(b, τ_{j+1..}) · f(r, X, b)`, of degree
+//! `deg_j(f)` instead of `deg_j(f) + 1`, and `verify_round_split` multiplies the two factors
+//! back in.
+//!
+//! The weights `eq(b, τ_{j+1..})` come from two tables built once by `prove`, for the halves
+//! `τ_{1..s}` and `τ_{s..}` of the point, so that a weight is the product of one entry of each
+//! and neither table has more than `2^(v/2)` entries. Every round sums the leading variable
+//! out of the table that still contains it, instead of rebuilding the weights.
+use ark_ff::{Field, Zero};
+use ark_poly::polynomial::{DenseMVPolynomial, Polynomial};
+use ark_std::{marker::PhantomData, rand::RngCore, vec::Vec};
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{to_binary_vec, ProverMsg, ProverState},
+    verifier::{max_degrees, VerifierMsg, VerifierState},
+};
+use crate::poly_utils::{build_eq_x_r_evals, eq_1, eq_eval};
+use crate::transcript::SumcheckTranscript;
+use crate::{MultiPoly, UniPoly};
+
+/// ZeroCheck protocol for polynomials over the field `F`
+pub struct ZeroCheck<F: Field> {
+    _marker: PhantomData<F>,   // cache field F
+}
+
+/// Prover State for the ZeroCheck protocol
+pub struct ZeroCheckProverState<F: Field + From<i32>> {
+    /// Prover state for `f`, which also tracks the verifier's randomness
+    pub inner: ProverState<F>,
+    /// The verifier-chosen point `τ`
+    pub tau: Vec<F>,
+    /// `eq` restricted to the variables fixed so far, i.e. `Π_{j < i} eq(r_j, τ_j)`
+    pub eq_prefix: F,
+    /// Evaluations of `eq(·, τ_{j+1..s})` in round `j`, for the split point `s` of `τ`
+    pub eq_high: Vec<F>,
+    /// Evaluations of `eq(·, τ_{max(j+1, s)..})` in round `j`
+    pub eq_low: Vec<F>,
+}
+
+/// Verifier State for the ZeroCheck protocol with split round messages
+pub struct ZeroCheckVerifierState<F: Field> {
+    /// Sumcheck verifier state for `f·eq(·, τ)`, fed with the reconstructed round polynomials
+    pub inner: VerifierState<F>,
+    /// The verifier-chosen point `τ`
+    pub tau: Vec<F>,
+    /// `eq` restricted to the variables fixed so far, i.e. `Π_{j < i} eq(r_j, τ_j)`
+    pub eq_prefix: F,
+    /// Degree of `f` in every variable, which bounds the split round polynomials
+    pub degree_bounds: Vec<usize>,
+}
+
+/// The table of `eq(·, τ_{i+1..})` from the table of `eq(·, τ_{i..})`, both indexed with the
+/// first variable as the most significant bit: the two entries that differ in the leading bit
+/// carry `1 - τ_i` and `τ_i`, which add up to 1.
+fn sum_out_leading_variable<F: Field>(table: &[F]) -> Vec<F> {
+    let (zero, one) = table.split_at(table.len() / 2);
+    zero.iter().zip(one).map(|(a, b)| *a + b).collect()
+}
+
+/// `eq(X, τ_j) = (2τ_j - 1)·X + (1 - τ_j)`
+fn eq_factor<F: Field>(tau_j: F) -> UniPoly<F> {
+    UniPoly::from_coefficients_vec(
+        [(0, F::one() - tau_j), (1, tau_j.double() - F::one())]
+            .into_iter()
+            .filter(|(_, c)| !c.is_zero())
+            .collect(),
+    )
+}
+
+impl<F: Field + From<i32>> ZeroCheck<F> {
+    /// Verifier samples the point `τ` that binds the zerocheck to a single sumcheck instance.
+    ///
+    pub fn sample_tau<R: RngCore>(num_vars: usize, rng: &mut R) -> Vec<F> {
+        (0..num_vars).map(|_| F::rand(rng)).collect()
+    }
+
+    /// Initialize prover to argue that `f` vanishes on the boolean hypercube, given the verifier's `τ`.
+    ///
+    pub fn prove(f: &MultiPoly<F>, tau: &[F]) -> Result<ZeroCheckProverState<F>, crate::Error> {
+        if tau.len() != f.num_vars() {
+            return Err(crate::Error::RoundMismatch { expected: f.num_vars(), got: tau.len() });
+        }
+
+        // Round 0 sums over the variables after x_0, split into halves of (almost) equal size.
+        let (eq_high, eq_low) = match tau.len() {
+            0 => (vec![F::one()], vec![F::one()]),
+            n => {
+                let split = n.div_ceil(2);
+                (build_eq_x_r_evals(&tau[1..split]), build_eq_x_r_evals(&tau[split..]))
+            }
+        };
+
+        Ok(ZeroCheckProverState {
+            inner: IPForSumcheck::prover_init(f.clone())?,
+            tau: tau.to_vec(),
+            eq_prefix: F::one(),
+            eq_high,
+            eq_low,
+        })
+    }
+
+    /// Receive message from verifier, generate prover message for `f·eq(·, τ)`, and proceed to next round.
+    ///
+    pub fn prove_round(
+        prover_state: &mut ZeroCheckProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        let (partial, tau_j) = Self::partial_round(prover_state, v_msg);
+
+        ProverMsg::new(&partial.mul(&eq_factor(tau_j)) * prover_state.eq_prefix)
+    }
+
+    /// Same as `prove_round`, but leaves out the factor `eq_prefix · eq(X, τ_j)`, which
+    /// `verify_round_split` multiplies back in.
+    pub fn prove_round_split(
+        prover_state: &mut ZeroCheckProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> ProverMsg<F> {
+        ProverMsg::new(Self::partial_round(prover_state, v_msg).0)
+    }
+
+    /// Proceeds to the next round and returns `Σ_b eq(b, τ_{j+1..}) · f(r, X, b)` along with `τ_j`.
+    fn partial_round(
+        prover_state: &mut ZeroCheckProverState<F>,
+        v_msg: &Option<VerifierMsg<F>>,
+    ) -> (UniPoly<F>, F) {
+        let state = &mut prover_state.inner;
+        if state.round >= state.g.num_vars {
+            panic!("Prover is no longer active...");
+        }
+
+        if let Some(msg) = v_msg {
+            if state.round == 0 {
+                panic!("Prover should go first...");
+            }
+
+            let j = state.randomness.len();
+            prover_state.eq_prefix *= eq_1(msg.randomness, prover_state.tau[j]);
+            state.randomness.push(msg.randomness);
+
+            // τ_{j+1} moves from the weights into eq(X, τ_{j+1}).
+            if prover_state.eq_high.len() > 1 {
+                prover_state.eq_high = sum_out_leading_variable(&prover_state.eq_high);
+            } else {
+                prover_state.eq_low = sum_out_leading_variable(&prover_state.eq_low);
+            }
+        } else if state.round > 0 {
+            panic!("Verifier message should not be empty...");
+        }
+
+        // remaining number of "non-fixed" variables
+        let j = state.randomness.len();
+        let v = state.g.num_vars() - j;
+
+        // Σ_b eq(b, τ_{j+1..}) · f(r, X, b), where the leading bit of each point is a placeholder
+        // for X and eq(b, τ_{j+1..}) = eq_high[b_high] · eq_low[b_low].
+        let (eq_high, eq_low) = (&prover_state.eq_high, &prover_state.eq_low);
+        let partial = eq_high.iter().enumerate().fold(UniPoly::<F>::zero(), |sum, (high, high_weight)| {
+            let inner = eq_low.iter().enumerate().fold(UniPoly::<F>::zero(), |inner, (low, weight)| {
+                let point = to_binary_vec::<F>(high * eq_low.len() + low, v);
+                inner + &state.evaluate_gi(point) * *weight
+            });
+            sum + &inner * *high_weight
+        });
+
+        // Increment round
+        state.round += 1;
+
+        (partial, prover_state.tau[j])
+    }
+
+    /// Full verification of the ZeroCheck protocol.
+    ///
+    /// Runs the sumcheck checks for `f·eq(·, τ)` with asserted sum zero, then
+    /// queries `f` once at the random point `r_vec`.
+    pub fn verify(
+        f: &MultiPoly<F>,
+        tau: &[F],
+        verifier_state: VerifierState<F>,
+    ) -> Result<(), crate::Error> {
+        // Multiplying by eq raises each variable's degree by one.
+        let degrees = max_degrees(f);
+
+        verifier_state.check_degrees(|i| degrees[i] + 1)?;
+
+        let v_out = IPForSumcheck::partial_verify(verifier_state, F::zero())?;
+        let oracle_eval = f.evaluate(&v_out.r_vec) * eq_eval(&v_out.r_vec, tau)?;
+        if oracle_eval == v_out.expected_evaluation {
+            Ok(())
+        } else {
+            Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, oracle_eval))
+        }
+    }
+
+    /// Initialize verifier for split round messages on `f`, given the point `τ`.
+    ///
+    pub fn verifier_init_split(
+        f: &MultiPoly<F>,
+        tau: &[F],
+    ) -> Result<ZeroCheckVerifierState<F>, crate::Error> {
+        if tau.len() != f.num_vars() {
+            return Err(crate::Error::RoundMismatch { expected: f.num_vars(), got: tau.len() });
+        }
+
+        Ok(ZeroCheckVerifierState {
+            inner: IPForSumcheck::verifier_init(f.num_vars()),
+            tau: tau.to_vec(),
+            eq_prefix: F::one(),
+            degree_bounds: max_degrees(f),
+        })
+    }
+
+    /// Run verifier at current round, given a split message from `prove_round_split`.
+    ///
+    /// The message must have degree at most `deg_j(f)`. It is multiplied by `eq(X, τ_j)` and
+    /// the accumulated scalar, and the result goes through `IPForSumcheck::verify_round`, so the
+    /// challenges are the ones `prove_round` would have been answered with.
+    pub fn verify_round_split<T: SumcheckTranscript<F>>(
+        prover_msg: ProverMsg<F>,
+        verifier_state: &mut ZeroCheckVerifierState<F>,
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error> {
+        let j = verifier_state.inner.challenges().len();
+        if let Some(bound) = verifier_state.degree_bounds.get(j) {
+            if prover_msg.degree() > *bound {
+                return Err(crate::Error::DegreeTooHigh {
+                    round: j + 1,
+                    got: prover_msg.degree(),
+                    bound: *bound,
+                });
+            }
+        }
+
+        let tau_j = verifier_state.tau.get(j).copied().unwrap_or_else(F::one);
+        let gi = &prover_msg.gi.mul(&eq_factor(tau_j)) * verifier_state.eq_prefix;
+        let v_msg = IPForSumcheck::verify_round(ProverMsg::new(gi), &mut verifier_state.inner, transcript)?;
+        verifier_state.eq_prefix *= eq_1(v_msg.randomness, tau_j);
+
+        Ok(v_msg)
+    }
+
+    /// Full verification of the ZeroCheck protocol with split round messages, see `verify`.
+    pub fn verify_split(
+        f: &MultiPoly<F>,
+        verifier_state: ZeroCheckVerifierState<F>,
+    ) -> Result<(), crate::Error> {
+        Self::verify(f, &verifier_state.tau, verifier_state.inner)
+    }
+}
@@ -0,0 +1,606 @@
+//! Utilities for constructing and manipulating polynomials
+use ark_ff::{Field, PrimeField};
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::{DenseMVPolynomial, Polynomial};
+use ark_std::{string::{String, ToString}, vec::Vec};
+
+use crate::naive_sumcheck::protocol::prover::to_binary_vec;
+use crate::{MultiPoly, UniPoly};
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Builder for `MultiPoly<F>` that checks variable indices before handing them to ark-poly
+///
+/// ```
+/// # use ark_bls12_381::Fr;
+/// # use sumcheck::poly_utils::PolynomialBuilder;
+/// // 2·x0^3 + x0·x2 + x1·x2
+/// let g = PolynomialBuilder::<Fr>::new()
+///     .num_vars(3)
+///     .add_term(2.into(), &[(0, 3)])
+///     .add_term(1.into(), &[(0, 1), (2, 1)])
+///     .add_term(1.into(), &[(1, 1), (2, 1)])
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PolynomialBuilder<F: Field> {
+    /// Number of variables, which must be set before `build`
+    num_vars: Option<usize>,
+    /// Terms added so far, as `(coefficient, [(variable, power)])`
+    terms: Vec<(F, Vec<(usize, usize)>)>,
+}
+
+impl<F: Field> PolynomialBuilder<F> {
+    /// An empty builder.
+    pub fn new() -> Self {
+        Self { num_vars: None, terms: Vec::new() }
+    }
+
+    /// Sets the number of variables of the polynomial.
+    pub fn num_vars(mut self, num_vars: usize) -> Self {
+        self.num_vars = Some(num_vars);
+        self
+    }
+
+    /// Adds `coefficient · Π x_var^power` to the polynomial.
+    pub fn add_term(mut self, coefficient: F, vars_and_powers: &[(usize, usize)]) -> Self {
+        self.terms.push((coefficient, vars_and_powers.to_vec()));
+        self
+    }
+
+    /// Builds the polynomial, merging duplicate monomials.
+    ///
+    /// Fails if `num_vars` was never set or if any term refers to a variable index `>= num_vars`,
+    /// which ark-poly would otherwise only catch with a panic during evaluation.
+    pub fn build(self) -> Result<MultiPoly<F>, crate::Error> {
+        let num_vars = self.num_vars.ok_or_else(|| {
+            crate::Error::PolynomialError("Number of variables must be set before building.".into())
+        })?;
+
+        if let Some((_, vars)) = self
+            .terms
+            .iter()
+            .find(|(_, vars)| vars.iter().any(|(var, _)| *var >= num_vars))
+        {
+            return Err(crate::Error::PolynomialError(format!(
+                "Term {vars:?} refers to a variable outside of 0..{num_vars}."
+            )));
+        }
+
+        Ok(MultiPoly::from_coefficients_vec(
+            num_vars,
+            self.terms
+                .into_iter()
+                .map(|(coeff, vars)| (coeff, SparseTerm::new(vars)))
+                .collect(),
+        ))
+    }
+}
+
+/// Builds a `MultiPoly` from `(coefficient, [(variable, power), ...])` terms, through
+/// `PolynomialBuilder`.
+///
+/// Evaluates to a `Result`, which is an error if a term refers to a variable index `>= vars`.
+/// Coefficients are converted with `From`, and duplicate monomials are merged.
+///
+/// ```
+/// # use ark_bls12_381::Fr;
+/// # use sumcheck::poly;
+/// // 2·x0^3 + x0·x2 + x1·x2
+/// let g = poly!(Fr; vars = 3; (2, [(0, 3)]), (1, [(0, 1), (2, 1)]), (1, [(1, 1), (2, 1)])).unwrap();
+/// assert_eq!(g.terms.len(), 3);
+///
+/// assert!(poly!(Fr; vars = 2; (1, [(2, 1)])).is_err());
+/// ```
+#[macro_export]
+macro_rules! poly {
+    ($field:ty; vars = $num_vars:expr; $(($coeff:expr, [$(($var:expr, $power:expr)),* $(,)?])),* $(,)?) => {
+        $crate::poly_utils::PolynomialBuilder::<$field>::new()
+            .num_vars($num_vars)
+            $(.add_term(<$field>::from($coeff), &[$(($var, $power)),*]))*
+            .build()
+    };
+}
+
+/// The term `coeff · Π x_var^power`, mapping a negative `coeff` to the negation of its absolute
+/// value in `F`.
+pub fn term<F: Field>(coeff: i64, vars: &[(usize, usize)]) -> (F, SparseTerm) {
+    (signed_to_field(coeff), SparseTerm::new(vars.to_vec()))
+}
+
+/// Builds the polynomial in `num_vars` variables with signed integer coefficients `terms`,
+/// through `PolynomialBuilder`, so that variable indices are checked and monomials merged.
+pub fn poly_from_signed<F: Field>(
+    num_vars: usize,
+    terms: &[(i64, &[(usize, usize)])],
+) -> Result<MultiPoly<F>, crate::Error> {
+    terms
+        .iter()
+        .fold(PolynomialBuilder::new().num_vars(num_vars), |builder, (coeff, vars)| {
+            builder.add_term(signed_to_field(*coeff), vars)
+        })
+        .build()
+}
+
+/// `x` as an element of `F`.
+fn signed_to_field<F: Field>(x: i64) -> F {
+    let magnitude = F::from(x.unsigned_abs());
+    if x < 0 { -magnitude } else { magnitude }
+}
+
+/// Multiplies two sparse polynomials in the same variables, term by term.
+pub fn multiply<F: Field>(a: &MultiPoly<F>, b: &MultiPoly<F>) -> MultiPoly<F> {
+    let mut terms = Vec::with_capacity(a.terms().len() * b.terms().len());
+    for (ca, ta) in a.terms() {
+        for (cb, tb) in b.terms() {
+            let vars = ta.iter().chain(tb.iter()).cloned().collect();
+            terms.push((*ca * cb, SparseTerm::new(vars)));
+        }
+    }
+
+    MultiPoly::from_coefficients_vec(a.num_vars().max(b.num_vars()), terms)
+}
+
+/// Total degree of `g`, i.e. the largest sum of powers within a single term.
+///
+/// The sum saturates instead of overflowing.
+pub fn polynomial_total_degree<F: Field>(g: &MultiPoly<F>) -> usize {
+    g.terms()
+        .iter()
+        .map(|(_, term)| term.iter().fold(0_usize, |degree, (_, power)| degree.saturating_add(*power)))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Whether every variable of `g` appears with power at most 1 in every term.
+pub fn is_multilinear<F: Field>(g: &MultiPoly<F>) -> bool {
+    g.terms().iter().all(|(_, term)| term.iter().all(|(_, power)| *power <= 1))
+}
+
+/// Same as `is_multilinear`, failing with the first term of higher degree in some variable.
+pub fn assert_multilinear<F: Field>(g: &MultiPoly<F>) -> Result<(), crate::Error> {
+    match g.terms().iter().find(|(_, term)| term.iter().any(|(_, power)| *power > 1)) {
+        Some((_, term)) => Err(crate::Error::PolynomialError(format!(
+            "Polynomial is not multilinear: term {:?} has a variable of degree above 1.",
+            &**term
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `g` is in the canonical form produced by `from_coefficients_vec`.
+///
+/// Fails, naming the offending term, if a term refers to a variable index `>= num_vars`, is not
+/// in the form of `SparseTerm::new` (variables strictly ascending, i.e. sorted and without
+/// repetition, and every power nonzero), has a zero coefficient, or repeats the monomial of
+/// another term. Such polynomials can only be built by filling in `MultiPoly`'s fields directly
+/// or by decoding untrusted bytes, and are rejected rather than normalized so that the prover
+/// and verifier never disagree on what `g` is; callers that want the merge must ask for it with
+/// `normalize_polynomial`.
+pub fn validate_poly<F: Field>(g: &MultiPoly<F>) -> Result<(), crate::Error> {
+    let invalid = |term: &SparseTerm, what: &str| {
+        crate::Error::PolynomialError(format!("Term {:?} {what}.", &**term))
+    };
+
+    for (coeff, term) in g.terms() {
+        if let Some((var, _)) = term.iter().find(|(var, _)| *var >= g.num_vars) {
+            return Err(invalid(term, &format!("refers to x{var}, outside of 0..{}", g.num_vars)));
+        }
+        if let Some((var, _)) = term.iter().find(|(_, power)| *power == 0) {
+            return Err(invalid(term, &format!("has x{var} to the power 0")));
+        }
+        if let Some(pair) = term.windows(2).find(|pair| pair[0].0 >= pair[1].0) {
+            let what = if pair[0].0 == pair[1].0 { "repeats a variable" } else { "is not sorted by variable" };
+            return Err(invalid(term, what));
+        }
+        if coeff.is_zero() {
+            return Err(invalid(term, "has a zero coefficient"));
+        }
+    }
+
+    let mut monomials: Vec<&SparseTerm> = g.terms().iter().map(|(_, term)| term).collect();
+    monomials.sort();
+    match monomials.windows(2).find(|pair| pair[0] == pair[1]) {
+        Some(pair) => Err(invalid(pair[0], "appears more than once")),
+        None => Ok(()),
+    }
+}
+
+/// Brings `g` into canonical form, rebuilding every term with `SparseTerm::new`, merging terms
+/// with the same monomial by summing their coefficients and dropping the terms whose
+/// coefficient is then zero.
+///
+/// Terms that refer to a variable index `>= num_vars` are kept, and still fail `validate_poly`.
+pub fn normalize_polynomial<F: Field>(g: MultiPoly<F>) -> MultiPoly<F> {
+    let terms = g.terms.into_iter().map(|(coeff, term)| (coeff, SparseTerm::new(term.to_vec()))).collect();
+    MultiPoly::from_coefficients_vec(g.num_vars, terms)
+}
+
+/// Brings `p` into canonical form: coefficients sorted by strictly ascending degree, those of
+/// the same degree summed, and zero coefficients dropped.
+pub fn normalize_uni_poly<F: Field>(p: UniPoly<F>) -> UniPoly<F> {
+    if is_canonical_uni_poly(&p) {
+        return p;
+    }
+
+    let mut coeffs: Vec<(usize, F)> = p.iter().copied().collect();
+    coeffs.sort_by_key(|(degree, _)| *degree);
+    let mut merged: Vec<(usize, F)> = Vec::with_capacity(coeffs.len());
+    for (degree, coeff) in coeffs {
+        match merged.last_mut() {
+            Some((last, sum)) if *last == degree => *sum += coeff,
+            _ => merged.push((degree, coeff)),
+        }
+    }
+    merged.retain(|(_, c)| !c.is_zero());
+
+    UniPoly::from_coefficients_vec(merged)
+}
+
+/// Whether `p` is in the canonical form of `normalize_uni_poly`.
+pub fn is_canonical_uni_poly<F: Field>(p: &UniPoly<F>) -> bool {
+    p.iter().all(|(_, c)| !c.is_zero()) && p.windows(2).all(|pair| pair[0].0 < pair[1].0)
+}
+
+/// The multilinear polynomial agreeing with `g` on `{domain.0, domain.1}^num_vars`, e.g. on the
+/// boolean hypercube for `domain = (0, 1)`.
+///
+/// Every factor `x_i^k` with `k > 1` is replaced by the line through `(a, a^k)` and `(b, b^k)`,
+/// which for the boolean hypercube is the identity `x^k = x`. A term with `m` such factors
+/// expands into `2^m` terms. Panics unless the two domain points are distinct.
+pub fn truncate_to_multilinear<F: Field>(g: &MultiPoly<F>, domain: (F, F)) -> MultiPoly<F> {
+    let (a, b) = domain;
+    let inv_width = (b - a).inverse().expect("Domain points should be distinct...");
+
+    let mut terms = Vec::with_capacity(g.terms.len());
+    for (coeff, term) in &g.terms {
+        // Partial products as (coefficient, linear variables), one factor at a time.
+        let mut partial = vec![(*coeff, Vec::new())];
+        for &(var, power) in term.iter() {
+            if power == 1 {
+                partial.iter_mut().for_each(|(_, vars)| vars.push((var, 1)));
+                continue;
+            }
+
+            // x^k ↦ intercept + slope · x on {a, b}.
+            let (a_k, b_k) = (a.pow([power as u64]), b.pow([power as u64]));
+            let slope = (b_k - a_k) * inv_width;
+            let intercept = a_k - slope * a;
+            partial = partial
+                .into_iter()
+                .flat_map(|(c, vars)| {
+                    let mut with_var = vars.clone();
+                    with_var.push((var, 1));
+                    [(c * intercept, vars), (c * slope, with_var)]
+                })
+                .collect();
+        }
+        terms.extend(partial.into_iter().map(|(c, vars)| (c, SparseTerm::new(vars))));
+    }
+
+    MultiPoly::from_coefficients_vec(g.num_vars, terms)
+}
+
+/// Evaluates `g` at the point `x(t) = a + t·(b - a)` of the line through `a` (at `t = 0`) and
+/// `b` (at `t = 1`). Panics unless `a` and `b` have one coordinate per variable of `g`.
+pub fn evaluate_along_line<F: Field>(g: &MultiPoly<F>, a: &[F], b: &[F], t: F) -> F {
+    assert!(
+        a.len() == g.num_vars && b.len() == g.num_vars,
+        "Points should have one coordinate per variable..."
+    );
+
+    let point: Vec<F> = a.iter().zip(b).map(|(ai, bi)| *ai + t * (*bi - ai)).collect();
+    g.evaluate(&point)
+}
+
+/// The restriction `t ↦ g(a + t·(b - a))` of `g` to the line through `a` and `b`, interpolated
+/// from `evaluate_along_line` at `t = 0, ..., deg(g)`.
+pub fn to_univariate_at<F: Field>(g: &MultiPoly<F>, a: &[F], b: &[F]) -> UniPoly<F> {
+    let evals: Vec<F> = (0..=polynomial_total_degree(g))
+        .map(|t| evaluate_along_line(g, a, b, F::from(t as u64)))
+        .collect();
+
+    uni_poly_from_evaluations(&evals)
+}
+
+/// Evaluates the one-variable equality polynomial `x·y + (1 - x)·(1 - y)`.
+#[inline]
+pub(crate) fn eq_1<F: Field>(x: F, y: F) -> F {
+    x * y + (F::one() - x) * (F::one() - y)
+}
+
+/// Builds the multilinear equality polynomial
+/// `eq(x, r) = Π_i (x_i·r_i + (1 - x_i)·(1 - r_i))` in sparse coefficient form.
+pub fn build_eq_x_r<F: Field>(r: &[F]) -> MultiPoly<F> {
+    // Expand Π_i ((2r_i - 1)·x_i + (1 - r_i)) one factor at a time.
+    let mut terms: Vec<(F, Vec<(usize, usize)>)> = vec![(F::one(), vec![])];
+
+    for (i, ri) in r.iter().enumerate() {
+        let linear = ri.double() - F::one();
+        let constant = F::one() - ri;
+
+        terms = terms
+            .into_iter()
+            .flat_map(|(coeff, vars)| {
+                let mut with_xi = vars.clone();
+                with_xi.push((i, 1));
+                [(coeff * constant, vars), (coeff * linear, with_xi)]
+            })
+            .collect();
+    }
+
+    MultiPoly::from_coefficients_vec(
+        r.len(),
+        terms
+            .into_iter()
+            .map(|(coeff, vars)| (coeff, SparseTerm::new(vars)))
+            .collect(),
+    )
+}
+
+/// Evaluates `eq(x, r)` directly, in `O(n)` field operations.
+pub fn eq_eval<F: Field>(x: &[F], r: &[F]) -> Result<F, crate::Error> {
+    if x.len() != r.len() {
+        return Err(crate::Error::PolynomialError(format!(
+            "Cannot evaluate eq on points of different lengths ({} and {}).",
+            x.len(),
+            r.len(),
+        )));
+    }
+
+    Ok(x.iter().zip(r).map(|(xi, ri)| eq_1(*xi, *ri)).product())
+}
+
+/// Builds the table of evaluations of `eq(·, r)` over the boolean hypercube, in `O(2^n)` time.
+///
+/// Entries are ordered as in `to_binary_vec`, i.e. the first variable is the most significant bit.
+pub fn build_eq_x_r_evals<F: Field>(r: &[F]) -> Vec<F> {
+    let mut evals = Vec::with_capacity(1 << r.len());
+    evals.push(F::one());
+
+    // Each variable doubles the table: entry `b` splits into `2b` (x_i = 0) and `2b + 1` (x_i = 1).
+    for ri in r {
+        evals = evals
+            .into_iter()
+            .flat_map(|e| {
+                let high = e * ri;
+                [e - high, high]
+            })
+            .collect();
+    }
+
+    evals
+}
+
+/// Interpolates the unique multilinear polynomial taking the value `evals[i]` at the `i`-th point
+/// of the boolean hypercube, ordered as in `to_binary_vec`.
+///
+/// Inverts the table with a Möbius transform in `O(v·2^v)` field operations.
+pub fn from_hypercube_evaluations<F: Field>(evals: &[F]) -> Result<MultiPoly<F>, crate::Error> {
+    if !evals.len().is_power_of_two() {
+        return Err(crate::Error::PolynomialError(format!(
+            "Expected 2^v evaluations, got {}.",
+            evals.len(),
+        )));
+    }
+    let num_vars = evals.len().trailing_zeros() as usize;
+
+    // After processing every bit, entry `b` holds the coefficient of the monomial Π_{i ∈ b} x_i.
+    let mut coeffs = evals.to_vec();
+    for i in 0..num_vars {
+        let bit = 1 << (num_vars - 1 - i);
+        for b in (0..coeffs.len()).filter(|b| b & bit != 0) {
+            let low = coeffs[b ^ bit];
+            coeffs[b] -= low;
+        }
+    }
+
+    Ok(MultiPoly::from_coefficients_vec(
+        num_vars,
+        coeffs
+            .into_iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(b, c)| {
+                let vars = (0..num_vars)
+                    .filter(|i| b >> (num_vars - 1 - i) & 1 == 1)
+                    .map(|i| (i, 1))
+                    .collect();
+                (c, SparseTerm::new(vars))
+            })
+            .collect(),
+    ))
+}
+
+/// Evaluates `g` at every point of the boolean hypercube, ordered as in `to_binary_vec`.
+///
+/// Inverse of `from_hypercube_evaluations` for multilinear `g`.
+pub fn to_dense_evaluations<F: Field + From<i32>>(g: &MultiPoly<F>) -> Vec<F> {
+    let v = g.num_vars();
+
+    (0..1 << v).map(|i| g.evaluate(&to_binary_vec(i, v))).collect()
+}
+
+/// Interpolates the unique univariate polynomial of degree `< evals.len()` taking the value
+/// `evals[i]` at `X = i`, returned in sparse coefficient form.
+pub fn uni_poly_from_evaluations<F: Field>(evals: &[F]) -> UniPoly<F> {
+    let n = evals.len();
+    let mut coeffs = vec![F::zero(); n];
+
+    for (i, y) in evals.iter().enumerate() {
+        // Lagrange basis polynomial L_i(X) = Π_{j != i} (X - j) / (i - j), in dense form
+        let mut basis = vec![F::one()];
+        let mut denominator = F::one();
+
+        for j in (0..n).filter(|j| *j != i) {
+            let j_f = F::from(j as u64);
+            let mut next = vec![F::zero(); basis.len() + 1];
+            for (k, c) in basis.iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= j_f * c;
+            }
+            basis = next;
+            denominator *= F::from(i as u64) - j_f;
+        }
+
+        let scale = *y * denominator.inverse().unwrap();
+        for (c, b) in coeffs.iter_mut().zip(basis) {
+            *c += scale * b;
+        }
+    }
+
+    dense_to_sparse_uni(&coeffs)
+}
+
+/// Evaluates at `x` the unique univariate polynomial of degree `< evals.len()` taking the value
+/// `evals[i]` at `X = i`, i.e. `uni_poly_from_evaluations(evals).evaluate(&x)` without forming
+/// its coefficients, in `O(n)` field operations and `n` inversions.
+///
+/// No evaluations give the zero polynomial.
+pub fn interpolate_uni_poly<F: Field>(evals: &[F], x: F) -> F {
+    let n = evals.len();
+
+    // (x - j) for every node, and their prefix and suffix products.
+    let diffs: Vec<F> = (0..n).map(|j| x - F::from(j as u64)).collect();
+    let mut suffix = vec![F::one(); n + 1];
+    for j in (0..n).rev() {
+        suffix[j] = suffix[j + 1] * diffs[j];
+    }
+
+    // L_i(x) = Π_{j != i} (x - j) / (i! · (n - 1 - i)! · (-1)^(n - 1 - i))
+    let mut factorials = vec![F::one(); n.max(1)];
+    for k in 1..n {
+        factorials[k] = factorials[k - 1] * F::from(k as u64);
+    }
+
+    let mut prefix = F::one();
+    let mut result = F::zero();
+    for (i, y) in evals.iter().enumerate() {
+        let mut denominator = factorials[i] * factorials[n - 1 - i];
+        if (n - 1 - i) % 2 == 1 {
+            denominator = -denominator;
+        }
+        result += *y * prefix * suffix[i + 1] * denominator.inverse().unwrap();
+        prefix *= diffs[i];
+    }
+
+    result
+}
+
+/// Dense coefficients of `p`, of length `p.degree() + 1`, with the coefficient of `X^i` at
+/// index `i`. The zero polynomial gives `[0]`.
+pub fn sparse_uni_to_dense_coefficients<F: Field>(p: &UniPoly<F>) -> Vec<F> {
+    let mut coeffs = vec![F::zero(); p.degree() + 1];
+    for (i, c) in p.iter() {
+        coeffs[*i] = *c;
+    }
+
+    coeffs
+}
+
+/// The polynomial with dense coefficients `coeffs`, in sparse form, i.e. without its zero
+/// coefficients. Inverse of `sparse_uni_to_dense_coefficients`.
+pub fn dense_to_sparse_uni<F: Field>(coeffs: &[F]) -> UniPoly<F> {
+    UniPoly::from_coefficients_vec(
+        coeffs
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .collect(),
+    )
+}
+
+/// Parses a polynomial in `num_vars` variables written as text, e.g. `"2*x0^3 + x0*x2 - 5"`.
+///
+/// Terms are products of nonnegative integer coefficients and variables `x<i>`, optionally raised
+/// to a power with `^`, separated by `+` or `-`. Whitespace is ignored, subtraction maps to field
+/// negation and repeated monomials are merged. Malformed input and variable indices
+/// `>= num_vars` are reported as errors.
+pub fn parse_poly<F: Field>(s: &str, num_vars: usize) -> Result<MultiPoly<F>, crate::Error> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    if s.is_empty() {
+        return Err(crate::Error::PolynomialError("Expected a polynomial, got an empty string.".into()));
+    }
+
+    let mut builder = PolynomialBuilder::new().num_vars(num_vars);
+    let mut rest = s.as_str();
+    while !rest.is_empty() {
+        // Every term but the first starts with its sign.
+        let negative = rest.starts_with('-');
+        if let Some(unsigned) = rest.strip_prefix(['+', '-']) {
+            rest = unsigned;
+        }
+
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let (coeff, vars) = parse_term::<F>(&rest[..end])?;
+        builder = builder.add_term(if negative { -coeff } else { coeff }, &vars);
+        rest = &rest[end..];
+    }
+
+    builder.build()
+}
+
+/// Parses a single term without its sign, e.g. `2*x0^3*x2`.
+fn parse_term<F: Field>(term: &str) -> Result<(F, Vec<(usize, usize)>), crate::Error> {
+    let malformed = |what: &str| crate::Error::PolynomialError(format!("Malformed term {term:?}: {what}."));
+    let number = |digits: &str| {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(malformed("expected an integer"));
+        }
+        digits.parse::<usize>().map_err(|_| malformed("integer out of range"))
+    };
+
+    let mut coeff = F::one();
+    let mut vars = Vec::new();
+    for factor in term.split('*') {
+        if let Some(var) = factor.strip_prefix('x') {
+            let (index, power) = var.split_once('^').unwrap_or((var, "1"));
+            vars.push((number(index)?, number(power)?));
+        } else if !factor.is_empty() && factor.bytes().all(|b| b.is_ascii_digit()) {
+            // Coefficients may exceed any machine integer, so accumulate them in F.
+            coeff *= factor.bytes().fold(F::zero(), |acc, b| acc * F::from(10_u8) + F::from(b - b'0'));
+        } else {
+            return Err(malformed(&format!("unexpected factor {factor:?}")));
+        }
+    }
+
+    Ok((coeff, vars))
+}
+
+/// Prints `g` in the syntax accepted by `parse_poly`.
+///
+/// Each coefficient is printed as the integer of smallest absolute value it represents, so that
+/// e.g. `-5` appears as `- 5` rather than as `p - 5`.
+pub fn poly_to_string<F: PrimeField>(g: &MultiPoly<F>) -> String {
+    let mut out = String::new();
+    for (coeff, term) in g.terms() {
+        let negative = (-*coeff).into_bigint() < coeff.into_bigint();
+        let magnitude = if negative { -*coeff } else { *coeff };
+
+        match (out.is_empty(), negative) {
+            (true, false) => {}
+            (true, true) => out.push('-'),
+            (false, false) => out.push_str(" + "),
+            (false, true) => out.push_str(" - "),
+        }
+
+        let mut factors: Vec<String> = Vec::new();
+        if !magnitude.is_one() || term.is_constant() {
+            factors.push(magnitude.into_bigint().to_string());
+        }
+        factors.extend(term.iter().map(|(var, power)| match power {
+            1 => format!("x{var}"),
+            _ => format!("x{var}^{power}"),
+        }));
+        out.push_str(&factors.join("*"));
+    }
+
+    if out.is_empty() {
+        out.push('0');
+    }
+
+    out
+}
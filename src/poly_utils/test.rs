@@ -0,0 +1,390 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::{One, UniformRand, Zero};
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::Polynomial;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::IPForSumcheck;
+use crate::naive_sumcheck::protocol::prover::to_binary_vec;
+use crate::naive_sumcheck::protocol::verifier::max_degrees;
+use crate::poly_utils::{
+    assert_multilinear, is_multilinear,
+    build_eq_x_r, build_eq_x_r_evals, eq_eval, evaluate_along_line, from_hypercube_evaluations,
+    interpolate_uni_poly, polynomial_total_degree, to_univariate_at, uni_poly_from_evaluations,
+    dense_to_sparse_uni, parse_poly, poly_to_string, sparse_uni_to_dense_coefficients,
+    normalize_polynomial, poly_from_signed, term, to_dense_evaluations, truncate_to_multilinear,
+    validate_poly, PolynomialBuilder,
+};
+use crate::testing::{random_multilinear_polynomial, random_sparse_polynomial, raw_polynomial};
+use crate::{MultiPoly, UniPoly};
+
+#[test]
+fn test_eq_utilities_agree() {
+    let mut rng = rand::thread_rng();
+
+    for n in 1..=12 {
+        let r: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let evals = build_eq_x_r_evals(&r);
+        assert_eq!(evals.len(), 1 << n);
+
+        // The evaluation table matches direct evaluation over the whole hypercube.
+        for (i, eval) in evals.iter().enumerate() {
+            let x = to_binary_vec::<Fr>(i, n);
+            assert_eq!(*eval, eq_eval(&x, &r).unwrap());
+        }
+
+        // The sparse form matches direct evaluation at random points.
+        let poly = build_eq_x_r(&r);
+        assert_eq!(poly.num_vars, n);
+        for _ in 0..4 {
+            let x: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+            assert_eq!(poly.evaluate(&x), eq_eval(&x, &r).unwrap());
+        }
+
+        // ... and the evaluation table, on small instances.
+        if n <= 6 {
+            for (i, eval) in evals.iter().enumerate() {
+                assert_eq!(poly.evaluate(&to_binary_vec(i, n)), *eval);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_eq_on_hypercube_is_indicator() {
+    let n = 4;
+
+    for i in 0..(1 << n) {
+        let r = to_binary_vec::<Fr>(i, n);
+        let evals = build_eq_x_r_evals(&r);
+
+        for (j, eval) in evals.iter().enumerate() {
+            assert_eq!(*eval, if i == j { Fr::one() } else { Fr::zero() });
+        }
+    }
+}
+
+#[test]
+fn test_eq_eval_length_mismatch() {
+    let x = vec![Fr::one(); 3];
+    let r = vec![Fr::one(); 4];
+
+    assert!(eq_eval(&x, &r).is_err());
+}
+
+#[test]
+fn test_polynomial_builder() {
+    let g = PolynomialBuilder::<Fr>::new()
+        .num_vars(3)
+        .add_term(2.into(), &[(0, 3)])
+        .add_term(1.into(), &[(0, 1), (2, 1)])
+        .add_term(1.into(), &[(1, 1), (2, 1)])
+        .add_term(1.into(), &[(2, 1), (1, 1)])
+        .build()
+        .unwrap();
+
+    // Duplicate monomials are merged.
+    assert_eq!(g.num_vars, 3);
+    assert_eq!(g.terms.len(), 3);
+    assert_eq!(g.evaluate(&vec![1.into(), 1.into(), 1.into()]), Fr::from(5));
+
+    // Variable index out of range.
+    let result = PolynomialBuilder::<Fr>::new()
+        .num_vars(3)
+        .add_term(1.into(), &[(3, 1)])
+        .build();
+    assert!(result.is_err());
+
+    // Missing number of variables.
+    let result = PolynomialBuilder::<Fr>::new().add_term(1.into(), &[(0, 1)]).build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_poly_macro() {
+    let g = crate::poly!(Fr; vars = 3;
+        (2, [(0, 3)]),
+        (1, [(0, 1), (2, 1)]),
+        (1, [(1, 1), (2, 1)]),
+        (-1, [(2, 1), (1, 1)]),
+        (4, []),
+    )
+    .unwrap();
+
+    // Duplicate monomials are merged, here cancelling out.
+    assert_eq!(g.num_vars, 3);
+    assert_eq!(g.terms.len(), 3);
+    assert_eq!(g.evaluate(&vec![1.into(), 1.into(), 1.into()]), Fr::from(7));
+
+    // Variable index out of range.
+    assert!(crate::poly!(Fr; vars = 3; (1, [(0, 1)]), (1, [(3, 1)])).is_err());
+    assert!(crate::poly!(Fr; vars = 0; (1, [(0, 1)])).is_err());
+}
+
+#[test]
+fn test_from_hypercube_evaluations() {
+    let mut rng = rand::thread_rng();
+
+    for n in 0..=6 {
+        let evals: Vec<Fr> = (0..(1 << n)).map(|_| Fr::rand(&mut rng)).collect();
+        let g = from_hypercube_evaluations(&evals).unwrap();
+
+        assert_eq!(g.num_vars, n);
+        assert!(max_degrees(&g).iter().all(|d| *d <= 1));
+        for (i, eval) in evals.iter().enumerate() {
+            assert_eq!(g.evaluate(&to_binary_vec(i, n)), *eval);
+        }
+    }
+
+    // The MLE of eq(·, r) restricted to the hypercube is eq itself.
+    let r: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    assert_eq!(from_hypercube_evaluations(&build_eq_x_r_evals(&r)).unwrap(), build_eq_x_r(&r));
+
+    // Tabulating a multilinear polynomial gives its evaluations back.
+    let evals: Vec<Fr> = (0..8).map(|_| Fr::rand(&mut rng)).collect();
+    assert_eq!(to_dense_evaluations(&from_hypercube_evaluations(&evals).unwrap()), evals);
+    assert_eq!(to_dense_evaluations(&build_eq_x_r(&r)), build_eq_x_r_evals(&r));
+
+    assert!(from_hypercube_evaluations(&[Fr::one(); 3]).is_err());
+    assert!(from_hypercube_evaluations::<Fr>(&[]).is_err());
+}
+
+#[test]
+fn test_dense_uni_coefficients() {
+    // 3 + 5·X^2 + X^5
+    let p = UniPoly::<Fr>::from_coefficients_vec(vec![(0, 3.into()), (2, 5.into()), (5, 1.into())]);
+    let dense = sparse_uni_to_dense_coefficients(&p);
+    assert_eq!(
+        dense,
+        [3, 0, 5, 0, 0, 1].into_iter().map(Fr::from).collect::<Vec<_>>()
+    );
+    assert_eq!(dense_to_sparse_uni(&dense), p);
+
+    let zero = UniPoly::<Fr>::from_coefficients_vec(vec![]);
+    assert_eq!(sparse_uni_to_dense_coefficients(&zero), vec![Fr::zero()]);
+    assert_eq!(dense_to_sparse_uni(&sparse_uni_to_dense_coefficients(&zero)), zero);
+}
+
+#[test]
+fn test_polynomial_total_degree() {
+    // 2·x0^3 + x0·x2 + x1^2·x2^2
+    let g = PolynomialBuilder::<Fr>::new()
+        .num_vars(3)
+        .add_term(2.into(), &[(0, 3)])
+        .add_term(1.into(), &[(0, 1), (2, 1)])
+        .add_term(1.into(), &[(1, 2), (2, 2)])
+        .build()
+        .unwrap();
+    assert_eq!(polynomial_total_degree(&g), 4);
+
+    let constant = PolynomialBuilder::<Fr>::new().num_vars(2).add_term(5.into(), &[]).build().unwrap();
+    assert_eq!(polynomial_total_degree(&constant), 0);
+
+    // Huge powers saturate instead of overflowing.
+    let huge = PolynomialBuilder::<Fr>::new()
+        .num_vars(2)
+        .add_term(1.into(), &[(0, usize::MAX), (1, usize::MAX)])
+        .build()
+        .unwrap();
+    assert_eq!(polynomial_total_degree(&huge), usize::MAX);
+}
+
+#[test]
+fn test_parse_poly() {
+    let g = parse_poly::<Fr>("2*x0^3 + x0*x2 + x1*x2 - 5", 3).unwrap();
+    let expected = crate::poly!(Fr; vars = 3;
+        (2, [(0, 3)]),
+        (1, [(0, 1), (2, 1)]),
+        (1, [(1, 1), (2, 1)]),
+        (-5, []),
+    )
+    .unwrap();
+    assert_eq!(g, expected);
+    assert_eq!(poly_to_string(&g), "-5 + x1*x2 + x0*x2 + 2*x0^3");
+
+    // Whitespace is irrelevant and repeated monomials are merged.
+    assert_eq!(parse_poly::<Fr>(" - 5+2 * x0 ^3+x2*x0+x1*x2 ", 3).unwrap(), expected);
+    assert_eq!(parse_poly::<Fr>("x0*x1 + 2*x1*x0 - 3*x0*x1 + x2", 3).unwrap(), parse_poly("x2", 3).unwrap());
+    assert_eq!(parse_poly::<Fr>("x1*x1^2", 2).unwrap(), parse_poly("x1^3", 2).unwrap());
+    assert_eq!(poly_to_string(&parse_poly::<Fr>("x0 - x0", 1).unwrap()), "0");
+
+    // Out-of-range variables and malformed tokens.
+    for s in ["x3", "", "2*", "x0 +", "x0 + - 1", "y0", "x", "x0^", "x0^-1", "2x0", "x0**x1", "(x0)"] {
+        let result = parse_poly::<Fr>(s, 3);
+        assert!(matches!(result, Err(crate::Error::PolynomialError(_))), "{s:?} should not parse");
+    }
+}
+
+#[test]
+fn test_parse_poly_round_trip() {
+    let mut rng = rand::thread_rng();
+
+    for num_vars in 1..=5 {
+        let g = random_sparse_polynomial::<Fr, _>(num_vars, 10, 3, &mut rng);
+        let printed = poly_to_string(&g);
+        let parsed = parse_poly::<Fr>(&printed, num_vars).unwrap();
+        assert_eq!(parsed, g);
+        assert_eq!(poly_to_string(&parsed), printed);
+    }
+}
+
+#[test]
+fn test_validate_poly() {
+    let mut rng = rand::thread_rng();
+    assert!(validate_poly(&random_sparse_polynomial::<Fr, _>(4, 10, 3, &mut rng)).is_ok());
+    assert!(validate_poly(&parse_poly::<Fr>("0", 2).unwrap()).is_ok());
+
+    let is_error_about = |g: &MultiPoly<Fr>, what: &str| {
+        matches!(validate_poly(g), Err(crate::Error::PolynomialError(msg)) if msg.contains(what))
+    };
+
+    // A term referring to x5 in a 3-variable polynomial.
+    let out_of_range = MultiPoly {
+        num_vars: 3,
+        terms: vec![(1.into(), SparseTerm::new(vec![(0, 1)])), (2.into(), SparseTerm::new(vec![(1, 1), (5, 2)]))],
+    };
+    assert!(is_error_about(&out_of_range, "x5"));
+
+    // The same monomial twice, with its variables in a different order.
+    let duplicated = MultiPoly {
+        num_vars: 3,
+        terms: vec![
+            (1.into(), SparseTerm::new(vec![(0, 1), (2, 1)])),
+            (1.into(), SparseTerm::new(vec![(1, 1)])),
+            (3.into(), SparseTerm::new(vec![(2, 1), (0, 1)])),
+        ],
+    };
+    assert!(is_error_about(&duplicated, "more than once"));
+
+    let zero = MultiPoly { num_vars: 1, terms: vec![(Fr::zero(), SparseTerm::new(vec![(0, 1)]))] };
+    assert!(is_error_about(&zero, "zero coefficient"));
+
+    // Terms not in the form of `SparseTerm::new`, as decoded from untrusted bytes.
+    let zero_power = raw_polynomial(2, vec![(Fr::one(), vec![(0, 0)])]);
+    assert!(is_error_about(&zero_power, "power 0"));
+    let repeated = raw_polynomial(2, vec![(Fr::one(), vec![(0, 1), (0, 1)])]);
+    assert!(is_error_about(&repeated, "repeats a variable"));
+    let unsorted = raw_polynomial(2, vec![(Fr::one(), vec![(1, 1), (0, 1)])]);
+    assert!(is_error_about(&unsorted, "not sorted"));
+    for g in [zero_power, repeated, unsorted] {
+        assert!(validate_poly(&normalize_polynomial(g)).is_ok());
+    }
+}
+
+#[test]
+fn test_normalize_polynomial() {
+    // x0*x2 twice, and x1 cancelling out.
+    let g = MultiPoly {
+        num_vars: 3,
+        terms: vec![
+            (1.into(), SparseTerm::new(vec![(0, 1), (2, 1)])),
+            (1.into(), SparseTerm::new(vec![(1, 1)])),
+            (3.into(), SparseTerm::new(vec![(2, 1), (0, 1)])),
+            (-Fr::from(1), SparseTerm::new(vec![(1, 1)])),
+        ],
+    };
+    let normalized = normalize_polynomial(g.clone());
+    assert!(validate_poly(&normalized).is_ok());
+    assert_eq!(normalized, parse_poly("4*x0*x2", 3).unwrap());
+
+    // Same sum over the hypercube as the terms taken one by one.
+    let sum = |g: &MultiPoly<Fr>| -> Fr {
+        (0..8).map(|i| g.terms.iter().map(|(c, t)| *c * t.evaluate(&to_binary_vec::<Fr>(i, 3))).sum::<Fr>()).sum()
+    };
+    assert_eq!(sum(&normalized), sum(&g));
+    assert_eq!(normalize_polynomial(normalized.clone()), normalized);
+}
+
+#[test]
+fn test_truncate_to_multilinear() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(4, 10, 4, &mut rng);
+    let truncated = truncate_to_multilinear(&g, (Fr::zero(), Fr::one()));
+    assert!(is_multilinear(&truncated));
+    assert!(validate_poly(&truncated).is_ok());
+
+    // Same values on the hypercube, hence the same sum.
+    for i in 0..16 {
+        let point = to_binary_vec::<Fr>(i, 4);
+        assert_eq!(truncated.evaluate(&point), g.evaluate(&point));
+    }
+    assert_eq!(IPForSumcheck::compute_sum(&truncated).unwrap(), IPForSumcheck::compute_sum(&g).unwrap());
+    assert_eq!(truncate_to_multilinear(&truncated, (Fr::zero(), Fr::one())), truncated);
+
+    // x0^3 on {2, 5} is the line through (2, 8) and (5, 125), i.e. 39·x0 - 70.
+    let cube = parse_poly::<Fr>("x0^3", 1).unwrap();
+    assert_eq!(truncate_to_multilinear(&cube, (2.into(), 5.into())), parse_poly("39*x0 - 70", 1).unwrap());
+    let on_grid = truncate_to_multilinear(&g, (2.into(), 5.into()));
+    for i in 0..16 {
+        let point: Vec<Fr> = to_binary_vec::<Fr>(i, 4).iter().map(|b| Fr::from(2) + Fr::from(3) * b).collect();
+        assert_eq!(on_grid.evaluate(&point), g.evaluate(&point));
+    }
+}
+
+#[test]
+fn test_evaluate_along_line() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(4, 10, 4, &mut rng);
+    let a: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    let b: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+
+    // The line passes through a at t = 0 and b at t = 1.
+    assert_eq!(evaluate_along_line(&g, &a, &b, Fr::zero()), g.evaluate(&a));
+    assert_eq!(evaluate_along_line(&g, &a, &b, Fr::one()), g.evaluate(&b));
+
+    let t = Fr::rand(&mut rng);
+    let point: Vec<Fr> = a.iter().zip(&b).map(|(ai, bi)| *ai + t * (*bi - ai)).collect();
+    assert_eq!(evaluate_along_line(&g, &a, &b, t), g.evaluate(&point));
+
+    let line = to_univariate_at(&g, &a, &b);
+    assert!(line.degree() <= polynomial_total_degree(&g));
+    assert_eq!(line.evaluate(&t), g.evaluate(&point));
+
+    // x0·x1 on the line from (0, 1) to (1, 0) is t·(1 - t).
+    let g = parse_poly::<Fr>("x0*x1", 2).unwrap();
+    let line = to_univariate_at(&g, &[Fr::zero(), Fr::one()], &[Fr::one(), Fr::zero()]);
+    assert_eq!(line, UniPoly::from_coefficients_vec(vec![(1, Fr::one()), (2, -Fr::one())]));
+}
+
+#[test]
+fn test_interpolate_uni_poly() {
+    let mut rng = rand::thread_rng();
+
+    for n in 0..6 {
+        let evals: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let x = Fr::rand(&mut rng);
+        assert_eq!(interpolate_uni_poly(&evals, x), uni_poly_from_evaluations(&evals).evaluate(&x));
+
+        // At the nodes the evaluations are returned as they are.
+        for (i, y) in evals.iter().enumerate() {
+            assert_eq!(interpolate_uni_poly(&evals, Fr::from(i as u64)), *y);
+        }
+    }
+}
+
+#[test]
+fn test_is_multilinear() {
+    let mut rng = rand::thread_rng();
+
+    let g = random_multilinear_polynomial::<Fr, _>(5, 10, &mut rng);
+    assert!(is_multilinear(&g));
+    assert!(assert_multilinear(&g).is_ok());
+    assert!(is_multilinear(&build_eq_x_r(&[Fr::from(3), Fr::from(4)])));
+    assert!(is_multilinear(&parse_poly::<Fr>("7", 2).unwrap()));
+
+    let g = parse_poly::<Fr>("x0*x1 + 3*x1^2*x2", 3).unwrap();
+    assert!(!is_multilinear(&g));
+    assert!(matches!(assert_multilinear(&g), Err(crate::Error::PolynomialError(msg)) if msg.contains("(1, 2)")));
+}
+
+#[test]
+fn test_signed_coefficients() {
+    assert_eq!(term::<Fr>(-2, &[(2, 1)]).0, -Fr::from(2));
+    assert_eq!(term::<Fr>(i64::MIN, &[]).0, -Fr::from(1_u64 << 63));
+    assert_eq!(term::<Fr>(5, &[(1, 1), (0, 1)]), (Fr::from(5), SparseTerm::new(vec![(0, 1), (1, 1)])));
+
+    // 3·x0·x1 - 2·x2
+    let g = poly_from_signed::<Fr>(3, &[(3, &[(0, 1), (1, 1)]), (-2, &[(2, 1)])]).unwrap();
+    assert_eq!(g, parse_poly("3*x0*x1 - 2*x2", 3).unwrap());
+    assert!(poly_from_signed::<Fr>(2, &[(1, &[(2, 1)])]).is_err());
+}
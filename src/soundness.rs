@@ -0,0 +1,112 @@
+//! Soundness error of the Sumcheck protocol
+//!
+//! A cheating prover survives round `i` with probability at most `d_i / |F|`, where `d_i` is the
+//! degree bound of that round, so by the union bound the whole protocol is sound up to
+//! `Σ_i d_i / |F|`. The estimates here are reported in bits of security, i.e. the protocol
+//! errs with probability at most `2^-bits`. Only available with `std`, for `f64::log2`.
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::borrow::Borrow;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    verifier::{max_degrees, VerifierState},
+};
+use crate::MultiPoly;
+
+#[cfg(test)]
+mod test;
+
+/// Soundness error of a Sumcheck instance
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoundnessReport {
+    /// Number of rounds
+    pub num_vars: usize,
+    /// Sum of the degree bounds of every round
+    pub total_degree: usize,
+    /// `log2 |F|`
+    pub field_bits: f64,
+    /// Number of independent repetitions of the protocol, all of which must accept
+    pub repetitions: usize,
+}
+
+/// `log2 |F|`, i.e. the extension degree of `F` times the logarithm of its characteristic.
+pub fn field_bits<F: Field>() -> f64 {
+    let modulus = <F::BasePrimeField as PrimeField>::MODULUS;
+    let bits = modulus.num_bits() as usize;
+
+    // The leading 53 bits are all an `f64` holds.
+    let mantissa: f64 = (0..bits.min(53))
+        .filter(|j| modulus.get_bit(bits - 1 - j))
+        .map(|j| 0.5_f64.powi(j as i32))
+        .sum();
+
+    F::extension_degree() as f64 * ((bits - 1) as f64 + mantissa.log2())
+}
+
+/// Bits of security of the protocol over `F` for `num_vars` rounds of degree at most
+/// `max_degrees[i]` each, i.e. `log2 |F| - log2 Σ_i max_degrees[i]`, infinite if every degree
+/// is zero.
+pub fn soundness_error_bits<F: Field>(num_vars: usize, max_degrees: &[usize]) -> f64 {
+    SoundnessReport::new::<F>(num_vars, max_degrees).error_bits()
+}
+
+/// Soundness of the protocol for `g`, with the degree bounds of `max_degrees`.
+pub fn soundness_for_poly<F: Field>(g: &MultiPoly<F>) -> SoundnessReport {
+    SoundnessReport::new::<F>(g.num_vars, &max_degrees(g))
+}
+
+impl SoundnessReport {
+    /// Soundness of a single run over `F` for `num_vars` rounds, the `i`-th (from 0) of degree
+    /// at most `max_degrees[i]`.
+    pub fn new<F: Field>(num_vars: usize, max_degrees: &[usize]) -> Self {
+        assert_eq!(max_degrees.len(), num_vars, "Expected one degree bound per round...");
+
+        Self {
+            num_vars,
+            total_degree: max_degrees.iter().sum(),
+            field_bits: field_bits::<F>(),
+            repetitions: 1,
+        }
+    }
+
+    /// The same instance, repeated `repetitions` times with independent challenges.
+    pub fn repeated(self, repetitions: usize) -> Self {
+        Self { repetitions, ..self }
+    }
+
+    /// Bits of security: every repetition errs with probability at most `Σ_i d_i / |F|`.
+    pub fn error_bits(&self) -> f64 {
+        if self.total_degree == 0 {
+            return f64::INFINITY;
+        }
+
+        self.repetitions as f64 * (self.field_bits - (self.total_degree as f64).log2())
+    }
+
+    /// Fails unless the protocol has at least `min_bits` bits of security.
+    pub fn check(&self, min_bits: f64) -> Result<(), crate::Error> {
+        if self.error_bits() >= min_bits {
+            Ok(())
+        } else {
+            Err(crate::Error::ProtocolError(format!(
+                "Soundness of {:.2} bits is below the required {min_bits} bits.",
+                self.error_bits()
+            )))
+        }
+    }
+}
+
+impl<F: Field> IPForSumcheck<F> {
+    /// Full verification, as `verify`, refusing up front if `g` makes the protocol sound to
+    /// fewer than `min_bits` bits over `F`.
+    pub fn verify_with_security(
+        g: &MultiPoly<F>,
+        verifier_state: impl Borrow<VerifierState<F>>,
+        asserted_sum: F,
+        min_bits: f64,
+    ) -> Result<(), crate::Error> {
+        soundness_for_poly(g).check(min_bits)?;
+
+        Self::verify(g, verifier_state, asserted_sum)
+    }
+}
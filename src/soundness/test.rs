@@ -0,0 +1,48 @@
+use ark_bls12_381::{Fq, Fq2, Fr};
+
+use crate::naive_sumcheck::protocol::IPForSumcheck;
+use crate::soundness::{field_bits, soundness_error_bits, soundness_for_poly, SoundnessReport};
+use crate::testing::thaler_example;
+use crate::transcript::RngTranscript;
+
+#[test]
+fn test_soundness_error_bits() {
+    // The scalar field of BLS12-381 is a bit short of 2^255, its base field of 2^381.
+    assert!((254.85..254.86).contains(&field_bits::<Fr>()));
+    assert!((380.70..380.71).contains(&field_bits::<Fq>()));
+    assert!((field_bits::<Fq2>() - 2.0 * field_bits::<Fq>()).abs() < 1e-9);
+
+    // 3 variables of degree 3 lose log2(9) bits.
+    let bits = soundness_error_bits::<Fr>(3, &[3, 3, 3]);
+    assert!((251.0..252.0).contains(&bits));
+    assert!((bits - (field_bits::<Fr>() - 9_f64.log2())).abs() < 1e-9);
+    assert_eq!(soundness_error_bits::<Fr>(2, &[0, 0]), f64::INFINITY);
+
+    let report = soundness_for_poly(&thaler_example::<Fr>());
+    assert_eq!((report.num_vars, report.total_degree, report.repetitions), (3, 5, 1));
+    assert!((report.repeated(2).error_bits() - 2.0 * report.error_bits()).abs() < 1e-9);
+    assert_eq!(report, SoundnessReport::new::<Fr>(3, &[3, 1, 1]));
+    assert!(report.check(128.0).is_ok());
+    assert!(report.check(253.0).is_err());
+}
+
+#[test]
+fn test_verify_with_security() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example::<Fr>();
+    let mut prover_state = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::verifier_init(3);
+    let mut verifier_msg = None;
+    for _ in 0..3 {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut RngTranscript::new(&mut rng)).unwrap());
+    }
+
+    assert!(IPForSumcheck::verify_with_security(&g, &verifier_state, 12.into(), 128.0).is_ok());
+    assert!(IPForSumcheck::verify_with_security(&g, &verifier_state, 13.into(), 128.0).is_err());
+    assert!(matches!(
+        IPForSumcheck::verify_with_security(&g, &verifier_state, 12.into(), 253.0),
+        Err(crate::Error::ProtocolError(_))
+    ));
+}
@@ -0,0 +1,240 @@
+//! Utilities for testing and benchmarking the protocol: random polynomials, and provers that
+//! cheat in controlled ways
+//!
+//! `run_with_strategy` plays a `MaliciousProver` against the honest verifier, so that every
+//! check of the verifier can be exercised by a test.
+use ark_ff::Field;
+use ark_poly::polynomial::multivariate::{SparseTerm, Term};
+use ark_poly::DenseMVPolynomial;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{rand::RngCore, vec::Vec};
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::{max_degrees, VerifierMsg},
+};
+use crate::poly_utils::poly_from_signed;
+use crate::traits::SumcheckProver;
+use crate::transcript::RngTranscript;
+use crate::{MultiPoly, UniPoly};
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// A random polynomial in `num_vars` variables made of `num_terms` random monomials, each with
+/// a random coefficient and a power of at most `max_degree_per_var` in every variable.
+///
+/// Repeated monomials are merged, so the result may have fewer than `num_terms` terms, but never
+/// two with the same monomial.
+pub fn random_sparse_polynomial<F: Field, R: RngCore>(
+    num_vars: usize,
+    num_terms: usize,
+    max_degree_per_var: usize,
+    rng: &mut R,
+) -> MultiPoly<F> {
+    let terms: Vec<(F, SparseTerm)> = (0..num_terms)
+        .map(|_| {
+            let vars = (0..num_vars)
+                .map(|var| (var, rng.next_u64() as usize % (max_degree_per_var + 1)))
+                .filter(|(_, power)| *power > 0)
+                .collect();
+            (F::rand(rng), SparseTerm::new(vars))
+        })
+        .collect();
+
+    MultiPoly::from_coefficients_vec(num_vars, terms)
+}
+
+/// Same as `random_sparse_polynomial`, restricted to multilinear polynomials.
+pub fn random_multilinear_polynomial<F: Field, R: RngCore>(
+    num_vars: usize,
+    num_terms: usize,
+    rng: &mut R,
+) -> MultiPoly<F> {
+    random_sparse_polynomial(num_vars, num_terms, 1, rng)
+}
+
+/// The example of Section 4.1 of Thaler's "Proofs, Arguments, and Zero-Knowledge",
+/// `g = 2·x_0^3 + x_0·x_2 + x_1·x_2`, which sums to 12 over the boolean hypercube.
+pub fn thaler_example<F: Field>() -> MultiPoly<F> {
+    poly_from_signed(3, &[(2, &[(0, 3)]), (1, &[(0, 1), (2, 1)]), (1, &[(1, 1), (2, 1)])]).unwrap()
+}
+
+/// The polynomial with the given terms taken as they are, e.g. with unsorted or repeated
+/// variables and zero powers, the way `CanonicalDeserialize` accepts them from untrusted bytes.
+///
+/// `SparseTerm::new` would bring every term into canonical form, so this goes through the
+/// serialized encoding instead.
+pub fn raw_polynomial<F: Field>(num_vars: usize, terms: Vec<(F, Vec<(usize, usize)>)>) -> MultiPoly<F> {
+    let mut bytes = Vec::new();
+    (num_vars, terms).serialize_compressed(&mut bytes).unwrap();
+    MultiPoly::deserialize_compressed(bytes.as_slice()).unwrap()
+}
+
+/// How a `MaliciousProver` deviates from the honest prover. Rounds are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Strategy<F: Field> {
+    /// Claim a sum off by `delta`, adding `delta·X` to the first round polynomial so that it
+    /// is consistent with the lie
+    LieAboutSum {
+        /// Difference between the claimed and the true sum
+        delta: F,
+    },
+    /// Add a random term, within the degree bound, to the polynomial of `round`
+    CorruptRound {
+        /// Round of the corrupted message
+        round: usize,
+    },
+    /// Add `c·(2X^{d+1} - 1)`, where `d` is the degree bound, to the polynomial of `round`,
+    /// which leaves `gi(0) + gi(1)` unchanged
+    OverDegree {
+        /// Round of the offending message
+        round: usize,
+    },
+    /// Prove for `g` with variables `first` and `second` (from 0) exchanged, i.e. send the
+    /// messages of the two rounds in each other's place. This is only a lie if `g` is not
+    /// symmetric in these variables.
+    SwapRounds {
+        /// Variable summed in place of `second`
+        first: usize,
+        /// Variable summed in place of `first`
+        second: usize,
+    },
+}
+
+/// Prover that follows the honest prover for `g` except where `Strategy` says otherwise
+pub struct MaliciousProver<F: Field + From<i32>> {
+    /// Honest prover, possibly for a relabelling of `g`
+    honest: ProverState<F>,
+    /// Round in which `shift` is added to the honest message, if any
+    round: Option<usize>,
+    /// Polynomial added to the honest message of `round`
+    shift: UniPoly<F>,
+    /// Difference between the sum claimed by this prover and the true one
+    sum_offset: F,
+}
+
+impl<F: Field + From<i32>> MaliciousProver<F> {
+    /// A prover for `g` following `strategy`, drawing any randomness it needs from `rng`.
+    pub fn new<R: RngCore>(
+        g: &MultiPoly<F>,
+        strategy: &Strategy<F>,
+        rng: &mut R,
+    ) -> Result<Self, crate::Error> {
+        let degrees = max_degrees(g);
+        let check_round = |round: usize| {
+            if (1..=g.num_vars).contains(&round) {
+                Ok(round)
+            } else {
+                Err(crate::Error::ProtocolError(format!(
+                    "Round {round} does not exist, expected 1 to {}.",
+                    g.num_vars
+                )))
+            }
+        };
+        let c = nonzero(rng);
+
+        let (prover_g, round, shift, sum_offset) = match strategy {
+            Strategy::LieAboutSum { delta } => (g.clone(), Some(1), monomial(1, *delta), *delta),
+            Strategy::CorruptRound { round } => {
+                let degree = rng.next_u32() as usize % (degrees[check_round(*round)? - 1] + 1);
+                (g.clone(), Some(*round), monomial(degree, c), F::zero())
+            }
+            Strategy::OverDegree { round } => {
+                let degree = degrees[check_round(*round)? - 1] + 1;
+                let shift = monomial(degree, c.double()) + monomial(0, -c);
+                (g.clone(), Some(*round), shift, F::zero())
+            }
+            Strategy::SwapRounds { first, second } => {
+                check_round(first + 1)?;
+                check_round(second + 1)?;
+                (swap_variables(g, *first, *second), None, monomial(0, F::zero()), F::zero())
+            }
+        };
+
+        Ok(Self {
+            honest: IPForSumcheck::prover_init(prover_g)?,
+            round,
+            shift,
+            sum_offset,
+        })
+    }
+
+    /// The sum this prover claims for `g`.
+    pub fn claimed_sum(&self) -> F {
+        self.honest.asserted_sum() + self.sum_offset
+    }
+}
+
+impl<F: Field + From<i32>> SumcheckProver<F> for MaliciousProver<F> {
+    fn next_message(
+        &mut self,
+        verifier_msg: Option<VerifierMsg<F>>,
+    ) -> Result<ProverMsg<F>, crate::Error> {
+        let msg = self.honest.next_message(verifier_msg)?;
+        if self.round == Some(self.honest.round) {
+            return Ok(ProverMsg::new(msg.gi + self.shift.clone()));
+        }
+
+        Ok(msg)
+    }
+
+    fn is_done(&self) -> bool {
+        self.honest.is_done()
+    }
+}
+
+/// Runs the whole protocol for `g` between a `MaliciousProver` following `strategy` and the
+/// verifier, which draws its challenges from `rng`, and returns the verifier's decision on the
+/// sum claimed by the prover.
+pub fn run_with_strategy<F: Field + From<i32>, R: RngCore>(
+    g: &MultiPoly<F>,
+    strategy: &Strategy<F>,
+    rng: &mut R,
+) -> Result<(), crate::Error> {
+    let mut prover = MaliciousProver::new(g, strategy, rng)?;
+    let claimed_sum = prover.claimed_sum();
+    let mut transcript = RngTranscript::new(rng);
+    let mut verifier_state = IPForSumcheck::verifier_init(g.num_vars);
+    let mut verifier_msg = None;
+
+    while !prover.is_done() {
+        let prover_msg = prover.next_message(verifier_msg)?;
+        verifier_msg =
+            Some(IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript)?);
+    }
+
+    IPForSumcheck::verify(g, verifier_state, claimed_sum)
+}
+
+/// `c·X^degree`.
+fn monomial<F: Field>(degree: usize, c: F) -> UniPoly<F> {
+    UniPoly::from_coefficients_vec(
+        Some((degree, c)).filter(|(_, c)| !c.is_zero()).into_iter().collect(),
+    )
+}
+
+/// A uniformly random nonzero field element.
+fn nonzero<F: Field, R: RngCore>(rng: &mut R) -> F {
+    loop {
+        let c = F::rand(rng);
+        if !c.is_zero() {
+            return c;
+        }
+    }
+}
+
+/// `g` with the variables `a` and `b` exchanged.
+fn swap_variables<F: Field>(g: &MultiPoly<F>, a: usize, b: usize) -> MultiPoly<F> {
+    let swap = |var: usize| if var == a { b } else if var == b { a } else { var };
+    let terms: Vec<(F, SparseTerm)> = g
+        .terms
+        .iter()
+        .map(|(coeff, term)| {
+            (*coeff, SparseTerm::new(term.iter().map(|(var, power)| (swap(*var), *power)).collect()))
+        })
+        .collect();
+
+    MultiPoly::from_coefficients_vec(g.num_vars, terms)
+}
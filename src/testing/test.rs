@@ -0,0 +1,148 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::UniformRand;
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::naive_sumcheck::protocol::{IPForSumcheck, verifier::max_degrees};
+use crate::poly_utils::polynomial_total_degree;
+use crate::testing::{
+    random_multilinear_polynomial, random_sparse_polynomial, run_with_strategy, thaler_example,
+    MaliciousProver, Strategy,
+};
+use crate::transcript::Blake2Transcript;
+
+#[test]
+fn test_sum_lie_is_rejected() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example();
+
+    for _ in 0..20 {
+        let strategy = Strategy::LieAboutSum { delta: Fr::rand(&mut rng) };
+        let prover = MaliciousProver::new(&g, &strategy, &mut rng).unwrap();
+        assert_ne!(prover.claimed_sum(), IPForSumcheck::compute_sum(&g).unwrap());
+
+        // The first message is consistent with the lie, so the second one gives it away.
+        assert!(matches!(
+            run_with_strategy(&g, &strategy, &mut rng),
+            Err(crate::Error::SumMismatch { round: 2, .. })
+        ));
+    }
+}
+
+#[test]
+fn test_corrupt_round_is_rejected() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example::<Fr>();
+
+    for round in 1..=3 {
+        for _ in 0..5 {
+            assert!(matches!(
+                run_with_strategy(&g, &Strategy::CorruptRound { round }, &mut rng),
+                Err(crate::Error::SumMismatch { round: r, .. }) if r == round
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_over_degree_is_rejected() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example::<Fr>();
+
+    for (round, bound) in [(1, 3), (2, 1), (3, 1)] {
+        assert_eq!(
+            run_with_strategy(&g, &Strategy::OverDegree { round }, &mut rng),
+            Err(crate::Error::DegreeTooHigh { round, got: bound + 1, bound })
+        );
+    }
+}
+
+#[test]
+fn test_verify_rejects_degrees_above_bounds() {
+    let mut rng = rand::thread_rng();
+
+    for max_degree in 1..=4 {
+        let num_vars = 4;
+        let g = random_sparse_polynomial::<Fr, _>(num_vars, 12, max_degree, &mut rng);
+        let bounds = max_degrees(&g);
+        let proof = IPForSumcheck::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+        let sum = IPForSumcheck::compute_sum(&g).unwrap();
+        assert!(IPForSumcheck::verify_with_randomness(
+            &g,
+            &proof.prover_messages,
+            proof.verifier_randomness.clone(),
+            sum,
+        )
+        .is_ok());
+
+        // An honest proof for g + x_i^(d_i + 1) exceeds the bound of g in round i + 1 only.
+        for (i, bound) in bounds.iter().enumerate() {
+            let h = &g + &crate::poly!(Fr; vars = num_vars; (1, [(i, bound + 1)])).unwrap();
+            let proof = IPForSumcheck::prove_non_interactive(h.clone(), &mut Blake2Transcript::new()).unwrap();
+            assert_eq!(
+                IPForSumcheck::verify_with_randomness(
+                    &g,
+                    &proof.prover_messages,
+                    proof.verifier_randomness.clone(),
+                    IPForSumcheck::compute_sum(&h).unwrap(),
+                ),
+                Err(crate::Error::DegreeTooHigh { round: i + 1, got: bound + 1, bound: *bound })
+            );
+        }
+    }
+}
+
+#[test]
+fn test_swapped_rounds_are_rejected() {
+    let mut rng = rand::thread_rng();
+    let g = thaler_example::<Fr>();
+
+    // x1 and x2 have the same degree, so only the final query catches the swap.
+    assert!(matches!(
+        run_with_strategy(&g, &Strategy::SwapRounds { first: 1, second: 2 }, &mut rng),
+        Err(crate::Error::FinalEvaluationMismatch { .. })
+    ));
+    assert!(matches!(
+        run_with_strategy(&g, &Strategy::SwapRounds { first: 0, second: 1 }, &mut rng),
+        Err(crate::Error::DegreeTooHigh { round: 2, .. })
+    ));
+
+    // Swapping a variable with itself is the honest prover.
+    assert!(run_with_strategy(&g, &Strategy::SwapRounds { first: 2, second: 2 }, &mut rng).is_ok());
+    assert!(run_with_strategy(&g, &Strategy::CorruptRound { round: 4 }, &mut rng).is_err());
+}
+
+#[test]
+fn test_random_sparse_polynomial() {
+    let mut rng = rand::thread_rng();
+
+    for num_vars in 1..=6 {
+        for max_degree in 1..=4 {
+            let g = random_sparse_polynomial::<Fr, _>(num_vars, 20, max_degree, &mut rng);
+            assert_eq!(g.num_vars, num_vars);
+            assert!(!g.terms.is_empty() && g.terms.len() <= 20);
+            assert!(max_degrees(&g).iter().all(|d| *d <= max_degree));
+            assert!(g.terms.windows(2).all(|pair| pair[0].1 != pair[1].1));
+        }
+    }
+
+    // Few monomials exist in one multilinear variable, so draws repeat and get merged.
+    let g = random_multilinear_polynomial::<Fr, _>(1, 50, &mut rng);
+    assert!(g.terms.len() <= 2);
+    assert!(polynomial_total_degree(&random_multilinear_polynomial::<Fr, _>(4, 10, &mut rng)) <= 4);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // The honest prover convinces the verifier on every multilinear instance.
+    #[test]
+    fn test_honest_prover_is_accepted(num_vars in 1..=8_usize, num_terms in 1..=32_usize, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let g = random_multilinear_polynomial::<Fr, _>(num_vars, num_terms, &mut rng);
+
+        let (asserted_sum, v_out) = IPForSumcheck::run_interactive_protocol(&g, &mut rng).unwrap();
+        prop_assert_eq!(asserted_sum, IPForSumcheck::compute_sum(&g).unwrap());
+        prop_assert_eq!(v_out.r_vec.len(), num_vars);
+    }
+}
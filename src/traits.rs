@@ -0,0 +1,152 @@
+//! Abstract interfaces to the parties of the Sumcheck protocol
+//!
+//! The functions on `IPForSumcheck` remain the reference implementation; the traits here let
+//! a verifier be driven by any prover, e.g. `MockCheatingProver` in negative tests, and test
+//! harnesses be written once for any pair of a `SumcheckProver` and a `SumcheckVerifier`.
+use ark_ff::Field;
+
+use crate::mle::DenseMLE;
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck,
+    prover::{ProverMsg, ProverState},
+    verifier::{VerifierMsg, VerifierOutput, VerifierState},
+};
+use crate::transcript::SumcheckTranscript;
+use crate::UniPoly;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// A prover for the Sumcheck protocol, answering one verifier message per round
+pub trait SumcheckProver<F: Field> {
+    /// Produces the message for the next round, given the verifier's message of the previous
+    /// one (`None` in the first round).
+    fn next_message(
+        &mut self,
+        verifier_msg: Option<VerifierMsg<F>>,
+    ) -> Result<ProverMsg<F>, crate::Error>;
+
+    /// Whether every round has been answered.
+    fn is_done(&self) -> bool;
+}
+
+impl<F: Field + From<i32>> SumcheckProver<F> for ProverState<F> {
+    /// Same as `IPForSumcheck::try_prove_round`, with misuse reported as an error instead of a
+    /// panic.
+    fn next_message(
+        &mut self,
+        verifier_msg: Option<VerifierMsg<F>>,
+    ) -> Result<ProverMsg<F>, crate::Error> {
+        if self.is_done() {
+            return Err(crate::Error::RoundMismatch {
+                expected: self.num_vars(),
+                got: self.round + 1,
+            });
+        }
+        if verifier_msg.is_some() != (self.round > 0) {
+            return Err(crate::Error::ProtocolError(
+                "A verifier message is expected in every round but the first.".into(),
+            ));
+        }
+
+        IPForSumcheck::try_prove_round(self, &verifier_msg)
+    }
+
+    fn is_done(&self) -> bool {
+        self.round >= self.num_vars()
+    }
+}
+
+/// A verifier for the Sumcheck protocol, answering every prover message with a challenge
+pub trait SumcheckVerifier<F: Field> {
+    /// Receives the prover's message for the current round and answers it with a challenge
+    /// drawn from `transcript`, e.g. a `RngTranscript` for an interactive verifier.
+    fn receive_prover_message<T: SumcheckTranscript<F>>(
+        &mut self,
+        msg: ProverMsg<F>,
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error>;
+
+    /// Runs the checks of every round against `asserted_sum`, leaving the final oracle query
+    /// on the returned output to the caller.
+    fn finalize(self, asserted_sum: F) -> Result<VerifierOutput<F>, crate::Error>
+    where
+        Self: Sized;
+}
+
+impl<F: Field> SumcheckVerifier<F> for VerifierState<F> {
+    /// Same as `IPForSumcheck::verify_round`.
+    fn receive_prover_message<T: SumcheckTranscript<F>>(
+        &mut self,
+        msg: ProverMsg<F>,
+        transcript: &mut T,
+    ) -> Result<VerifierMsg<F>, crate::Error> {
+        IPForSumcheck::verify_round(msg, self, transcript)
+    }
+
+    /// Same as `IPForSumcheck::partial_verify`.
+    fn finalize(self, asserted_sum: F) -> Result<VerifierOutput<F>, crate::Error> {
+        IPForSumcheck::partial_verify(self, asserted_sum)
+    }
+}
+
+/// Answers the verifier's final query `g(r_vec)`, e.g. by opening a commitment to `g`, for
+/// `IPForSumcheck::verify_with_oracle`
+pub trait PolyOracle<F: Field> {
+    /// Number of variables of `g`.
+    fn num_vars(&self) -> usize;
+
+    /// Bound on the degree of `g` in the variable `x_var`, which bounds the round polynomials.
+    fn degree_bound(&self, var: usize) -> usize;
+
+    /// `g(point)`, or an error if it cannot be established, e.g. because an opening fails.
+    fn query(&self, point: &[F]) -> Result<F, crate::Error>;
+}
+
+impl<F: Field> PolyOracle<F> for DenseMLE<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn degree_bound(&self, _var: usize) -> usize {
+        1
+    }
+
+    fn query(&self, point: &[F]) -> Result<F, crate::Error> {
+        self.evaluate(point)
+    }
+}
+
+/// Prover that follows an honest prover but shifts every round polynomial by a constant, so
+/// that `gi(0) + gi(1)` is off by twice that constant in every round
+pub struct MockCheatingProver<F: Field + From<i32>> {
+    /// The honest prover being followed
+    pub honest: ProverState<F>,
+    /// Constant added to every round polynomial, nonzero for the cheat to be detectable
+    pub offset: F,
+}
+
+impl<F: Field + From<i32>> MockCheatingProver<F> {
+    /// A cheating prover shifting the honest messages of `honest` by `offset`.
+    pub fn new(honest: ProverState<F>, offset: F) -> Self {
+        Self { honest, offset }
+    }
+}
+
+impl<F: Field + From<i32>> SumcheckProver<F> for MockCheatingProver<F> {
+    fn next_message(
+        &mut self,
+        verifier_msg: Option<VerifierMsg<F>>,
+    ) -> Result<ProverMsg<F>, crate::Error> {
+        let honest = self.honest.next_message(verifier_msg)?;
+        let shift = UniPoly::from_coefficients_vec(
+            Some((0, self.offset)).filter(|(_, c)| !c.is_zero()).into_iter().collect(),
+        );
+
+        Ok(ProverMsg::new(honest.gi + shift))
+    }
+
+    fn is_done(&self) -> bool {
+        self.honest.is_done()
+    }
+}
@@ -0,0 +1,98 @@
+use ark_bls12_381::Fr as Fr;
+use ark_poly::Polynomial;
+
+use crate::naive_sumcheck::protocol::{IPForSumcheck, verifier::VerifierMsg};
+use crate::naive_sumcheck::protocol::verifier::VerifierOutput;
+use crate::testing::thaler_example;
+use crate::traits::{MockCheatingProver, SumcheckProver, SumcheckVerifier};
+use crate::transcript::RngTranscript;
+use crate::MultiPoly;
+
+// Runs any prover against any verifier, returning the verifier's output for `asserted_sum`.
+fn run_pair<P: SumcheckProver<Fr>, V: SumcheckVerifier<Fr>>(
+    prover: &mut P,
+    mut verifier: V,
+    asserted_sum: Fr,
+) -> Result<VerifierOutput<Fr>, crate::Error> {
+    let mut rng = rand::thread_rng();
+    let mut transcript = RngTranscript::new(&mut rng);
+    let mut verifier_msg: Option<VerifierMsg<Fr>> = None;
+
+    while !prover.is_done() {
+        let prover_msg = prover.next_message(verifier_msg)?;
+        verifier_msg = Some(verifier.receive_prover_message(prover_msg, &mut transcript)?);
+    }
+
+    verifier.finalize(asserted_sum)
+}
+
+// Runs the verifier against any prover, returning its verdict on `asserted_sum`.
+fn run_verifier<P: SumcheckProver<Fr>>(
+    prover: &mut P,
+    g: &MultiPoly<Fr>,
+    asserted_sum: Fr,
+) -> Result<(), crate::Error> {
+    let verifier_state = IPForSumcheck::verifier_init(g.num_vars);
+    let v_out = run_pair(prover, verifier_state, asserted_sum)?;
+
+    let g_eval = g.evaluate(&v_out.r_vec);
+    if g_eval == v_out.expected_evaluation {
+        Ok(())
+    } else {
+        Err(crate::Error::final_evaluation_mismatch(v_out.expected_evaluation, g_eval))
+    }
+}
+
+#[test]
+fn test_honest_prover() {
+    let g = thaler_example();
+    let mut prover = IPForSumcheck::prover_init(g.clone()).unwrap();
+
+    assert!(run_verifier(&mut prover, &g, Fr::from(12)).is_ok());
+    assert!(prover.is_done());
+    assert_eq!(
+        prover.next_message(Some(VerifierMsg::new(Fr::from(1)))),
+        Err(crate::Error::RoundMismatch { expected: 3, got: 4 })
+    );
+
+    // The first message answers no challenge, every later one does.
+    let mut prover = IPForSumcheck::prover_init(g).unwrap();
+    assert!(prover.next_message(Some(VerifierMsg::new(Fr::from(1)))).is_err());
+    assert!(prover.next_message(None).is_ok());
+    assert!(prover.next_message(None).is_err());
+}
+
+#[test]
+fn test_cheating_prover() {
+    let g = thaler_example();
+    let honest = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut prover = MockCheatingProver::new(honest, Fr::from(1));
+
+    // Claiming the sum the shifted first message is consistent with still fails in round 2.
+    assert!(matches!(
+        run_verifier(&mut prover, &g, Fr::from(14)),
+        Err(crate::Error::SumMismatch { round: 2, .. })
+    ));
+
+    let honest = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let mut prover = MockCheatingProver::new(honest, Fr::from(1));
+    assert_eq!(
+        run_verifier(&mut prover, &g, Fr::from(12)),
+        Err(crate::Error::sum_mismatch(1, Fr::from(12), Fr::from(14)))
+    );
+}
+
+#[test]
+fn test_verifier_trait() {
+    let g = thaler_example();
+    let mut prover = IPForSumcheck::prover_init(g.clone()).unwrap();
+    let v_out = run_pair(&mut prover, IPForSumcheck::verifier_init(3), Fr::from(12)).unwrap();
+    assert_eq!(v_out.expected_evaluation, g.evaluate(&v_out.r_vec));
+
+    // Finalizing early is the same error as with `partial_verify`.
+    let verifier_state = IPForSumcheck::<Fr>::verifier_init(3);
+    assert_eq!(
+        verifier_state.finalize(Fr::from(12)).err(),
+        Some(crate::Error::RoundMismatch { expected: 3, got: 0 })
+    );
+}
@@ -0,0 +1,58 @@
+//! Sources of verifier challenges
+//!
+//! The verifier never samples randomness directly: every challenge is drawn from a
+//! `SumcheckTranscript`, which sees each prover message before the challenge that follows it.
+//! An interactive verifier uses `RngTranscript`, while a non-interactive prover and verifier
+//! derive identical challenges by running the same deterministic transcript, such as
+//! `Blake2Transcript`, or `PoseidonTranscript` behind the `poseidon` feature.
+use ark_ff::Field;
+use ark_std::rand::RngCore;
+
+use crate::naive_sumcheck::protocol::prover::ProverMsg;
+
+pub mod fiat_shamir;
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+
+pub use fiat_shamir::{Blake2Transcript, FsConfig};
+#[cfg(feature = "poseidon")]
+pub use poseidon::PoseidonTranscript;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Source of verifier challenges for the Sumcheck protocol
+pub trait SumcheckTranscript<F: Field> {
+    /// Binds the transcript to the instance being proven, before the first round.
+    ///
+    /// Interactive transcripts may ignore it, which is the default.
+    fn absorb_instance(&mut self, _config: &FsConfig<F>) {}
+
+    /// Absorbs a message sent by the prover.
+    fn absorb_prover_msg(&mut self, msg: &ProverMsg<F>);
+
+    /// Produces the next verifier challenge.
+    fn challenge(&mut self) -> F;
+}
+
+/// Transcript that ignores the prover's messages and samples challenges from `rng`,
+/// i.e. an honest interactive verifier
+pub struct RngTranscript<R: RngCore> {
+    /// Source of randomness
+    rng: R,
+}
+
+impl<R: RngCore> RngTranscript<R> {
+    /// Wraps `rng`, which may also be a `&mut` reference to a generator.
+    pub fn new(rng: R) -> Self {
+        Self { rng }
+    }
+}
+
+impl<F: Field, R: RngCore> SumcheckTranscript<F> for RngTranscript<R> {
+    fn absorb_prover_msg(&mut self, _msg: &ProverMsg<F>) {}
+
+    fn challenge(&mut self) -> F {
+        F::rand(&mut self.rng)
+    }
+}
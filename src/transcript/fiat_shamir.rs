@@ -0,0 +1,137 @@
+//! Fiat–Shamir transcript with domain separation and instance binding
+//!
+//! Before round one the transcript absorbs the protocol name and version, the field, and the
+//! instance (`num_vars`, per-variable degree bounds and the asserted sum). Each prover message is
+//! then absorbed under a round-indexed label, and every absorption is length-prefixed so that
+//! distinct sequences of labelled data never hash to the same state.
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use ark_std::vec::Vec;
+use blake2::{Blake2b512, Digest};
+
+use crate::naive_sumcheck::protocol::{prover::ProverMsg, verifier::max_degrees};
+use crate::transcript::SumcheckTranscript;
+use crate::MultiPoly;
+
+/// Name and version of the protocol, absorbed first by every transcript
+pub const PROTOCOL_LABEL: &[u8] = b"sumcheck/naive/v1";
+
+/// Public parameters of a Sumcheck instance that the challenges are bound to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsConfig<F: Field> {
+    /// Number of variables, i.e. rounds
+    pub num_vars: usize,
+    /// Degree bound of every round polynomial, indexed by variable
+    pub degree_bounds: Vec<usize>,
+    /// Claimed sum over the boolean hypercube
+    pub asserted_sum: F,
+}
+
+impl<F: Field> FsConfig<F> {
+    /// The instance proving that `g` sums to `asserted_sum`, with degree bounds taken from `g`.
+    pub fn new(g: &MultiPoly<F>, asserted_sum: F) -> Self {
+        Self {
+            num_vars: g.num_vars,
+            degree_bounds: max_degrees(g),
+            asserted_sum,
+        }
+    }
+}
+
+/// Fiat–Shamir transcript over BLAKE2b
+#[derive(Clone)]
+pub struct Blake2Transcript {
+    /// Hash of everything absorbed so far
+    hasher: Blake2b512,
+    /// Number of prover messages absorbed so far
+    round: u64,
+    /// Number of challenges produced so far
+    num_challenges: u64,
+}
+
+impl Blake2Transcript {
+    /// A transcript that has absorbed only `PROTOCOL_LABEL`.
+    pub fn new() -> Self {
+        let mut transcript = Self {
+            hasher: Blake2b512::new(),
+            round: 0,
+            num_challenges: 0,
+        };
+        transcript.absorb(b"protocol", PROTOCOL_LABEL);
+
+        transcript
+    }
+
+    /// Absorbs `data` under `label`.
+    pub fn absorb(&mut self, label: &[u8], data: &[u8]) {
+        self.hasher.update((label.len() as u64).to_le_bytes());
+        self.hasher.update(label);
+        self.hasher.update((data.len() as u64).to_le_bytes());
+        self.hasher.update(data);
+    }
+
+    /// `len` pseudorandom bytes derived from the current state and `counter`.
+    fn squeeze_bytes(&self, counter: u64, len: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(len);
+        let mut block = 0_u64;
+
+        while bytes.len() < len {
+            let digest = self
+                .hasher
+                .clone()
+                .chain_update(counter.to_le_bytes())
+                .chain_update(block.to_le_bytes())
+                .finalize();
+            bytes.extend_from_slice(&digest);
+            block += 1;
+        }
+        bytes.truncate(len);
+
+        bytes
+    }
+}
+
+impl Default for Blake2Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compressed serialization of `value`.
+fn to_bytes<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut bytes).unwrap();
+
+    bytes
+}
+
+impl<F: Field> SumcheckTranscript<F> for Blake2Transcript {
+    fn absorb_instance(&mut self, config: &FsConfig<F>) {
+        self.absorb(b"field-modulus", &F::BasePrimeField::MODULUS.to_bytes_le());
+        self.absorb(b"extension-degree", &F::extension_degree().to_le_bytes());
+        self.absorb(b"num-vars", &(config.num_vars as u64).to_le_bytes());
+        let degree_bounds: Vec<u8> =
+            config.degree_bounds.iter().flat_map(|d| (*d as u64).to_le_bytes()).collect();
+        self.absorb(b"degree-bounds", &degree_bounds);
+        self.absorb(b"asserted-sum", &to_bytes(&config.asserted_sum));
+    }
+
+    fn absorb_prover_msg(&mut self, msg: &ProverMsg<F>) {
+        let label = [b"round-".as_slice(), &self.round.to_le_bytes()].concat();
+        self.absorb(&label, &to_bytes(msg));
+        self.round += 1;
+    }
+
+    fn challenge(&mut self) -> F {
+        let label = [b"challenge-".as_slice(), &self.num_challenges.to_le_bytes()].concat();
+        self.absorb(&label, &[]);
+        self.num_challenges += 1;
+
+        // Rejection sampling: candidates at least the modulus are discarded.
+        let len = F::extension_degree() as usize
+            * (F::BasePrimeField::MODULUS_BIT_SIZE as usize).div_ceil(8);
+        (0_u64..)
+            .find_map(|counter| F::from_random_bytes(&self.squeeze_bytes(counter, len)))
+            .unwrap()
+    }
+}
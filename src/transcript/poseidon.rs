@@ -0,0 +1,115 @@
+//! Fiat–Shamir transcript over the Poseidon permutation
+//!
+//! Unlike `Blake2Transcript`, everything is absorbed as field elements of `F` itself, so the
+//! challenges can be re-derived cheaply inside an arithmetic circuit. Labels, the instance and
+//! each prover message are absorbed in the same order as in `Blake2Transcript`.
+use ark_crypto_primitives::sponge::{
+    poseidon::{find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge},
+    Absorb, CryptographicSponge,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::prover::ProverMsg;
+use crate::transcript::{fiat_shamir::PROTOCOL_LABEL, FsConfig, SumcheckTranscript};
+
+/// Number of full rounds of `default_config`
+pub const FULL_ROUNDS: usize = 8;
+/// Number of partial rounds of `default_config`
+pub const PARTIAL_ROUNDS: usize = 57;
+/// S-box exponent of `default_config`
+pub const ALPHA: u64 = 5;
+/// Rate of `default_config`, in field elements
+pub const RATE: usize = 2;
+
+/// Poseidon parameters of width 3 (rate 2, capacity 1), with round constants and MDS matrix
+/// generated by the Grain LFSR for the modulus of `F`.
+///
+/// The round numbers match the usual choice for ~255-bit fields such as BLS12-381 `Fr`.
+pub fn default_config<F: PrimeField>() -> PoseidonConfig<F> {
+    let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+        F::MODULUS_BIT_SIZE as u64,
+        RATE,
+        FULL_ROUNDS as u64,
+        PARTIAL_ROUNDS as u64,
+        0,
+    );
+
+    PoseidonConfig::new(FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, mds, ark, RATE, 1)
+}
+
+/// Fiat–Shamir transcript over a Poseidon sponge
+#[derive(Clone)]
+pub struct PoseidonTranscript<F: PrimeField + Absorb> {
+    /// Sponge holding everything absorbed so far
+    sponge: PoseidonSponge<F>,
+    /// Number of prover messages absorbed so far
+    round: u64,
+    /// Number of challenges produced so far
+    num_challenges: u64,
+}
+
+impl<F: PrimeField + Absorb> PoseidonTranscript<F> {
+    /// A transcript over a sponge with parameters `config` that has absorbed only `PROTOCOL_LABEL`.
+    pub fn new(config: &PoseidonConfig<F>) -> Self {
+        let mut transcript = Self {
+            sponge: PoseidonSponge::new(config),
+            round: 0,
+            num_challenges: 0,
+        };
+        transcript.absorb(b"protocol", &bytes_as_elements(PROTOCOL_LABEL));
+
+        transcript
+    }
+
+    /// Absorbs `elements` under `label`, both prefixed by their length.
+    pub fn absorb(&mut self, label: &[u8], elements: &[F]) {
+        self.sponge.absorb(&(label.len() as u64));
+        self.sponge.absorb(&label);
+        self.sponge.absorb(&(elements.len() as u64));
+        self.sponge.absorb(&elements);
+    }
+}
+
+/// One field element per byte of `bytes`.
+fn bytes_as_elements<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    bytes.iter().map(|b| F::from(*b)).collect()
+}
+
+impl<F: PrimeField + Absorb> Default for PoseidonTranscript<F> {
+    /// A transcript over `default_config`.
+    fn default() -> Self {
+        Self::new(&default_config())
+    }
+}
+
+impl<F: PrimeField + Absorb> SumcheckTranscript<F> for PoseidonTranscript<F> {
+    fn absorb_instance(&mut self, config: &FsConfig<F>) {
+        self.absorb(b"field-modulus", &bytes_as_elements(&F::MODULUS.to_bytes_le()));
+        self.absorb(b"num-vars", &[F::from(config.num_vars as u64)]);
+        let degree_bounds: Vec<F> = config.degree_bounds.iter().map(|d| F::from(*d as u64)).collect();
+        self.absorb(b"degree-bounds", &degree_bounds);
+        self.absorb(b"asserted-sum", &[config.asserted_sum]);
+    }
+
+    fn absorb_prover_msg(&mut self, msg: &ProverMsg<F>) {
+        let label = [b"round-".as_slice(), &self.round.to_le_bytes()].concat();
+        // The version, followed by the (degree, coefficient) pairs of `gi`.
+        let mut elements = Vec::with_capacity(2 * msg.gi.len() + 1);
+        elements.push(F::from(msg.version()));
+        for (degree, coeff) in msg.gi.iter() {
+            elements.push(F::from(*degree as u64));
+            elements.push(*coeff);
+        }
+        self.absorb(&label, &elements);
+        self.round += 1;
+    }
+
+    fn challenge(&mut self) -> F {
+        let label = [b"challenge-".as_slice(), &self.num_challenges.to_le_bytes()].concat();
+        self.absorb(&label, &[]);
+        self.num_challenges += 1;
+
+        self.sponge.squeeze_field_elements(1)[0]
+    }
+}
@@ -0,0 +1,177 @@
+use ark_bls12_381::Fr as Fr;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{IPForSumcheck, prover::ProverMsg};
+use crate::testing::thaler_example;
+use crate::transcript::{Blake2Transcript, FsConfig, RngTranscript, SumcheckTranscript};
+
+// Mock transcript replaying a fixed sequence of challenges.
+struct FixedTranscript {
+    challenges: Vec<Fr>,
+    absorbed: usize,
+}
+
+impl FixedTranscript {
+    fn new(challenges: &[u64]) -> Self {
+        Self { challenges: challenges.iter().rev().map(|c| Fr::from(*c)).collect(), absorbed: 0 }
+    }
+}
+
+impl SumcheckTranscript<Fr> for FixedTranscript {
+    fn absorb_prover_msg(&mut self, _msg: &ProverMsg<Fr>) {
+        self.absorbed += 1;
+    }
+
+    fn challenge(&mut self) -> Fr {
+        self.challenges.pop().expect("Ran out of challenges...")
+    }
+}
+
+#[test]
+fn test_fixed_challenges() {
+    let g = thaler_example();
+
+    let mut prover_state = IPForSumcheck::<Fr>::prover_init(g.clone()).unwrap();
+    let mut verifier_state = IPForSumcheck::<Fr>::verifier_init(g.num_vars);
+    let mut transcript = FixedTranscript::new(&[2, 3, 6]);
+    let mut verifier_msg = None;
+    let mut challenges = Vec::new();
+
+    for _ in 0..g.num_vars {
+        let prover_message = IPForSumcheck::<Fr>::prove_round(&mut prover_state, &verifier_msg);
+        verifier_msg =
+            IPForSumcheck::<Fr>::verify_round(prover_message, &mut verifier_state, &mut transcript).ok();
+        challenges.push(verifier_msg.as_ref().unwrap().randomness);
+    }
+
+    assert_eq!(challenges, vec![Fr::from(2), Fr::from(3), Fr::from(6)]);
+    assert_eq!(transcript.absorbed, g.num_vars);
+    IPForSumcheck::<Fr>::verify(&g, verifier_state, 12.into()).expect("Failed to verify...");
+}
+
+#[test]
+fn test_non_interactive_is_reproducible() {
+    let g = thaler_example();
+
+    let proof = IPForSumcheck::<Fr>::prove_non_interactive(
+        g.clone(),
+        &mut FixedTranscript::new(&[2, 3, 6]),
+    ).unwrap();
+    let again = IPForSumcheck::<Fr>::prove_non_interactive(
+        g.clone(),
+        &mut FixedTranscript::new(&[2, 3, 6]),
+    ).unwrap();
+    assert_eq!(proof.verifier_randomness, again.verifier_randomness);
+    assert!(proof
+        .prover_messages
+        .iter()
+        .zip(&again.prover_messages)
+        .all(|(a, b)| a.gi == b.gi));
+
+    IPForSumcheck::<Fr>::verify_non_interactive(
+        &g,
+        &proof,
+        12.into(),
+        &mut FixedTranscript::new(&[2, 3, 6]),
+    )
+    .expect("Failed to verify...");
+
+    // Challenges that differ from the verifier's transcript are rejected.
+    assert!(IPForSumcheck::<Fr>::verify_non_interactive(
+        &g,
+        &proof,
+        12.into(),
+        &mut FixedTranscript::new(&[2, 3, 7]),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_rng_transcript() {
+    let g = thaler_example();
+
+    // Prover and verifier derive identical challenges from identically seeded generators.
+    let proof = IPForSumcheck::<Fr>::prove_non_interactive(
+        g.clone(),
+        &mut RngTranscript::new(ark_std::test_rng()),
+    ).unwrap();
+    IPForSumcheck::<Fr>::verify_non_interactive(
+        &g,
+        &proof,
+        12.into(),
+        &mut RngTranscript::new(ark_std::test_rng()),
+    )
+    .expect("Failed to verify...");
+
+    assert!(IPForSumcheck::<Fr>::verify_non_interactive(
+        &g,
+        &proof,
+        13.into(),
+        &mut RngTranscript::new(ark_std::test_rng()),
+    )
+    .is_err());
+    assert!(IPForSumcheck::<Fr>::verify_non_interactive(
+        &g,
+        &proof,
+        12.into(),
+        &mut RngTranscript::new(rand::thread_rng()),
+    )
+    .is_err());
+}
+
+// Whether `result` is a rejection caused by challenges that differ from the verifier's.
+fn is_challenge_mismatch(result: Result<(), crate::Error>) -> bool {
+    matches!(result, Err(crate::Error::ChallengeMismatch { round: 1 }))
+}
+
+#[test]
+fn test_fiat_shamir_binds_instance() {
+    let g = thaler_example();
+
+    let proof = IPForSumcheck::<Fr>::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+    let again = IPForSumcheck::<Fr>::prove_non_interactive(g.clone(), &mut Blake2Transcript::new()).unwrap();
+    assert_eq!(proof.verifier_randomness, again.verifier_randomness);
+
+    IPForSumcheck::<Fr>::verify_non_interactive(&g, &proof, 12.into(), &mut Blake2Transcript::new())
+        .expect("Failed to verify...");
+
+    // A different asserted sum changes the challenges before any round check is reached.
+    assert!(is_challenge_mismatch(IPForSumcheck::<Fr>::verify_non_interactive(
+        &g,
+        &proof,
+        13.into(),
+        &mut Blake2Transcript::new(),
+    )));
+
+    // g + x1^2 - x1 has the same hypercube sum but a higher degree bound for x1.
+    let raised = &g + &crate::poly!(Fr; vars = 3; (1, [(1, 2)]), (-1, [(1, 1)])).unwrap();
+    assert_eq!(IPForSumcheck::<Fr>::compute_sum(&raised).unwrap(), Fr::from(12));
+    assert!(is_challenge_mismatch(IPForSumcheck::<Fr>::verify_non_interactive(
+        &raised,
+        &proof,
+        12.into(),
+        &mut Blake2Transcript::new(),
+    )));
+}
+
+#[test]
+fn test_fiat_shamir_domain_separation() {
+    let config = FsConfig::new(&thaler_example(), Fr::from(12));
+    let first_challenge = |config: &FsConfig<Fr>| {
+        let mut transcript = Blake2Transcript::new();
+        transcript.absorb_instance(config);
+        SumcheckTranscript::<Fr>::challenge(&mut transcript)
+    };
+
+    let challenge = first_challenge(&config);
+    assert_eq!(challenge, first_challenge(&config));
+    assert_ne!(challenge, first_challenge(&FsConfig { num_vars: 4, ..config.clone() }));
+    assert_ne!(challenge, first_challenge(&FsConfig { degree_bounds: vec![3, 1, 2], ..config.clone() }));
+
+    // Successive challenges differ even without any prover message in between.
+    let mut transcript = Blake2Transcript::new();
+    transcript.absorb_instance(&config);
+    let c0: Fr = transcript.challenge();
+    let c1: Fr = transcript.challenge();
+    assert_ne!(c0, c1);
+}
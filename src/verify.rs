@@ -0,0 +1,77 @@
+//! Post-hoc audits of stored proofs
+//!
+//! Unlike `partial_verify`, which stops at the first failing round, the audits here check every
+//! round on its own and report each outcome, so that a stored transcript can be diagnosed
+//! round by round.
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::naive_sumcheck::protocol::{IPForSumcheck, proof::SumcheckProof, verifier::VerifierState};
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Checks the consistency condition of every round of `proof` independently, the first round
+/// against `asserted_sum` and round `i > 1` against `g_{i-1}(r_{i-1})` as recorded in `proof`.
+///
+/// Returns one result per prover message, so a single bad round only fails its own entry and,
+/// through the claim it passes on, possibly the next one. A round whose previous challenge is
+/// missing from `proof` fails with `RoundMismatch`. As with `verify_consistency_only`, neither
+/// the degree bounds nor the final oracle query are checked.
+///
+/// Each round is summed over the boolean domain, see `consistency_check_all_with_domains` for
+/// proofs over other domains.
+pub fn consistency_check_all<F: Field>(
+    proof: &SumcheckProof<F>,
+    asserted_sum: F,
+) -> Vec<Result<(), crate::Error>> {
+    let verifier_state = IPForSumcheck::verifier_init(proof.prover_messages.len());
+
+    consistency_check_all_with_domains(proof, asserted_sum, &verifier_state)
+}
+
+/// Same as `consistency_check_all`, except that round `i` sums its polynomial over the domain
+/// `verifier_state` assigns to variable `i`, as `partial_verify` would.
+///
+/// `verifier_state` is a verifier as initialized for the proof, e.g. by
+/// `verifier_init_with_base` or `verifier_init_with_domains`; the messages it has received, if
+/// any, are ignored. A round beyond its number of variables fails with `RoundMismatch`.
+pub fn consistency_check_all_with_domains<F: Field>(
+    proof: &SumcheckProof<F>,
+    asserted_sum: F,
+    verifier_state: &VerifierState<F>,
+) -> Vec<Result<(), crate::Error>> {
+    proof
+        .prover_messages
+        .iter()
+        .enumerate()
+        .map(|(i, msg)| {
+            if i >= verifier_state.num_vars() {
+                return Err(crate::Error::RoundMismatch {
+                    expected: verifier_state.num_vars(),
+                    got: proof.prover_messages.len(),
+                });
+            }
+
+            let expected_sum = match i {
+                0 => asserted_sum,
+                _ => match proof.verifier_randomness.get(i - 1) {
+                    Some(r) => proof.prover_messages[i - 1].eval_at(*r),
+                    None => {
+                        return Err(crate::Error::RoundMismatch {
+                            expected: i + 1,
+                            got: proof.verifier_randomness.len(),
+                        })
+                    }
+                },
+            };
+
+            let sum = verifier_state.round_sum(i, &msg.gi);
+            if sum == expected_sum {
+                Ok(())
+            } else {
+                Err(crate::Error::sum_mismatch(i + 1, expected_sum, sum))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,103 @@
+use ark_bls12_381::Fr as Fr;
+use ark_ff::One;
+
+use crate::naive_sumcheck::protocol::{
+    IPForSumcheck, proof::SumcheckProof, prover::{ProverMsg, ProverState}, verifier::VerifierState,
+};
+use crate::testing::random_sparse_polynomial;
+use crate::transcript::Blake2Transcript;
+use crate::verify::{consistency_check_all, consistency_check_all_with_domains};
+use crate::UniPoly;
+
+// Runs an honest prover against `verifier_state` and records the messages and challenges.
+fn record_proof(mut prover_state: ProverState<Fr>, mut verifier_state: VerifierState<Fr>) -> SumcheckProof<Fr> {
+    let mut transcript = Blake2Transcript::new();
+    let mut proof = SumcheckProof { prover_messages: Vec::new(), verifier_randomness: Vec::new() };
+    let mut verifier_msg = None;
+
+    for _ in 0..verifier_state.num_vars() {
+        let prover_msg = IPForSumcheck::prove_round(&mut prover_state, &verifier_msg);
+        proof.prover_messages.push(prover_msg.clone());
+        let msg = IPForSumcheck::verify_round(prover_msg, &mut verifier_state, &mut transcript).unwrap();
+        proof.verifier_randomness.push(msg.randomness);
+        verifier_msg = Some(msg);
+    }
+
+    proof
+}
+
+#[test]
+fn test_consistency_check_all() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(5, 10, 3, &mut rng);
+    let asserted_sum = IPForSumcheck::compute_sum(&g).unwrap();
+    let mut proof = IPForSumcheck::prove_non_interactive(g, &mut Blake2Transcript::new()).unwrap();
+    assert!(consistency_check_all(&proof, asserted_sum).iter().all(Result::is_ok));
+
+    // Adding `X - r_2` to the second round polynomial changes its sum but not the claim it
+    // passes on, so only the second round fails.
+    let r = proof.verifier_randomness[1];
+    let shift = UniPoly::from_coefficients_vec(vec![(0, -r), (1, Fr::one())]);
+    proof.prover_messages[1] = ProverMsg::new(proof.prover_messages[1].gi.clone() + shift);
+    let results = consistency_check_all(&proof, asserted_sum);
+    assert_eq!(results.len(), 5);
+    for (i, result) in results.iter().enumerate() {
+        match i {
+            1 => assert!(matches!(result, Err(crate::Error::SumMismatch { round: 2, .. }))),
+            _ => assert!(result.is_ok()),
+        }
+    }
+
+    // A missing challenge only fails the round that needs it.
+    proof.verifier_randomness.truncate(3);
+    let results = consistency_check_all(&proof, asserted_sum + Fr::one());
+    assert!(matches!(results[0], Err(crate::Error::SumMismatch { round: 1, .. })));
+    assert!(results[2].is_ok());
+    assert!(matches!(results[4], Err(crate::Error::RoundMismatch { expected: 5, got: 3 })));
+}
+
+#[test]
+fn test_consistency_check_all_with_domains() {
+    let mut rng = rand::thread_rng();
+    let g = random_sparse_polynomial::<Fr, _>(3, 10, 3, &mut rng);
+
+    // Base 3: every round sums gi(0) + gi(1) + gi(2).
+    let asserted_sum = IPForSumcheck::compute_sum_with_base(&g, 3);
+    let verifier_state = IPForSumcheck::<Fr>::verifier_init_with_base(3, 3);
+    let mut proof = record_proof(
+        IPForSumcheck::prover_init_with_base(g.clone(), 3).unwrap(),
+        verifier_state.clone(),
+    );
+    let results = consistency_check_all_with_domains(&proof, asserted_sum, &verifier_state);
+    assert!(results.iter().all(Result::is_ok));
+    assert!(matches!(
+        consistency_check_all(&proof, asserted_sum)[0],
+        Err(crate::Error::SumMismatch { round: 1, .. })
+    ));
+
+    // Shifting the last round's polynomial by a constant only fails that round.
+    let shift = UniPoly::from_coefficients_vec(vec![(0, Fr::one())]);
+    proof.prover_messages[2] = ProverMsg::new(proof.prover_messages[2].gi.clone() + shift);
+    let results = consistency_check_all_with_domains(&proof, asserted_sum, &verifier_state);
+    assert!(results[..2].iter().all(Result::is_ok));
+    assert!(matches!(results[2], Err(crate::Error::SumMismatch { round: 3, .. })));
+
+    // Custom domains: each round sums over its own set of points.
+    let domains: Vec<Vec<Fr>> =
+        vec![[0, 1].map(Fr::from).to_vec(), [0, 1, 2, 5].map(Fr::from).to_vec(), [3, 7, 11].map(Fr::from).to_vec()];
+    let asserted_sum = IPForSumcheck::compute_sum_over_domains(&g, &domains);
+    let verifier_state = IPForSumcheck::<Fr>::verifier_init_with_domains(domains.clone()).unwrap();
+    let proof = record_proof(
+        IPForSumcheck::prover_init_with_domains(g, domains).unwrap(),
+        verifier_state.clone(),
+    );
+    let results = consistency_check_all_with_domains(&proof, asserted_sum, &verifier_state);
+    assert!(results.iter().all(Result::is_ok));
+    let results = consistency_check_all_with_domains(&proof, asserted_sum + Fr::one(), &verifier_state);
+    assert!(matches!(results[0], Err(crate::Error::SumMismatch { round: 1, .. })));
+    assert!(results[1..].iter().all(Result::is_ok));
+
+    // Rounds beyond the verifier's variables have no domain.
+    let results = consistency_check_all_with_domains(&proof, asserted_sum, &IPForSumcheck::verifier_init(2));
+    assert!(matches!(results[2], Err(crate::Error::RoundMismatch { expected: 2, got: 3 })));
+}
@@ -0,0 +1,105 @@
+//! `wasm_bindgen` entry points over byte buffers
+//!
+//! Polynomials, sums and proofs cross the JavaScript boundary as their compressed
+//! `CanonicalSerialize` encodings over the scalar field `Fr` of BLS12-381. Proofs are
+//! non-interactive, with every challenge drawn from a fresh `Blake2Transcript`, so neither
+//! party needs a source of randomness.
+//!
+//! Proofs may also be passed as JSON strings, in the `serde` encoding of `SumcheckProof`.
+//!
+//! `prove_bytes`, `sum_bytes`, `verify_bytes` and `verify_json` do the work and report a
+//! `crate::Error`; the `wasm_*` functions only wrap them for JavaScript. `tests/wasm` checks
+//! the JavaScript side against the package built by
+//! `wasm-pack build --target nodejs -- --features wasm`.
+use ark_bls12_381::Fr;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{string::ToString, vec::Vec};
+use wasm_bindgen::prelude::*;
+
+use crate::naive_sumcheck::protocol::{IPForSumcheck, proof::SumcheckProof};
+use crate::transcript::Blake2Transcript;
+use crate::MultiPoly;
+
+#[cfg(all(test, feature = "std"))]
+mod test;
+
+/// Reads a compressed `T` that must span all of `bytes`.
+fn read_exact<T: CanonicalDeserialize>(mut bytes: &[u8]) -> Result<T, crate::Error> {
+    let value = T::deserialize_compressed(&mut bytes)?;
+    if !bytes.is_empty() {
+        return Err(crate::Error::DeserializeError(format!(
+            "{} bytes left after the encoding.",
+            bytes.len()
+        )));
+    }
+
+    Ok(value)
+}
+
+/// Writes the compressed encoding of `value`.
+fn write<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut bytes).expect("Writing to a Vec never fails...");
+    bytes
+}
+
+/// Proves the sum of the polynomial encoded in `poly_bytes` over the boolean hypercube,
+/// returning the encoded `SumcheckProof`.
+pub fn prove_bytes(poly_bytes: &[u8]) -> Result<Vec<u8>, crate::Error> {
+    let g: MultiPoly<Fr> = read_exact(poly_bytes)?;
+    let proof = IPForSumcheck::prove_non_interactive(g, &mut Blake2Transcript::new())?;
+
+    Ok(write(&proof))
+}
+
+/// Encoded sum of the polynomial encoded in `poly_bytes` over the boolean hypercube, i.e. the
+/// sum `prove_bytes` argues for.
+pub fn sum_bytes(poly_bytes: &[u8]) -> Result<Vec<u8>, crate::Error> {
+    let g: MultiPoly<Fr> = read_exact(poly_bytes)?;
+
+    Ok(write(&IPForSumcheck::compute_sum(&g)?))
+}
+
+/// Verifies the proof encoded in `proof_bytes` that the polynomial encoded in `poly_bytes`
+/// sums to the field element encoded in `sum_bytes`.
+pub fn verify_bytes(poly_bytes: &[u8], sum_bytes: &[u8], proof_bytes: &[u8]) -> Result<(), crate::Error> {
+    let g: MultiPoly<Fr> = read_exact(poly_bytes)?;
+    let asserted_sum: Fr = read_exact(sum_bytes)?;
+    let proof: SumcheckProof<Fr> = read_exact(proof_bytes)?;
+
+    IPForSumcheck::verify_non_interactive(&g, &proof, asserted_sum, &mut Blake2Transcript::new())
+}
+
+/// Same as `verify_bytes`, with the proof encoded as a JSON string.
+pub fn verify_json(poly_bytes: &[u8], sum_bytes: &[u8], proof_json: &str) -> Result<(), crate::Error> {
+    let g: MultiPoly<Fr> = read_exact(poly_bytes)?;
+    let asserted_sum: Fr = read_exact(sum_bytes)?;
+    let proof: SumcheckProof<Fr> = serde_json::from_str(proof_json)
+        .map_err(|e| crate::Error::DeserializeError(e.to_string()))?;
+
+    IPForSumcheck::verify_non_interactive(&g, &proof, asserted_sum, &mut Blake2Transcript::new())
+}
+
+/// Same as `prove_bytes`, throwing the error's message on failure.
+#[wasm_bindgen]
+pub fn wasm_prove(poly_bytes: &[u8]) -> Result<Vec<u8>, JsError> {
+    prove_bytes(poly_bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Same as `sum_bytes`, throwing the error's message on failure.
+#[wasm_bindgen]
+pub fn wasm_sum(poly_bytes: &[u8]) -> Result<Vec<u8>, JsError> {
+    sum_bytes(poly_bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Whether `verify_bytes` accepts, malformed inputs included among the rejections.
+#[wasm_bindgen]
+pub fn wasm_verify(poly_bytes: &[u8], sum_bytes: &[u8], proof_bytes: &[u8]) -> bool {
+    verify_bytes(poly_bytes, sum_bytes, proof_bytes).is_ok()
+}
+
+/// Whether `verify_json` accepts, malformed inputs included among the rejections.
+#[wasm_bindgen]
+pub fn wasm_verify_json(poly_bytes: &[u8], sum_bytes: &[u8], proof_json: &str) -> bool {
+    verify_json(poly_bytes, sum_bytes, proof_json).is_ok()
+}
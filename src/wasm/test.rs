@@ -0,0 +1,82 @@
+use ark_bls12_381::Fr as Fr;
+use ark_serialize::CanonicalSerialize;
+
+use crate::testing::{raw_polynomial, thaler_example};
+use crate::wasm::{prove_bytes, sum_bytes, verify_bytes, verify_json, wasm_prove, wasm_sum, wasm_verify, wasm_verify_json};
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn test_wasm_round_trip() {
+    let poly_bytes = encode(&thaler_example::<Fr>());
+    let sum = wasm_sum(&poly_bytes).unwrap();
+    assert_eq!(sum, encode(&Fr::from(12)));
+
+    let proof = wasm_prove(&poly_bytes).unwrap();
+    assert!(wasm_verify(&poly_bytes, &sum, &proof));
+    assert!(!wasm_verify(&poly_bytes, &encode(&Fr::from(13)), &proof));
+
+    // Malformed buffers are rejected, not panicked on.
+    assert!(!wasm_verify(&poly_bytes, &sum, &proof[..proof.len() - 1]));
+    assert!(!wasm_verify(&poly_bytes[1..], &sum, &proof));
+    let mut padded = proof.clone();
+    padded.push(0);
+    assert!(matches!(verify_bytes(&poly_bytes, &sum, &padded), Err(crate::Error::DeserializeError(_))));
+    assert!(prove_bytes(&[]).is_err());
+    assert!(sum_bytes(&poly_bytes[..3]).is_err());
+}
+
+
+/// Inputs of the JavaScript test, which must stay in sync with `thaler_example`.
+const FIXTURE: &str = include_str!("../../tests/wasm/fixture.json");
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn test_wasm_verify_json() {
+    let fixture: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+    let poly_bytes = from_hex(fixture["poly"].as_str().unwrap());
+    let sum = from_hex(fixture["sum"].as_str().unwrap());
+    let proof_json = fixture["proof"].to_string();
+    assert_eq!(poly_bytes, encode(&thaler_example::<Fr>()));
+    assert_eq!(sum, encode(&Fr::from(12)));
+
+    // The JSON proof is the one `prove_bytes` encodes, and verifies like it.
+    let proof = crate::naive_sumcheck::protocol::IPForSumcheck::prove_non_interactive(
+        thaler_example::<Fr>(),
+        &mut crate::transcript::Blake2Transcript::new(),
+    )
+    .unwrap();
+    assert_eq!(serde_json::to_value(&proof).unwrap(), fixture["proof"]);
+    assert_eq!(encode(&proof), prove_bytes(&poly_bytes).unwrap());
+    assert!(wasm_verify_json(&poly_bytes, &sum, &proof_json));
+    assert!(!wasm_verify_json(&poly_bytes, &encode(&Fr::from(13)), &proof_json));
+
+    let mut truncated = fixture["proof"].clone();
+    truncated["prover_messages"].as_array_mut().unwrap().pop();
+    assert_eq!(
+        verify_json(&poly_bytes, &sum, &truncated.to_string()),
+        Err(crate::Error::RoundMismatch { expected: 3, got: 2 })
+    );
+    assert!(matches!(
+        verify_json(&poly_bytes, &sum, &proof_json[..proof_json.len() - 1]),
+        Err(crate::Error::DeserializeError(_))
+    ));
+}
+
+#[test]
+fn test_wasm_non_canonical_terms() {
+    // `1·x_0^0` and `x_0·x_0` are accepted by the decoder, but rejected instead of summed or
+    // proven under a different reading.
+    for terms in [vec![(0, 0)], vec![(0, 1), (0, 1)]] {
+        let poly_bytes = encode(&raw_polynomial(1, vec![(Fr::from(1), terms)]));
+        assert!(matches!(sum_bytes(&poly_bytes), Err(crate::Error::PolynomialError(_))));
+        assert!(matches!(prove_bytes(&poly_bytes), Err(crate::Error::PolynomialError(_))));
+    }
+}
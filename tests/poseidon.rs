@@ -0,0 +1,41 @@
+//! End-to-end non-interactive Sumcheck over BLS12-381 `Fr` with the Poseidon transcript
+use ark_bls12_381::Fr;
+
+use sumcheck::naive_sumcheck::protocol::IPForSumcheck;
+use sumcheck::testing::thaler_example;
+use sumcheck::transcript::{PoseidonTranscript, SumcheckTranscript};
+use sumcheck::MultiPoly;
+
+#[test]
+fn test_poseidon_non_interactive_proof() {
+    let g: MultiPoly<Fr> = thaler_example();
+    let proof = IPForSumcheck::prove_non_interactive(g.clone(), &mut PoseidonTranscript::default())
+        .unwrap();
+
+    assert!(IPForSumcheck::verify_non_interactive(
+        &g,
+        &proof,
+        Fr::from(12),
+        &mut PoseidonTranscript::default()
+    )
+    .is_ok());
+
+    // The challenges are bound to the asserted sum.
+    assert!(IPForSumcheck::verify_non_interactive(
+        &g,
+        &proof,
+        Fr::from(13),
+        &mut PoseidonTranscript::default()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_poseidon_transcript_is_deterministic() {
+    let mut a = PoseidonTranscript::<Fr>::default();
+    let mut b = PoseidonTranscript::<Fr>::default();
+    let first: Fr = a.challenge();
+
+    assert_eq!(first, b.challenge());
+    assert_ne!(first, a.challenge());
+}
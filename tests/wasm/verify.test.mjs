@@ -0,0 +1,33 @@
+// Checks the `wasm_bindgen` verifier from JavaScript. Build the package first, then run
+// `node --test tests/wasm` from the crate root:
+//
+//     wasm-pack build --target nodejs --out-dir pkg -- --features wasm
+//
+// The inputs in `fixture.json` are the Thaler example, its sum 12 and its proof.
+import assert from "node:assert/strict";
+import { readFileSync } from "node:fs";
+import { test } from "node:test";
+import { createRequire } from "node:module";
+
+const { wasm_verify_json } = createRequire(import.meta.url)("../../pkg/sumcheck.js");
+const fixture = JSON.parse(readFileSync(new URL("./fixture.json", import.meta.url)));
+
+const fromHex = (hex) => Uint8Array.from(hex.match(/../g), (byte) => parseInt(byte, 16));
+const poly = fromHex(fixture.poly);
+const sum = fromHex(fixture.sum);
+
+test("accepts an honest proof", () => {
+  assert.equal(wasm_verify_json(poly, sum, JSON.stringify(fixture.proof)), true);
+});
+
+test("rejects a wrong sum", () => {
+  const wrongSum = fromHex("0d" + "00".repeat(31));
+  assert.equal(wasm_verify_json(poly, wrongSum, JSON.stringify(fixture.proof)), false);
+});
+
+test("rejects a truncated or malformed proof", () => {
+  const truncated = structuredClone(fixture.proof);
+  truncated.prover_messages.pop();
+  assert.equal(wasm_verify_json(poly, sum, JSON.stringify(truncated)), false);
+  assert.equal(wasm_verify_json(poly, sum, "{"), false);
+});